@@ -35,6 +35,7 @@ fn main() {
         CardType::Pokemon {
             species: "皮卡丘".to_string(),
             hp: 60,
+            pokemon_type: None,
             retreat_cost: 1,
             weakness: Some(EnergyType::Fighting),
             resistance: None,
@@ -67,6 +68,7 @@ fn main() {
         CardType::Pokemon {
             species: "小火龙".to_string(),
             hp: 60,
+            pokemon_type: None,
             retreat_cost: 1,
             weakness: Some(EnergyType::Water),
             resistance: None,
@@ -99,6 +101,7 @@ fn main() {
         CardType::Pokemon {
             species: "妙蛙种子".to_string(),
             hp: 60,
+            pokemon_type: None,
             retreat_cost: 1,
             weakness: Some(EnergyType::Fire),
             resistance: None,
@@ -131,6 +134,7 @@ fn main() {
         CardType::Pokemon {
             species: "杰尼龟".to_string(),
             hp: 60,
+            pokemon_type: None,
             retreat_cost: 1,
             weakness: Some(EnergyType::Grass),
             resistance: None,
@@ -291,7 +295,6 @@ fn main() {
 
     // 新增：启动游戏并显示先后手
     println!("🎮 Starting game setup...");
-    let mut game = game; // 转换为可变引用
 
     // 阶段1: 开始设置过程
     match game.start_setup() {
@@ -332,26 +335,34 @@ fn main() {
 
     // 阶段3: 发放初始手牌
     println!("🃏 Dealing opening hands...");
+    if let Err(e) = game.deal_opening_hands() {
+        println!("❌ Failed to deal opening hands: {}", e);
+        return;
+    }
     loop {
-        match game.perform_mulligan_for_both_and_check_basic_pokemon() {
-            Ok(ptcg_core::core::game::setup::MulliganResult::AllWithoutBasic) => {
+        match game.declare_no_basic_pokemon() {
+            Ok((players_without_basic, _)) if players_without_basic.is_empty() => {
+                println!("   ✅ Both players have basic Pokemon!");
+                break;
+            }
+            Ok((_, true)) => {
                 println!(
                     "   ⚠️  Both players have no basic Pokemon, performing mulligan for both..."
                 );
+                if let Err(e) = game.perform_mulligan_for_both_and_check_basic_pokemon() {
+                    println!("❌ Failed to perform mulligan: {}", e);
+                    return;
+                }
             }
-            Ok(ptcg_core::core::game::setup::MulliganResult::OneWithoutBasic(player_id)) => {
-                if let Ok(()) = game.mark_player_for_mulligan(player_id) {
-                    if let Some(player) = game.get_player(player_id) {
+            Ok((players_without_basic, false)) => {
+                let player_id = players_without_basic[0];
+                if let Ok(()) = game.mark_player_for_mulligan(player_id)
+                    && let Some(player) = game.get_player(player_id) {
                         println!(
                             "   - {} marked for mulligan after opponent completes setup",
                             player.name
                         );
                     }
-                }
-                break;
-            }
-            Ok(ptcg_core::core::game::setup::MulliganResult::AllWithBasic) => {
-                println!("   ✅ Both players have basic Pokemon!");
                 break;
             }
             Err(e) => {
@@ -368,11 +379,7 @@ fn main() {
     for player_id in player_order {
         // 使用单独的作用域来避免借用冲突
         let player_name = {
-            if let Some(player) = game.get_player(player_id) {
-                Some(player.name.clone())
-            } else {
-                None
-            }
+            game.get_player(player_id).map(|player| player.name.clone())
         };
 
         if let Some(name) = player_name {
@@ -413,11 +420,7 @@ fn main() {
     for player_id in player_order {
         // 使用单独的作用域来避免借用冲突
         let player_name = {
-            if let Some(player) = game.get_player(player_id) {
-                Some(player.name.clone())
-            } else {
-                None
-            }
+            game.get_player(player_id).map(|player| player.name.clone())
         };
 
         if let Some(name) = player_name {
@@ -451,7 +454,7 @@ fn main() {
             }
         }
     }
-    if let Some(player_id) = game.player_waiting_for_mulligan {
+    if let Some(player_id) = game.players_waiting_for_mulligan.first().copied() {
         println!("🔄 Performing pending mulligans for players who declared no basic Pokemon...");
         loop {
             // 阶段9: 需要重抽的玩家再次展示手牌
@@ -461,11 +464,7 @@ fn main() {
                 Ok(true) => {
                     println!("   ⚠️  Player still has no basic Pokemon after mulligan");
                     let player_name = {
-                        if let Some(player) = game.get_player(player_id) {
-                            Some(player.name.clone())
-                        } else {
-                            None
-                        }
+                        game.get_player(player_id).map(|player| player.name.clone())
                     };
 
                     if let Some(name) = player_name {
@@ -548,15 +547,10 @@ fn main() {
             }
             // 阶段7b: 奖赏卡补偿
             // 如果对手执行了步骤5.d.（重抽），则可以进行卡牌张数的宣告
-            if let Some(player_id) = game.player_waiting_for_mulligan {
+            if let Some(player_id) = game.players_waiting_for_mulligan.first().copied() {
                 println!("🎁 Processing mulligan compensation...");
-                let compensation_limit = match game.get_mulligan_compensation_limit(player_id) {
-                    Ok(limit) => limit,
-                    Err(e) => {
-                        println!("❌ Failed to get mulligan compensation limit: {}", e);
-                        return;
-                    }
-                };
+                // 对手可声明的补偿数量等于该玩家的重抽次数
+                let compensation_limit = game.get_mulligan_count(player_id);
 
                 if compensation_limit > 0 {
                     println!(
@@ -616,11 +610,10 @@ fn main() {
             println!("🎮 Game started successfully!");
             println!("   - Current turn: {}", game.turn_number);
             println!("   - Current phase: {:?}", game.phase);
-            if let Some(current_player_id) = game.get_current_player_id().ok() {
-                if let Some(player) = game.get_player(current_player_id) {
+            if let Ok(current_player_id) = game.get_current_player_id()
+                && let Some(player) = game.get_player(current_player_id) {
                     println!("   - Current player: {}", player.name);
                 }
-            }
         }
         Err(e) => {
             println!("❌ Failed to start game: {}", e);
@@ -631,21 +624,21 @@ fn main() {
     // 显示游戏状态
     println!("\n📋 Game State Summary:");
     println!("   - Game ID: {}", game.id);
-    println!("   - Current turn: {}", game.turn_number);
-    println!("   - Current phase: {:?}", game.phase);
-    
-    // 显示玩家状态
-    for player_id in &game.turn_order {
-        if let Some(player) = game.get_player(*player_id) {
-            println!("   - Player: {} ({})", player.name, player_id);
-            println!("     * Hand size: {}", player.hand.len());
+    if let Ok(summary) = game.current_turn_summary() {
+        println!("   - Current turn: {}", summary.turn_number);
+        println!("   - Current phase: {:?}", summary.phase);
+        println!("   - Current player: {}", summary.current_player_name);
+
+        // 显示玩家状态
+        for player in &summary.players {
+            println!("   - Player: {} ({})", player.name, player.player_id);
+            println!("     * Hand size: {}", player.hand_size);
             println!("     * Prize cards: {}", player.prize_cards);
-            if let Some(active_pokemon_id) = player.active_pokemon {
-                if let Some(card) = game.get_card(active_pokemon_id) {
+            if let Some(active_pokemon_id) = player.active_pokemon
+                && let Some(card) = game.get_card(active_pokemon_id) {
                     println!("     * Active Pokemon: {}", card.name);
                 }
-            }
-            println!("     * Bench Pokemon: {}", player.bench.len());
+            println!("     * Bench Pokemon: {}", player.bench_size);
         }
     }
 
@@ -68,6 +68,7 @@ fn main() {
         CardType::Pokemon {
             species: "皮卡丘".to_string(),
             hp: 60,
+            pokemon_type: None,
             retreat_cost: 1,
             weakness: Some(EnergyType::Fighting),
             resistance: None,
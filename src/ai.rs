@@ -0,0 +1,288 @@
+//! PTCG引擎的AI代理模块
+//!
+//! 此模块包含用于自动对战（self-play）的代理。
+
+use crate::core::game::state::{Game, GameState};
+use crate::core::player::PlayerId;
+use crate::core::rules::{GameAction, RuleEngine};
+use std::collections::HashMap;
+
+/// 单个回合内代理最多可执行的动作数，防止代理陷入死循环
+const MAX_ACTIONS_PER_TURN: usize = 100;
+
+/// 能够为当前玩家选择动作的代理
+pub trait Agent {
+    /// 根据当前游戏状态选择下一个动作
+    fn choose(&self, game: &Game, rule_engine: &RuleEngine) -> GameAction;
+}
+
+/// 简单的贪心代理
+///
+/// 优先使用伤害最高的攻击，其次附加能量，然后结束回合；
+/// 没有这些动作时选择第一个合法动作（例如昏厥后的替换）。
+#[derive(Debug, Clone, Copy, Default)]
+pub struct GreedyAgent;
+
+impl GreedyAgent {
+    /// 估算攻击动作的伤害（不抛硬币）
+    fn attack_damage(game: &Game, action: &GameAction) -> Option<u32> {
+        let GameAction::UseAttack {
+            player_id,
+            pokemon_id,
+            attack_index,
+            ..
+        } = action
+        else {
+            return None;
+        };
+
+        let attack = game.get_card(*pokemon_id)?.attacks.get(*attack_index)?;
+        let energy_count = game
+            .get_player(*player_id)
+            .map_or(0, |player| player.get_attached_energy_count(*pokemon_id));
+
+        game.attack_base_damage(*player_id, attack, energy_count as u32, &[]).ok()
+    }
+}
+
+impl Agent for GreedyAgent {
+    fn choose(&self, game: &Game, rule_engine: &RuleEngine) -> GameAction {
+        let actions = game.legal_actions(rule_engine);
+
+        // 伤害最高的攻击（伤害相同时取第一个）
+        let best_attack = actions
+            .iter()
+            .filter_map(|action| Self::attack_damage(game, action).map(|damage| (damage, action)))
+            .fold(None, |best: Option<(u32, &GameAction)>, (damage, action)| match best {
+                Some((best_damage, _)) if best_damage >= damage => best,
+                _ => Some((damage, action)),
+            });
+        if let Some((_, action)) = best_attack {
+            return action.clone();
+        }
+
+        if let Some(action) = actions
+            .iter()
+            .find(|action| matches!(action, GameAction::AttachEnergy { .. }))
+        {
+            return action.clone();
+        }
+
+        if let Some(action) = actions
+            .iter()
+            .find(|action| matches!(action, GameAction::EndTurn { .. }))
+            .or_else(|| actions.first())
+        {
+            return action.clone();
+        }
+
+        GameAction::Pass {
+            player_id: game.get_current_player_id().unwrap_or_default(),
+        }
+    }
+}
+
+impl Game {
+    /// 让各玩家的代理轮流行动，直到分出胜负或达到回合上限
+    ///
+    /// 如果游戏仍处于设置阶段，会先调用 `start`。每个回合中代理不断选择
+    /// 动作，直到结束回合、使用攻击（攻击会结束回合）或动作被规则拒绝；
+    /// 随后处理回合间效果并进入下一回合。宝可梦昏厥后，由需要替换的玩家的
+    /// 代理先选出新的活跃宝可梦。
+    ///
+    /// # 返回值
+    /// 返回胜者；平局、达到 `max_turns` 或缺少代理时返回 `None`
+    pub fn run_to_completion(
+        &mut self,
+        agents: HashMap<PlayerId, Box<dyn Agent>>,
+        rule_engine: &RuleEngine,
+        max_turns: u32,
+    ) -> Option<PlayerId> {
+        if self.state == GameState::Setup && self.start().is_err() {
+            return None;
+        }
+
+        for _ in 0..max_turns {
+            if self.state != GameState::InProgress {
+                break;
+            }
+
+            let player_id = self.get_current_player_id().ok()?;
+            let agent = agents.get(&player_id)?;
+
+            for _ in 0..MAX_ACTIONS_PER_TURN {
+                let action = agent.choose(self, rule_engine);
+                if matches!(action, GameAction::EndTurn { .. } | GameAction::Pass { .. }) {
+                    break;
+                }
+                if self.execute_action(rule_engine, &action).is_err()
+                    || matches!(action, GameAction::UseAttack { .. })
+                    || !self.resolve_knockouts(&agents, rule_engine)
+                {
+                    break;
+                }
+            }
+
+            if self.state != GameState::InProgress {
+                break;
+            }
+            self.process_between_turns();
+            if !self.resolve_knockouts(&agents, rule_engine) || self.end_turn().is_err() {
+                break;
+            }
+        }
+
+        match self.state {
+            GameState::Finished { winner } => winner,
+            _ => None,
+        }
+    }
+
+    /// 处理昏厥并让相应玩家的代理完成替换
+    ///
+    /// 游戏仍在进行且所有替换都已完成时返回 `true`。
+    fn resolve_knockouts(
+        &mut self,
+        agents: &HashMap<PlayerId, Box<dyn Agent>>,
+        rule_engine: &RuleEngine,
+    ) -> bool {
+        if self.process_knockouts().is_err() {
+            return false;
+        }
+
+        while self.state == GameState::InProgress
+            && let Some(&player_id) = self.pending_promotions.first()
+        {
+            let Some(agent) = agents.get(&player_id) else {
+                return false;
+            };
+            let action = agent.choose(self, rule_engine);
+            if self.execute_action(rule_engine, &action).is_err() {
+                return false;
+            }
+        }
+
+        self.state == GameState::InProgress
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::card::{Attack, Card, EnergyType};
+    use crate::core::game::state::GamePhase;
+    use crate::core::player::Player;
+    use crate::core::rules::StandardRules;
+    use crate::test_fixtures::{basic_energy, pokemon};
+
+    #[test]
+    fn test_greedy_agent_chooses_lethal_attack() {
+        let mut attacker = pokemon("Charmander", 70);
+        attacker.add_attack(Attack::simple("Scratch".to_string(), vec![EnergyType::Fire], 10));
+        attacker.add_attack(Attack::simple(
+            "Flamethrower".to_string(),
+            vec![EnergyType::Fire, EnergyType::Fire],
+            60,
+        ));
+        let defender = pokemon("Bulbasaur", 60);
+        let attached: Vec<Card> = (0..2).map(|_| basic_energy(EnergyType::Fire)).collect();
+        let in_hand = basic_energy(EnergyType::Fire);
+
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let alice_id = alice.id;
+        alice.active_pokemon = Some(attacker.id);
+        alice
+            .attached_energy
+            .insert(attacker.id, attached.iter().map(|card| card.id).collect());
+        alice.hand.push(in_hand.id);
+        bob.active_pokemon = Some(defender.id);
+
+        let mut game = Game::new();
+        let attacker_id = attacker.id;
+        for card in [attacker, defender, in_hand].into_iter().chain(attached) {
+            game.add_card_to_database(card);
+        }
+        game.turn_order = vec![alice_id, bob.id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.state = GameState::InProgress;
+        game.phase = GamePhase::Main;
+
+        let engine = StandardRules::create_engine();
+        let action = GreedyAgent.choose(&game, &engine);
+
+        assert_eq!(
+            action,
+            GameAction::UseAttack {
+                player_id: alice_id,
+                pokemon_id: attacker_id,
+                attack_index: 1,
+                target: None,
+            }
+        );
+    }
+
+    /// 每回合直接结束的代理
+    struct EndTurnAgent;
+
+    impl Agent for EndTurnAgent {
+        fn choose(&self, game: &Game, _rule_engine: &RuleEngine) -> GameAction {
+            GameAction::EndTurn {
+                player_id: game.get_current_player_id().unwrap(),
+            }
+        }
+    }
+
+    fn self_play_game() -> (Game, PlayerId, PlayerId) {
+        let mut game = Game::new();
+        let mut players = Vec::new();
+        for name in ["Alice", "Bob"] {
+            let mut player = Player::new(name.to_string());
+            let active = pokemon("Rattata", 40);
+            player.active_pokemon = Some(active.id);
+            game.add_card_to_database(active);
+
+            let deck: Vec<Card> = (0..8).map(|_| basic_energy(EnergyType::Fire)).collect();
+            player.set_deck(deck.iter().map(|card| card.id).collect());
+            for card in deck {
+                game.add_card_to_database(card);
+            }
+            players.push(player.id);
+            game.add_player(player).unwrap();
+        }
+        game.turn_order = players.clone();
+        (game, players[0], players[1])
+    }
+
+    #[test]
+    fn test_run_to_completion_stops_at_turn_cap() {
+        let (mut game, alice_id, bob_id) = self_play_game();
+        let mut agents: HashMap<PlayerId, Box<dyn Agent>> = HashMap::new();
+        agents.insert(alice_id, Box::new(GreedyAgent));
+        agents.insert(bob_id, Box::new(EndTurnAgent));
+
+        let winner = game.run_to_completion(agents, &StandardRules::create_engine(), 6);
+
+        assert_eq!(winner, None);
+        assert_eq!(game.state, GameState::InProgress);
+        // 贪心代理每回合都会附加抽到的能量
+        let alice = game.get_player(alice_id).unwrap();
+        let active = alice.active_pokemon.unwrap();
+        assert_eq!(alice.get_attached_energy_count(active), 3);
+    }
+
+    #[test]
+    fn test_run_to_completion_returns_winner() {
+        let (mut game, alice_id, bob_id) = self_play_game();
+        // Bob场上没有宝可梦，第一回合结束时Alice获胜
+        game.get_player_mut(bob_id).unwrap().active_pokemon = None;
+        let mut agents: HashMap<PlayerId, Box<dyn Agent>> = HashMap::new();
+        agents.insert(alice_id, Box::new(EndTurnAgent));
+        agents.insert(bob_id, Box::new(EndTurnAgent));
+
+        let winner = game.run_to_completion(agents, &StandardRules::create_engine(), 10);
+
+        assert_eq!(winner, Some(alice_id));
+    }
+}
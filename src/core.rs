@@ -17,7 +17,11 @@ pub mod rules;
 pub use card::*;
 pub use deck::*;
 pub use game::*;
-pub use player::*;
+// game 和 player 都有 state、actions 子模块，玩家类型逐个导出以免重名
+pub use player::{
+    CardLocation, ConditionEffect, Player, PlayerId, PlayerView, SpecialCondition, SpecialConditionInstance,
+    TURN_MARKER_PREFIX,
+};
 
 #[cfg(test)]
 mod tests {
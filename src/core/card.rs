@@ -8,12 +8,14 @@ pub mod energy;
 pub mod trainer;
 pub mod attacks;
 pub mod abilities;
+pub mod database;
 
 // 重新导出常用类型
 pub use types::*;
 pub use pokemon::*;
 pub use attacks::*;
 pub use abilities::*;
+pub use database::*;
 
 #[cfg(test)]
 mod tests {
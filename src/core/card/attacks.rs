@@ -2,6 +2,7 @@
 
 use crate::core::card::EnergyType;
 use crate::core::player::SpecialCondition;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 
 /// 宝可梦卡牌的攻击信息
@@ -39,6 +40,8 @@ pub enum DamageMode {
     PerPokemon { per_pokemon: u32, location: String },
     /// 可变伤害范围
     Variable { min: u32, max: u32 },
+    /// 由效果文本修正的伤害（例如 "30+"、"20×"、"120-"）
+    EffectText { modifier: String },
 }
 
 /// 攻击可施加的状态效果
@@ -156,24 +159,43 @@ impl Attack {
         if let Some(ref mode) = self.damage_mode {
             match mode {
                 DamageMode::PerEnergy { per_energy, .. } => {
-                    total_damage += per_energy * energy_count;
+                    total_damage =
+                        total_damage.saturating_add(per_energy.saturating_mul(energy_count));
                 }
                 DamageMode::CoinFlip { per_heads, .. } => {
                     let heads_count = coin_results.iter().filter(|&&result| result).count() as u32;
-                    total_damage += per_heads * heads_count;
+                    total_damage = total_damage.saturating_add(per_heads.saturating_mul(heads_count));
                 }
-                DamageMode::PerPokemon { per_pokemon, .. } => {
-                    // TODO: 当游戏状态可用时实现
-                    total_damage += per_pokemon * 2; // 占位符
+                DamageMode::PerPokemon { .. } => {
+                    // 宝可梦数量取决于场上状态，由 `Game::attack_base_damage` 计算
                 }
                 DamageMode::Variable { min, .. } => {
                     total_damage = *min; // 默认为最小值
                 }
+                DamageMode::EffectText { .. } => {
+                    // 修正量由效果文本决定，此处只使用基准伤害
+                }
             }
         }
 
         total_damage
     }
+
+    /// 使用随机数生成器计算实际伤害
+    ///
+    /// 与 `calculate_damage` 相同，但 `DamageMode::Variable` 会在 `[min, max]`
+    /// 范围内随机取值，而不是总取最小值。
+    pub fn roll_damage<R: Rng + ?Sized>(
+        &self,
+        energy_count: u32,
+        coin_results: &[bool],
+        rng: &mut R,
+    ) -> u32 {
+        match self.damage_mode {
+            Some(DamageMode::Variable { min, max }) => rng.gen_range(min.min(max)..=min.max(max)),
+            _ => self.calculate_damage(energy_count, coin_results),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -226,4 +248,31 @@ mod tests {
         // 基础伤害10 + 2个正面 * 每个正面10 = 30
         assert_eq!(damage, 30);
     }
+
+    #[test]
+    fn test_variable_damage_within_range() {
+        use crate::core::game::rng::GameRng;
+        use rand::SeedableRng;
+
+        let mut attack = Attack::simple("Wild Swing".to_string(), Vec::new(), 0);
+        attack.set_damage_mode(DamageMode::Variable { min: 20, max: 60 });
+
+        let rolls: Vec<u32> = (0..200)
+            .map(|seed| attack.roll_damage(0, &[], &mut GameRng::seed_from_u64(seed)))
+            .collect();
+        assert!(rolls.iter().all(|damage| (20..=60).contains(damage)));
+        assert!(rolls.iter().any(|&damage| damage != 20));
+        assert_eq!(attack.calculate_damage(0, &[]), 20);
+    }
+
+    #[test]
+    fn test_damage_saturates() {
+        let mut attack = Attack::simple("Overkill".to_string(), Vec::new(), u32::MAX - 10);
+        attack.set_damage_mode(DamageMode::PerEnergy {
+            per_energy: u32::MAX,
+            energy_type: None,
+        });
+
+        assert_eq!(attack.calculate_damage(3, &[]), u32::MAX);
+    }
 }
\ No newline at end of file
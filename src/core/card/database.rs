@@ -0,0 +1,134 @@
+//! 按印刷版本索引的卡牌数据库
+
+use crate::core::card::{Card, CardId};
+use std::collections::HashMap;
+
+/// 卡牌数据库
+///
+/// 以卡牌ID存储卡牌，同时按（卡包名称，编号）和卡牌名称建立索引，
+/// 以便卡组通过印刷版本引用卡牌。
+#[derive(Debug, Clone, Default)]
+pub struct CardDatabase {
+    /// 所有卡牌
+    cards: HashMap<CardId, Card>,
+    /// 印刷版本到卡牌ID的索引
+    by_printing: HashMap<(String, String), CardId>,
+    /// 卡牌名称到卡牌ID的索引（同名卡牌可能有多个印刷版本）
+    by_name: HashMap<String, Vec<CardId>>,
+}
+
+impl CardDatabase {
+    /// 创建空的卡牌数据库
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加卡牌，返回其ID
+    ///
+    /// 已存在相同ID的卡牌时会被替换。
+    pub fn insert(&mut self, card: Card) -> CardId {
+        let id = card.id;
+        if let Some(old) = self.cards.remove(&id) {
+            self.by_printing.remove(&(old.set_name, old.set_number));
+            if let Some(ids) = self.by_name.get_mut(&old.name) {
+                ids.retain(|&other| other != id);
+            }
+        }
+        self.by_printing
+            .insert((card.set_name.clone(), card.set_number.clone()), id);
+        self.by_name.entry(card.name.clone()).or_default().push(id);
+        self.cards.insert(id, card);
+        id
+    }
+
+    /// 通过ID获取卡牌
+    pub fn get(&self, id: CardId) -> Option<&Card> {
+        self.cards.get(&id)
+    }
+
+    /// 通过卡包名称和编号获取卡牌
+    pub fn get_by_printing(&self, set: &str, number: &str) -> Option<&Card> {
+        self.by_printing
+            .get(&(set.to_string(), number.to_string()))
+            .and_then(|id| self.cards.get(id))
+    }
+
+    /// 获取指定名称的所有卡牌（按插入顺序）
+    pub fn find_by_name(&self, name: &str) -> Vec<&Card> {
+        self.by_name
+            .get(name)
+            .into_iter()
+            .flatten()
+            .filter_map(|id| self.cards.get(id))
+            .collect()
+    }
+
+    /// 卡牌数量
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// 数据库是否为空
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    /// 遍历所有卡牌
+    pub fn iter(&self) -> impl Iterator<Item = &Card> {
+        self.cards.values()
+    }
+
+    /// 以 ID 到卡牌的映射形式获取全部卡牌
+    pub fn cards(&self) -> &HashMap<CardId, Card> {
+        &self.cards
+    }
+}
+
+impl FromIterator<Card> for CardDatabase {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        let mut database = Self::new();
+        for card in iter {
+            database.insert(card);
+        }
+        database
+    }
+}
+
+impl From<CardDatabase> for HashMap<CardId, Card> {
+    fn from(database: CardDatabase) -> Self {
+        database.cards
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::card::EnergyType;
+    use crate::test_fixtures::basic_energy;
+
+    fn energy(set: &str, number: &str) -> Card {
+        Card {
+            id: Card::printing_id(set, number),
+            set_name: set.to_string(),
+            set_number: number.to_string(),
+            ..basic_energy(EnergyType::Fire)
+        }
+    }
+
+    #[test]
+    fn test_same_printing_same_id() {
+        let first = energy("Base Set", "98");
+        let second = energy("Base Set", "98");
+        assert_eq!(first.id, second.id);
+        assert_ne!(first.id, energy("Base Set", "99").id);
+        assert_ne!(energy("Base Set", "9").id, energy("Base Set9", "").id);
+
+        let database: CardDatabase = [first, second, energy("Jungle", "98")].into_iter().collect();
+        assert_eq!(database.len(), 2);
+        let card = database.get_by_printing("Base Set", "98").unwrap();
+        assert_eq!(card.id, Card::printing_id("Base Set", "98"));
+        assert!(database.get_by_printing("Base Set", "99").is_none());
+        assert_eq!(database.find_by_name("Fire Energy").len(), 2);
+        assert!(database.find_by_name("Water Energy").is_empty());
+    }
+}
@@ -1,9 +1,18 @@
 //! 宝可梦卡牌特定功能
 
-use crate::core::card::{Attack, Ability, CardId, CardType, CardRarity, EnergyType};
+use crate::core::card::{Attack, Ability, CardId, CardType, CardRarity, EnergyType, EvolutionStage};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+/// 攻击能量费用的检查结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AttackCostCheck<'a> {
+    /// 能量足够的攻击及其索引
+    pub usable: Vec<(usize, &'a Attack)>,
+    /// 能量不足的攻击、其索引及每种能量的缺口数量
+    pub unusable: Vec<(usize, &'a Attack, HashMap<EnergyType, usize>)>,
+}
+
 /// 主卡牌结构
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Card {
@@ -11,6 +20,9 @@ pub struct Card {
     pub id: CardId,
     /// 卡牌名称
     pub name: String,
+    /// 各语言的卡牌名称（语言代码 → 名称，如 "zh" → "皮卡丘"）
+    #[serde(default)]
+    pub names: HashMap<String, String>,
     /// 卡牌类型（宝可梦、能量、训练家）
     pub card_type: CardType,
     /// 所属卡包信息
@@ -30,17 +42,30 @@ pub struct Card {
 }
 
 impl Card {
-    /// 使用给定参数创建新卡牌
+    /// 使用给定参数创建新卡牌（随机ID）
     pub fn new(
         name: String,
         card_type: CardType,
         set_name: String,
         set_number: String,
         rarity: CardRarity,
+    ) -> Self {
+        Self::with_id(uuid::Uuid::new_v4(), name, card_type, set_name, set_number, rarity)
+    }
+
+    /// 使用指定ID创建新卡牌
+    pub fn with_id(
+        id: CardId,
+        name: String,
+        card_type: CardType,
+        set_name: String,
+        set_number: String,
+        rarity: CardRarity,
     ) -> Self {
         Self {
-            id: uuid::Uuid::new_v4(),
+            id,
             name,
+            names: HashMap::new(),
             card_type,
             set_name,
             set_number,
@@ -52,11 +77,56 @@ impl Card {
         }
     }
 
+    /// 根据卡包名称和编号生成确定的卡牌ID
+    ///
+    /// 同一印刷版本（卡包 + 编号）总是得到相同的ID，与导入次数和平台无关。
+    pub fn printing_id(set_name: &str, set_number: &str) -> CardId {
+        // 两个不同初始值的 FNV-1a 哈希组成128位
+        let fnv = |basis: u64| {
+            set_name
+                .bytes()
+                .chain(std::iter::once(0xff))
+                .chain(set_number.bytes())
+                .fold(basis, |hash, byte| {
+                    (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+                })
+        };
+        let high = fnv(0xcbf2_9ce4_8422_2325);
+        let low = fnv(0x8422_2325_cbf2_9ce4);
+
+        let mut bytes = [0u8; 16];
+        bytes[..8].copy_from_slice(&high.to_be_bytes());
+        bytes[8..].copy_from_slice(&low.to_be_bytes());
+        uuid::Builder::from_custom_bytes(bytes).into_uuid()
+    }
+
     /// 检查是否为宝可梦卡
     pub fn is_pokemon(&self) -> bool {
         matches!(self.card_type, CardType::Pokemon { .. })
     }
 
+    /// 检查是否为基础宝可梦（可以直接放入备战区）
+    pub fn is_basic_pokemon(&self) -> bool {
+        matches!(
+            self.card_type,
+            CardType::Pokemon {
+                stage: EvolutionStage::Basic,
+                ..
+            }
+        )
+    }
+
+    /// 检查此卡是否可以从 `base` 进化而来（`evolves_from` 与其名称相同）
+    pub fn evolves_from_card(&self, base: &Card) -> bool {
+        match &self.card_type {
+            CardType::Pokemon {
+                evolves_from: Some(evolves_from),
+                ..
+            } => base.is_pokemon() && *evolves_from == base.name,
+            _ => false,
+        }
+    }
+
     /// 检查是否为能量卡
     pub fn is_energy(&self) -> bool {
         matches!(self.card_type, CardType::Energy { .. })
@@ -75,6 +145,20 @@ impl Card {
         }
     }
 
+    /// 此宝可梦被击倒时对手获得的奖赏卡数量
+    ///
+    /// EX、GX、V 为2张，VMAX 为3张，其他宝可梦为1张；非宝可梦卡为0。
+    pub fn prize_value(&self) -> u32 {
+        match &self.card_type {
+            CardType::Pokemon { stage, .. } => match stage {
+                EvolutionStage::EX | EvolutionStage::GX | EvolutionStage::V => 2,
+                EvolutionStage::VMax => 3,
+                _ => 1,
+            },
+            _ => 0,
+        }
+    }
+
     /// 获取能量卡的能量类型
     pub fn get_energy_type(&self) -> Option<&EnergyType> {
         match &self.card_type {
@@ -83,6 +167,35 @@ impl Card {
         }
     }
 
+    /// 能量卡提供的能量（每个元素为一个能量单位）
+    ///
+    /// 特殊能量可以在元数据 `provides` 中用逗号分隔列出提供的能量，例如双重无色能量
+    /// 为 `"Colorless,Colorless"`；否则提供一个自身类型的能量。非能量卡返回空列表。
+    pub fn energy_provides(&self) -> Vec<EnergyType> {
+        let Some(energy_type) = self.get_energy_type() else {
+            return Vec::new();
+        };
+
+        match self.metadata.get("provides") {
+            Some(provides) => provides
+                .split(',')
+                .filter_map(|energy| energy.parse().ok())
+                .collect(),
+            None => vec![energy_type.clone()],
+        }
+    }
+
+    /// 获取宝可梦的属性（非宝可梦卡或未设置属性时返回None）
+    ///
+    /// 只读取 `CardType::Pokemon` 的 `pokemon_type` 字段；旧数据的属性推断
+    /// 由导入器负责。
+    pub fn pokemon_type(&self) -> Option<EnergyType> {
+        match &self.card_type {
+            CardType::Pokemon { pokemon_type, .. } => pokemon_type.clone(),
+            _ => None,
+        }
+    }
+
     /// 向宝可梦卡添加攻击
     pub fn add_attack(&mut self, attack: Attack) {
         if self.is_pokemon() {
@@ -102,6 +215,16 @@ impl Card {
         self.rules.push(rule);
     }
 
+    /// 设置某个语言的卡牌名称
+    pub fn set_localized_name(&mut self, locale: &str, name: &str) {
+        self.names.insert(locale.to_string(), name.to_string());
+    }
+
+    /// 获取某个语言的卡牌名称，没有该语言时返回 `name`
+    pub fn localized_name(&self, locale: &str) -> &str {
+        self.names.get(locale).map_or(&self.name, String::as_str)
+    }
+
     /// 向卡牌添加元数据
     pub fn add_metadata(&mut self, key: String, value: String) {
         self.metadata.insert(key, value);
@@ -118,42 +241,77 @@ impl Card {
         counts
     }
 
-    /// 获取满足能量需求的攻击数组
+    /// 计算支付能量费用还缺少的能量
+    ///
+    /// 先用同类型能量支付非无色的需求，剩余的能量（任意类型）再支付无色需求，
+    /// 每个能量只使用一次。返回每种能量类型的缺口，为空表示可以支付。
+    pub fn energy_shortfall(
+        cost: &[EnergyType],
+        attached_energy: &[EnergyType],
+    ) -> HashMap<EnergyType, usize> {
+        let mut remaining = Self::count_energy_types(attached_energy);
+        let mut shortfall = HashMap::new();
+        let mut colorless_required = 0;
+
+        for energy_type in cost {
+            if *energy_type == EnergyType::Colorless {
+                colorless_required += 1;
+                continue;
+            }
+            match remaining.get_mut(energy_type) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => *shortfall.entry(energy_type.clone()).or_insert(0) += 1,
+            }
+        }
+
+        let leftover: usize = remaining.values().sum();
+        if colorless_required > leftover {
+            shortfall.insert(EnergyType::Colorless, colorless_required - leftover);
+        }
+
+        shortfall
+    }
+
+    /// 按附加的能量检查每个攻击的费用
     ///
     /// # 参数
     /// * `attached_energy` - 附加到宝可梦的能量类型列表
     ///
     /// # 返回值
-    /// 返回可以使用的攻击列表及其索引
-    pub fn get_usable_attacks(
-        &self,
-        attached_energy: &[EnergyType],
-    ) -> Vec<(usize, &Attack)> {
+    /// 可以使用的攻击，以及不能使用的攻击和各类型能量的缺口
+    pub fn check_attack_costs(&self, attached_energy: &[EnergyType]) -> AttackCostCheck<'_> {
+        let mut check = AttackCostCheck {
+            usable: Vec::new(),
+            unusable: Vec::new(),
+        };
         if !self.is_pokemon() {
-            return Vec::new();
+            return check;
         }
 
-        let attached_counts = Self::count_energy_types(attached_energy);
-        let mut usable_attacks = Vec::new();
-
         for (index, attack) in self.attacks.iter().enumerate() {
-            let required_counts = Self::count_energy_types(&attack.cost);
-
-            let mut can_use = true;
-            for (energy_type, &required_count) in &required_counts {
-                let attached_count = attached_counts.get(energy_type).cloned().unwrap_or(0);
-                if attached_count < required_count {
-                    can_use = false;
-                    break;
-                }
-            }
-
-            if can_use {
-                usable_attacks.push((index, attack));
+            let shortfall = Self::energy_shortfall(&attack.cost, attached_energy);
+            if shortfall.is_empty() {
+                check.usable.push((index, attack));
+            } else {
+                check.unusable.push((index, attack, shortfall));
             }
         }
 
-        usable_attacks
+        check
+    }
+
+    /// 获取满足能量需求的攻击数组
+    ///
+    /// # 参数
+    /// * `attached_energy` - 附加到宝可梦的能量类型列表
+    ///
+    /// # 返回值
+    /// 返回可以使用的攻击列表及其索引
+    pub fn get_usable_attacks(
+        &self,
+        attached_energy: &[EnergyType],
+    ) -> Vec<(usize, &Attack)> {
+        self.check_attack_costs(attached_energy).usable
     }
 }
 
@@ -167,6 +325,7 @@ mod tests {
         let card_type = CardType::Pokemon {
             species: "Pikachu".to_string(),
             hp: 60,
+            pokemon_type: None,
             retreat_cost: 1,
             weakness: Some(EnergyType::Fighting),
             resistance: None,
@@ -189,6 +348,33 @@ mod tests {
         assert_eq!(card.get_hp(), Some(60));
     }
 
+    #[test]
+    fn test_localized_name() {
+        let mut card = Card::new(
+            "Pikachu".to_string(),
+            CardType::Pokemon {
+                species: "Pikachu".to_string(),
+                hp: 60,
+                pokemon_type: None,
+                retreat_cost: 1,
+                weakness: None,
+                resistance: None,
+                stage: EvolutionStage::Basic,
+                evolves_from: None,
+            },
+            "Base Set".to_string(),
+            "58".to_string(),
+            CardRarity::Common,
+        );
+        card.set_localized_name("zh", "皮卡丘");
+        card.set_localized_name("en", "Pikachu");
+
+        assert_eq!(card.localized_name("zh"), "皮卡丘");
+        assert_eq!(card.localized_name("en"), "Pikachu");
+        // 没有日文名称时使用默认名称
+        assert_eq!(card.localized_name("ja"), "Pikachu");
+    }
+
     #[test]
     fn test_create_energy_card() {
         let card_type = CardType::Energy {
@@ -215,6 +401,7 @@ mod tests {
         let card_type = CardType::Pokemon {
             species: "Pikachu".to_string(),
             hp: 60,
+            pokemon_type: None,
             retreat_cost: 1,
             weakness: Some(EnergyType::Fighting),
             resistance: None,
@@ -239,4 +426,52 @@ mod tests {
         card.add_attack(attack);
         assert_eq!(card.attacks.len(), 1);
     }
+
+    #[test]
+    fn test_multi_type_attack_cost() {
+        let mut card = Card::new(
+            "Volcanion".to_string(),
+            CardType::Pokemon {
+                species: "Volcanion".to_string(),
+                hp: 130,
+                pokemon_type: None,
+                retreat_cost: 2,
+                weakness: Some(EnergyType::Water),
+                resistance: None,
+                stage: EvolutionStage::Basic,
+                evolves_from: None,
+            },
+            "Steam Siege".to_string(),
+            "25".to_string(),
+            CardRarity::Rare,
+        );
+        card.add_attack(Attack::simple(
+            "Steam Artillery".to_string(),
+            vec![EnergyType::Fire, EnergyType::Fire, EnergyType::Water],
+            100,
+        ));
+        card.add_attack(Attack::simple(
+            "Flare Strike".to_string(),
+            vec![EnergyType::Fire, EnergyType::Colorless, EnergyType::Colorless],
+            60,
+        ));
+
+        let check = card.check_attack_costs(&[EnergyType::Fire, EnergyType::Fire, EnergyType::Water]);
+        assert_eq!(check.usable.len(), 2);
+        assert!(check.unusable.is_empty());
+
+        let check = card.check_attack_costs(&[EnergyType::Fire, EnergyType::Water, EnergyType::Water]);
+        assert_eq!(check.usable.iter().map(|(index, _)| *index).collect::<Vec<_>>(), vec![1]);
+        assert_eq!(check.unusable.len(), 1);
+        let (index, _, shortfall) = &check.unusable[0];
+        assert_eq!(*index, 0);
+        assert_eq!(*shortfall, HashMap::from([(EnergyType::Fire, 1)]));
+
+        // 同一个能量不能同时支付火和无色
+        let shortfall = Card::energy_shortfall(
+            &[EnergyType::Fire, EnergyType::Colorless, EnergyType::Colorless],
+            &[EnergyType::Fire, EnergyType::Fire],
+        );
+        assert_eq!(shortfall, HashMap::from([(EnergyType::Colorless, 1)]));
+    }
 }
\ No newline at end of file
@@ -1,6 +1,8 @@
 //! 核心卡牌类型和枚举
 
 use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
 use uuid::Uuid;
 
 /// 卡牌的唯一标识符
@@ -15,6 +17,9 @@ pub enum CardType {
         species: String,
         /// 生命值
         hp: u32,
+        /// 宝可梦的属性（卡牌颜色），用于弱点和抗性判定
+        #[serde(default)]
+        pokemon_type: Option<EnergyType>,
         /// 撤退费用（撤退所需的能量）
         retreat_cost: u32,
         /// 弱点（造成双倍伤害的类型）
@@ -56,6 +61,97 @@ pub enum EnergyType {
     Colorless,  // 无色
 }
 
+impl EnergyType {
+    /// 按标准顺序返回所有能量类型
+    pub fn all() -> [EnergyType; 11] {
+        [
+            EnergyType::Grass,
+            EnergyType::Fire,
+            EnergyType::Water,
+            EnergyType::Lightning,
+            EnergyType::Psychic,
+            EnergyType::Fighting,
+            EnergyType::Darkness,
+            EnergyType::Metal,
+            EnergyType::Fairy,
+            EnergyType::Dragon,
+            EnergyType::Colorless,
+        ]
+    }
+
+    /// 能量的标准单字符符号（如火为 'R'，电为 'L'）
+    pub fn symbol(&self) -> char {
+        match self {
+            EnergyType::Grass => 'G',
+            EnergyType::Fire => 'R',
+            EnergyType::Water => 'W',
+            EnergyType::Lightning => 'L',
+            EnergyType::Psychic => 'P',
+            EnergyType::Fighting => 'F',
+            EnergyType::Darkness => 'D',
+            EnergyType::Metal => 'M',
+            EnergyType::Fairy => 'Y',
+            EnergyType::Dragon => 'N',
+            EnergyType::Colorless => 'C',
+        }
+    }
+
+    /// 根据单字符符号解析能量类型（不区分大小写）
+    pub fn from_symbol(c: char) -> Option<EnergyType> {
+        Self::all()
+            .into_iter()
+            .find(|energy| energy.symbol() == c.to_ascii_uppercase())
+    }
+
+    /// 能量类型的英文名称
+    pub fn name(&self) -> &'static str {
+        match self {
+            EnergyType::Grass => "Grass",
+            EnergyType::Fire => "Fire",
+            EnergyType::Water => "Water",
+            EnergyType::Lightning => "Lightning",
+            EnergyType::Psychic => "Psychic",
+            EnergyType::Fighting => "Fighting",
+            EnergyType::Darkness => "Darkness",
+            EnergyType::Metal => "Metal",
+            EnergyType::Fairy => "Fairy",
+            EnergyType::Dragon => "Dragon",
+            EnergyType::Colorless => "Colorless",
+        }
+    }
+}
+
+impl fmt::Display for EnergyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for EnergyType {
+    type Err = String;
+
+    /// 支持英文名称（"Fire"）、单字符符号（"R"）以及花括号符号（"{R}"），不区分大小写
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let inner = trimmed
+            .strip_prefix('{')
+            .and_then(|rest| rest.strip_suffix('}'))
+            .unwrap_or(trimmed);
+
+        let mut chars = inner.chars();
+        if let (Some(c), None) = (chars.next(), chars.next())
+            && let Some(energy) = Self::from_symbol(c)
+        {
+            return Ok(energy);
+        }
+
+        Self::all()
+            .into_iter()
+            .find(|energy| energy.name().eq_ignore_ascii_case(inner))
+            .ok_or_else(|| format!("Unknown energy type: {}", s))
+    }
+}
+
 /// 宝可梦的进化阶段
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EvolutionStage {
@@ -101,6 +197,20 @@ mod tests {
         assert_ne!(grass, fire);
     }
 
+    #[test]
+    fn test_energy_type_round_trip() {
+        for energy in EnergyType::all() {
+            assert_eq!(EnergyType::from_symbol(energy.symbol()), Some(energy.clone()));
+            assert_eq!(energy.to_string().parse::<EnergyType>(), Ok(energy.clone()));
+            assert_eq!(format!("{{{}}}", energy.symbol()).parse::<EnergyType>(), Ok(energy.clone()));
+        }
+
+        assert_eq!("fire".parse::<EnergyType>(), Ok(EnergyType::Fire));
+        assert_eq!(EnergyType::from_symbol('X'), None);
+        assert_eq!(EnergyType::from_symbol('?'), None);
+        assert!("Plasma".parse::<EnergyType>().is_err());
+    }
+
     #[test]
     fn test_evolution_stages() {
         let basic = EvolutionStage::Basic;
@@ -15,6 +15,24 @@ pub struct Deck {
     pub cards: HashMap<CardId, u32>,
 }
 
+/// 两个牌组版本之间的差异
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeckDiff {
+    /// 只在新牌组中出现的卡牌及其数量
+    pub added: HashMap<CardId, u32>,
+    /// 只在旧牌组中出现的卡牌及其数量
+    pub removed: HashMap<CardId, u32>,
+    /// 两边都有但数量不同的卡牌：(旧数量, 新数量)
+    pub changed: HashMap<CardId, (u32, u32)>,
+}
+
+impl DeckDiff {
+    /// 两个牌组的卡牌是否完全相同
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
 impl Deck {
     /// 创建一个新的空牌组
     pub fn new(name: String, format: String) -> Self {
@@ -47,6 +65,26 @@ impl Deck {
         }
     }
 
+    /// 把另一个牌组的卡牌数量加到这个牌组上
+    ///
+    /// 名称和格式保持 `self` 的不变。
+    pub fn merge(&mut self, other: &Deck) {
+        for (&card_id, &count) in &other.cards {
+            self.add_card(card_id, count);
+        }
+    }
+
+    /// 把多个卡池合并成一个牌组（限制赛构筑用）
+    ///
+    /// 名称和格式使用传入的值，各卡池自己的名称和格式被忽略。
+    pub fn from_pools(name: String, format: String, pools: &[Deck]) -> Self {
+        let mut deck = Self::new(name, format);
+        for pool in pools {
+            deck.merge(pool);
+        }
+        deck
+    }
+
     /// 获取牌组中特定卡牌的数量
     pub fn get_card_count(&self, card_id: CardId) -> u32 {
         *self.cards.get(&card_id).unwrap_or(&0)
@@ -67,20 +105,53 @@ impl Deck {
         self.cards.keys().cloned().collect()
     }
 
+    /// 比较两个牌组，`self` 是旧版本，`other` 是新版本
+    ///
+    /// 只比较卡牌和数量，不比较名称和格式。
+    pub fn diff(&self, other: &Deck) -> DeckDiff {
+        let mut diff = DeckDiff::default();
+        for (&card_id, &old_count) in &self.cards {
+            match other.cards.get(&card_id) {
+                None => {
+                    diff.removed.insert(card_id, old_count);
+                }
+                Some(&new_count) if new_count != old_count => {
+                    diff.changed.insert(card_id, (old_count, new_count));
+                }
+                Some(_) => {}
+            }
+        }
+        for (&card_id, &new_count) in &other.cards {
+            if !self.cards.contains_key(&card_id) {
+                diff.added.insert(card_id, new_count);
+            }
+        }
+        diff
+    }
+
     /// 洗牌并返回随机顺序的卡牌ID
     pub fn shuffle(&self) -> Vec<CardId> {
+        self.shuffle_with(&mut rand::thread_rng())
+    }
+
+    /// 用指定的随机数生成器洗牌
+    ///
+    /// 洗牌前先按卡牌ID排序，所以结果只取决于生成器，不受HashMap迭代顺序影响。
+    pub fn shuffle_with<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Vec<CardId> {
         use rand::seq::SliceRandom;
-        use rand::thread_rng;
 
-        let mut cards = Vec::new();
-        for (&card_id, &count) in &self.cards {
-            for _ in 0..count {
-                cards.push(card_id);
-            }
-        }
+        let mut entries: Vec<(CardId, u32)> = self
+            .cards
+            .iter()
+            .map(|(&card_id, &count)| (card_id, count))
+            .collect();
+        entries.sort();
+        let mut cards: Vec<CardId> = entries
+            .into_iter()
+            .flat_map(|(card_id, count)| std::iter::repeat_n(card_id, count as usize))
+            .collect();
 
-        let mut rng = thread_rng();
-        cards.shuffle(&mut rng);
+        cards.shuffle(rng);
         cards
     }
 }
@@ -131,4 +202,55 @@ mod tests {
         // 卡牌数量应该保持不变
         assert_eq!(deck.get_card_count(card_id), 2);
     }
+
+    #[test]
+    fn test_merge_pools() {
+        let shared = Uuid::new_v4();
+        let mut first = Deck::new("卡池一".to_string(), "Sealed".to_string());
+        let mut second = Deck::new("卡池二".to_string(), "Sealed".to_string());
+        first.add_card(shared, 2);
+        second.add_card(shared, 1);
+        for (pool, count) in [(&mut first, 28), (&mut second, 29)] {
+            for _ in 0..count {
+                pool.add_card(Uuid::new_v4(), 1);
+            }
+        }
+        assert_eq!((first.total_cards(), second.total_cards()), (30, 30));
+
+        let pools = [first.clone(), second.clone()];
+        let combined = Deck::from_pools("构筑".to_string(), "Limited".to_string(), &pools);
+        assert_eq!(combined.name, "构筑");
+        assert_eq!(combined.format, "Limited");
+        assert_eq!(combined.total_cards(), 60);
+        assert_eq!(combined.get_card_count(shared), 3);
+        assert_eq!(combined.unique_cards().len(), 58);
+
+        first.merge(&second);
+        assert_eq!(first.name, "卡池一");
+        assert_eq!(first.cards, combined.cards);
+    }
+
+    #[test]
+    fn test_deck_diff() {
+        let (kept, tuned, cut, new) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        let mut old = Deck::new("皮卡丘 v1".to_string(), "Standard".to_string());
+        old.add_card(kept, 4);
+        old.add_card(tuned, 3);
+        old.add_card(cut, 2);
+        let mut updated = Deck::new("皮卡丘 v2".to_string(), "Standard".to_string());
+        updated.add_card(kept, 4);
+        updated.add_card(tuned, 4);
+        updated.add_card(new, 1);
+
+        let diff = old.diff(&updated);
+        assert_eq!(diff.added, HashMap::from([(new, 1)]));
+        assert_eq!(diff.removed, HashMap::from([(cut, 2)]));
+        assert_eq!(diff.changed, HashMap::from([(tuned, (3, 4))]));
+
+        // 反向比较时新增和移除互换
+        let reverse = updated.diff(&old);
+        assert_eq!(reverse.added, diff.removed);
+        assert_eq!(reverse.changed, HashMap::from([(tuned, (4, 3))]));
+        assert!(old.diff(&old.clone()).is_empty());
+    }
 }
\ No newline at end of file
@@ -2,7 +2,9 @@
 
 use crate::core::card::{Card, CardId, CardType, EnergyType};
 use crate::core::deck::Deck;
-use std::collections::HashMap;
+use crate::core::game::GameRules;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 
 /// 牌组统计信息
 #[derive(Debug, Clone)]
@@ -22,7 +24,14 @@ pub enum DeckValidationError {
     /// 牌组卡牌数量过少
     TooFewCards { minimum: u32, actual: u32 },
     /// 卡牌副本数量过多（违反4副本规则）
-    TooManyCopies { card_id: CardId, maximum: u32, actual: u32 },
+    ///
+    /// 按名称计数时 `card_id` 为该名称下ID最小的卡牌，`actual` 为所有版本的总数。
+    TooManyCopies {
+        card_id: CardId,
+        name: String,
+        maximum: u32,
+        actual: u32,
+    },
     /// 牌组卡牌数量过多
     TooManyCards { maximum: u32, actual: u32 },
     /// 牌组中没有基础宝可梦
@@ -31,6 +40,43 @@ pub enum DeckValidationError {
     TooManyBasicPokemon { maximum: u32, actual: u32 },
 }
 
+/// 牌组验证警告：牌组合法，但构成可能有问题
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeckValidationWarning {
+    /// 宝可梦数量过少
+    TooFewPokemon { minimum: u32, actual: u32 },
+    /// 能量卡数量过少
+    TooFewEnergy { minimum: u32, actual: u32 },
+}
+
+/// 产生牌组警告的阈值，由比赛主办方按格式设定
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeckWarningThresholds {
+    /// 宝可梦少于此数量时警告
+    pub min_pokemon: u32,
+    /// 能量卡少于此数量时警告
+    pub min_energy: u32,
+}
+
+impl Default for DeckWarningThresholds {
+    fn default() -> Self {
+        Self {
+            min_pokemon: 8,
+            min_energy: 6,
+        }
+    }
+}
+
+/// 副本上限的计数方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CopyLimitMode {
+    /// 每张卡牌（每个印刷版本）单独计数
+    #[default]
+    ById,
+    /// 同名卡牌跨卡包、跨版本合并计数（正式比赛规则）
+    ByName,
+}
+
 impl Deck {
     /// 获取牌组统计信息
     pub fn get_statistics(&self, card_database: &HashMap<CardId, Card>) -> DeckStatistics {
@@ -72,6 +118,62 @@ impl Deck {
 
     /// 根据标准PTCG规则验证牌组
     pub fn validate(&self, card_database: &HashMap<CardId, Card>) -> Result<(), Vec<DeckValidationError>> {
+        self.validate_with_copy_limit(card_database, 4, CopyLimitMode::ById, &HashSet::new())
+    }
+
+    /// 根据游戏规则验证牌组（例如GLC格式的单卡限制）
+    ///
+    /// `rules.unlimited_copies` 中的卡牌（按卡包名称和编号）不受副本上限限制。
+    pub fn validate_for_rules(
+        &self,
+        card_database: &HashMap<CardId, Card>,
+        rules: &GameRules,
+    ) -> Result<(), Vec<DeckValidationError>> {
+        self.validate_with_copy_limit(
+            card_database,
+            rules.max_copies_per_card,
+            rules.copy_limit_by,
+            &rules.unlimited_copies,
+        )
+    }
+
+    /// 按游戏规则验证牌组，并按阈值给出警告
+    ///
+    /// 返回 (错误, 警告)。只有警告的牌组仍然合法。
+    pub fn validate_with_warnings(
+        &self,
+        card_database: &HashMap<CardId, Card>,
+        rules: &GameRules,
+        thresholds: &DeckWarningThresholds,
+    ) -> (Vec<DeckValidationError>, Vec<DeckValidationWarning>) {
+        let errors = self.validate_for_rules(card_database, rules).err().unwrap_or_default();
+
+        let mut warnings = Vec::new();
+        let stats = self.get_statistics(card_database);
+        if stats.pokemon_count < thresholds.min_pokemon {
+            warnings.push(DeckValidationWarning::TooFewPokemon {
+                minimum: thresholds.min_pokemon,
+                actual: stats.pokemon_count,
+            });
+        }
+        if stats.energy_count < thresholds.min_energy {
+            warnings.push(DeckValidationWarning::TooFewEnergy {
+                minimum: thresholds.min_energy,
+                actual: stats.energy_count,
+            });
+        }
+
+        (errors, warnings)
+    }
+
+    /// 使用指定的副本上限和计数方式验证牌组
+    fn validate_with_copy_limit(
+        &self,
+        card_database: &HashMap<CardId, Card>,
+        max_copies: u32,
+        copy_limit_by: CopyLimitMode,
+        unlimited_copies: &HashSet<(String, String)>,
+    ) -> Result<(), Vec<DeckValidationError>> {
         let mut errors = Vec::new();
 
         // 检查最小牌组大小（通常为60张卡牌）
@@ -91,22 +193,45 @@ impl Deck {
             });
         }
 
-        // 检查4副本规则（除基本能量卡外，任何卡牌最多4张）
+        // 检查副本规则（除基本能量卡和豁免卡牌外，任何卡牌最多max_copies张）
+        // 按计数方式分组：键为卡牌ID或卡牌名称，值为（代表卡牌ID，名称，总数）
+        let mut copies: HashMap<String, (CardId, &str, u32)> = HashMap::new();
         for (&card_id, &count) in &self.cards {
             if let Some(card) = card_database.get(&card_id) {
-                // 基本能量卡不受4副本规则限制
+                // 基本能量卡不受副本规则限制
                 let is_basic_energy = matches!(card.card_type, CardType::Energy { is_basic: true, .. });
-                
-                if !is_basic_energy && count > 4 {
-                    errors.push(DeckValidationError::TooManyCopies {
-                        card_id,
-                        maximum: 4,
-                        actual: count,
-                    });
+                if is_basic_energy {
+                    continue;
+                }
+                // 明确允许任意张数的卡牌
+                if unlimited_copies.contains(&(card.set_name.clone(), card.set_number.clone())) {
+                    continue;
                 }
+
+                let key = match copy_limit_by {
+                    CopyLimitMode::ById => card_id.to_string(),
+                    CopyLimitMode::ByName => card.name.clone(),
+                };
+                let entry = copies.entry(key).or_insert((card_id, &card.name, 0));
+                entry.0 = entry.0.min(card_id);
+                entry.2 += count;
             }
         }
 
+        let mut over_limit: Vec<_> = copies
+            .into_values()
+            .filter(|&(_, _, count)| count > max_copies)
+            .collect();
+        over_limit.sort();
+        for (card_id, name, count) in over_limit {
+            errors.push(DeckValidationError::TooManyCopies {
+                card_id,
+                name: name.to_string(),
+                maximum: max_copies,
+                actual: count,
+            });
+        }
+
         // 检查是否有基础宝可梦
         let stats = self.get_statistics(card_database);
         if stats.basic_pokemon_count == 0 {
@@ -145,6 +270,7 @@ mod tests {
             CardType::Pokemon {
                 species: "Pikachu".to_string(),
                 hp: 60,
+                pokemon_type: None,
                 retreat_cost: 1,
                 weakness: Some(EnergyType::Fighting),
                 resistance: None,
@@ -209,6 +335,7 @@ mod tests {
             CardType::Pokemon {
                 species: "Pikachu".to_string(),
                 hp: 60,
+                pokemon_type: None,
                 retreat_cost: 1,
                 weakness: Some(EnergyType::Fighting),
                 resistance: None,
@@ -257,6 +384,7 @@ mod tests {
             CardType::Pokemon {
                 species: "Pikachu".to_string(),
                 hp: 60,
+                pokemon_type: None,
                 retreat_cost: 1,
                 weakness: Some(EnergyType::Fighting),
                 resistance: None,
@@ -297,4 +425,259 @@ mod tests {
         assert_eq!(errors.len(), 1);
         assert!(matches!(errors[0], DeckValidationError::TooFewCards { .. }));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_glc_rules_enforce_singleton() {
+        let mut deck = Deck::new("GLC Deck".to_string(), "GLC".to_string());
+        let mut card_database = HashMap::new();
+
+        let pokemon_card = Card::new(
+            "Pikachu".to_string(),
+            CardType::Pokemon {
+                species: "Pikachu".to_string(),
+                hp: 60,
+                pokemon_type: None,
+                retreat_cost: 1,
+                weakness: Some(EnergyType::Fighting),
+                resistance: None,
+                stage: EvolutionStage::Basic,
+                evolves_from: None,
+            },
+            "Base Set".to_string(),
+            "025".to_string(),
+            CardRarity::Common,
+        );
+        let energy_card = Card::new(
+            "Lightning Energy".to_string(),
+            CardType::Energy {
+                energy_type: EnergyType::Lightning,
+                is_basic: true,
+            },
+            "Base Set".to_string(),
+            "100".to_string(),
+            CardRarity::Common,
+        );
+
+        let pokemon_id = pokemon_card.id;
+        let energy_id = energy_card.id;
+        card_database.insert(pokemon_id, pokemon_card);
+        card_database.insert(energy_id, energy_card);
+
+        deck.add_card(pokemon_id, 2);
+        deck.add_card(energy_id, 58);
+
+        // 标准规则允许2张副本
+        assert!(deck.validate_for_rules(&card_database, &GameRules::standard()).is_ok());
+
+        // GLC规则只允许1张副本
+        let errors = deck
+            .validate_for_rules(&card_database, &GameRules::glc())
+            .unwrap_err();
+        assert_eq!(
+            errors,
+            vec![DeckValidationError::TooManyCopies {
+                card_id: pokemon_id,
+                name: "Pikachu".to_string(),
+                maximum: 1,
+                actual: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_copy_limit_by_name_across_sets() {
+        let mut deck = Deck::new("Research Deck".to_string(), "Standard".to_string());
+        let mut card_database = HashMap::new();
+
+        let research = |set: &str| {
+            Card::new(
+                "Professor's Research".to_string(),
+                CardType::Trainer {
+                    trainer_type: TrainerType::Supporter,
+                },
+                set.to_string(),
+                "178".to_string(),
+                CardRarity::Rare,
+            )
+        };
+        let pokemon_card = Card::new(
+            "Pikachu".to_string(),
+            CardType::Pokemon {
+                species: "Pikachu".to_string(),
+                hp: 60,
+                pokemon_type: None,
+                retreat_cost: 1,
+                weakness: Some(EnergyType::Fighting),
+                resistance: None,
+                stage: EvolutionStage::Basic,
+                evolves_from: None,
+            },
+            "Base Set".to_string(),
+            "025".to_string(),
+            CardRarity::Common,
+        );
+        let energy_card = Card::new(
+            "Lightning Energy".to_string(),
+            CardType::Energy {
+                energy_type: EnergyType::Lightning,
+                is_basic: true,
+            },
+            "Base Set".to_string(),
+            "100".to_string(),
+            CardRarity::Common,
+        );
+
+        let (set_a, set_b) = (research("Sword & Shield"), research("Celebrations"));
+        let (set_a_id, set_b_id) = (set_a.id, set_b.id);
+        deck.add_card(set_a_id, 3);
+        deck.add_card(set_b_id, 2);
+        deck.add_card(pokemon_card.id, 4);
+        deck.add_card(energy_card.id, 51);
+        for card in [set_a, set_b, pokemon_card, energy_card] {
+            card_database.insert(card.id, card);
+        }
+
+        // 按ID计数时每个版本都没有超过4张
+        let by_id = GameRules::default();
+        assert_eq!(by_id.copy_limit_by, CopyLimitMode::ById);
+        assert!(deck.validate_for_rules(&card_database, &by_id).is_ok());
+
+        // 按名称计数时两个版本合计5张
+        let errors = deck
+            .validate_for_rules(&card_database, &GameRules::standard())
+            .unwrap_err();
+        assert_eq!(
+            errors,
+            vec![DeckValidationError::TooManyCopies {
+                card_id: set_a_id.min(set_b_id),
+                name: "Professor's Research".to_string(),
+                maximum: 4,
+                actual: 5,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_unlimited_copies_exemption() {
+        let mut deck = Deck::new("Test Deck".to_string(), "Standard".to_string());
+        let mut card_database = HashMap::new();
+        let trainer = |name: &str, number: &str| {
+            Card::new(
+                name.to_string(),
+                CardType::Trainer {
+                    trainer_type: TrainerType::Item,
+                },
+                "Test".to_string(),
+                number.to_string(),
+                CardRarity::Common,
+            )
+        };
+        let pokemon_card = Card::new(
+            "Pikachu".to_string(),
+            CardType::Pokemon {
+                species: "Pikachu".to_string(),
+                hp: 60,
+                pokemon_type: None,
+                retreat_cost: 1,
+                weakness: None,
+                resistance: None,
+                stage: EvolutionStage::Basic,
+                evolves_from: None,
+            },
+            "Base Set".to_string(),
+            "025".to_string(),
+            CardRarity::Common,
+        );
+        let energy_card = Card::new(
+            "Lightning Energy".to_string(),
+            CardType::Energy {
+                energy_type: EnergyType::Lightning,
+                is_basic: true,
+            },
+            "Base Set".to_string(),
+            "100".to_string(),
+            CardRarity::Common,
+        );
+
+        let (exempt, limited) = (trainer("Arceus Rule Item", "1"), trainer("Potion", "2"));
+        let (limited_id, energy_id) = (limited.id, energy_card.id);
+        deck.add_card(exempt.id, 10);
+        deck.add_card(limited_id, 5);
+        deck.add_card(pokemon_card.id, 4);
+        deck.add_card(energy_card.id, 41);
+        for card in [exempt, limited, pokemon_card, energy_card] {
+            card_database.insert(card.id, card);
+        }
+
+        // 10张豁免卡牌不报错，5张普通卡牌仍然超出上限
+        let rules = GameRules::builder().unlimited_copies("Test", "1").build().unwrap();
+        let errors = deck.validate_for_rules(&card_database, &rules).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![DeckValidationError::TooManyCopies {
+                card_id: limited_id,
+                name: "Potion".to_string(),
+                maximum: 4,
+                actual: 5,
+            }]
+        );
+
+        // 减到4张后牌组合法
+        deck.remove_card(limited_id, 1);
+        deck.add_card(energy_id, 1);
+        assert!(deck.validate_for_rules(&card_database, &rules).is_ok());
+        assert!(deck.validate(&card_database).is_err());
+    }
+    #[test]
+    fn test_low_pokemon_count_is_only_a_warning() {
+        let mut deck = Deck::new("能量过多".to_string(), "Standard".to_string());
+        let mut card_database = HashMap::new();
+        let pokemon_card = Card::new(
+            "Pikachu".to_string(),
+            CardType::Pokemon {
+                species: "Pikachu".to_string(),
+                hp: 60,
+                pokemon_type: None,
+                retreat_cost: 1,
+                weakness: None,
+                resistance: None,
+                stage: EvolutionStage::Basic,
+                evolves_from: None,
+            },
+            "Base Set".to_string(),
+            "025".to_string(),
+            CardRarity::Common,
+        );
+        let energy_card = Card::new(
+            "Lightning Energy".to_string(),
+            CardType::Energy {
+                energy_type: EnergyType::Lightning,
+                is_basic: true,
+            },
+            "Base Set".to_string(),
+            "100".to_string(),
+            CardRarity::Common,
+        );
+        deck.add_card(pokemon_card.id, 4);
+        deck.add_card(energy_card.id, 56);
+        for card in [pokemon_card, energy_card] {
+            card_database.insert(card.id, card);
+        }
+
+        let rules = GameRules::default();
+        let (errors, warnings) =
+            deck.validate_with_warnings(&card_database, &rules, &DeckWarningThresholds::default());
+        assert!(errors.is_empty());
+        assert_eq!(
+            warnings,
+            vec![DeckValidationWarning::TooFewPokemon { minimum: 8, actual: 4 }]
+        );
+
+        // 阈值可以按格式调整
+        let relaxed = DeckWarningThresholds {
+            min_pokemon: 4,
+            ..DeckWarningThresholds::default()
+        };
+        assert_eq!(deck.validate_with_warnings(&card_database, &rules, &relaxed), (Vec::new(), Vec::new()));
+    }
+}
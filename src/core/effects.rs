@@ -9,6 +9,7 @@ pub mod outcomes;
 pub mod pokemon_effects;
 pub mod trainer_effects;
 pub mod energy_effects;
+pub mod parser;
 
 // 重新导出常用类型
 pub use manager::*;
@@ -17,6 +18,7 @@ pub use targets::*;
 pub use pokemon_effects::*;
 pub use trainer_effects::*;
 pub use energy_effects::*;
+pub use parser::*;
 
 #[cfg(test)]
 mod tests {
@@ -7,6 +7,7 @@ use crate::core::player::PlayerId;
 use std::collections::HashMap;
 
 /// 效果管理器，用于处理所有与效果相关的操作
+#[derive(Clone)]
 pub struct EffectManager {
     /// 所有已注册的效果
     effects: HashMap<EffectId, Box<dyn Effect>>,
@@ -53,11 +54,11 @@ impl EffectManager {
 
     /// 从卡牌上移除效果
     pub fn detach_effect(&mut self, card_id: CardId, effect_id: EffectId) -> Result<(), EffectError> {
-        if let Some(effects) = self.active_effects.get_mut(&card_id) {
-            if let Some(pos) = effects.iter().position(|&id| id == effect_id) {
-                effects.remove(pos);
-                return Ok(());
-            }
+        if let Some(effects) = self.active_effects.get_mut(&card_id)
+            && let Some(pos) = effects.iter().position(|&id| id == effect_id)
+        {
+            effects.remove(pos);
+            return Ok(());
         }
         
         Err(EffectError::General { 
@@ -96,10 +97,10 @@ impl EffectManager {
         
         for (card_id, effect_ids) in &self.active_effects {
             for effect_id in effect_ids {
-                if let Some(effect) = self.effects.get(effect_id) {
-                    if effect.triggers().contains(&trigger) {
-                        result.push((effect.as_ref(), *card_id));
-                    }
+                if let Some(effect) = self.effects.get(effect_id)
+                    && effect.triggers().contains(&trigger)
+                {
+                    result.push((effect.as_ref(), *card_id));
                 }
             }
         }
@@ -108,23 +109,35 @@ impl EffectManager {
     }
 
     /// 触发特定类型的效果
+    ///
+    /// 按卡牌ID顺序对游戏应用所有匹配的效果。上下文中的 `source_card`
+    /// 会被替换为效果所在的卡牌，`controller` 替换为该卡牌的拥有者（如果能找到）。
     pub fn trigger_effects(
-        &mut self, 
-        trigger: crate::EffectTrigger, 
+        &mut self,
+        game: &mut Game,
+        trigger: crate::EffectTrigger,
         context: EffectContext
     ) -> Vec<Result<Vec<EffectOutcome>, EffectError>> {
         let mut results = Vec::new();
         
-        // 获取所有应该触发的效果
-        let triggered_effects = self.get_effects_by_trigger(trigger.clone());
+        // 获取所有应该触发的效果，按卡牌排序以保证结果确定
+        let mut triggered_effects = self.get_effects_by_trigger(trigger.clone());
+        triggered_effects.sort_by_key(|(_, card_id)| *card_id);
         
         // 应用每个触发的效果
         for (effect, card_id) in triggered_effects {
             let mut effect_context = context.clone();
             effect_context.source_card = card_id;
+            if let Some(owner) = game
+                .players
+                .values()
+                .find(|player| player.find_card_location(card_id).is_some())
+            {
+                effect_context.controller = owner.id;
+            }
             
-            if effect.can_apply(&Game::default(), &effect_context) {
-                let result = effect.apply(&mut Game::default(), &effect_context);
+            if effect.can_apply(game, &effect_context) {
+                let result = effect.apply(game, &effect_context);
                 results.push(result);
             }
         }
@@ -169,6 +182,15 @@ impl EffectManager {
     }
 }
 
+impl std::fmt::Debug for EffectManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EffectManager")
+            .field("effects", &self.effects.len())
+            .field("active_effects", &self.active_effects)
+            .finish()
+    }
+}
+
 impl Default for EffectManager {
     fn default() -> Self {
         Self::new()
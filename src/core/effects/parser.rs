@@ -0,0 +1,191 @@
+//! 攻击效果文本解析
+//!
+//! 把卡牌上的效果文本（英文或中文）识别为结构化效果，无法识别的句子原样保留。
+
+use crate::core::player::SpecialCondition;
+use serde::{Deserialize, Serialize};
+
+/// 中毒每次在回合之间造成的伤害
+pub const DEFAULT_POISON_DAMAGE: u32 = 10;
+/// 灼伤每次在回合之间造成的伤害
+pub const DEFAULT_BURN_DAMAGE: u32 = 20;
+
+/// 从效果文本中识别出的效果
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ParsedEffect {
+    /// 对方的活跃宝可梦陷入特殊状态
+    ApplyCondition { condition: SpecialCondition },
+    /// 投掷硬币，正面时产生 `heads` 效果
+    CoinFlip { heads: Box<ParsedEffect> },
+    /// 丢弃附着在攻击宝可梦身上的能量
+    DiscardEnergy { count: u32 },
+    /// 无法识别的句子
+    Unrecognized(String),
+}
+
+/// 解析攻击的效果文本
+///
+/// 文本按句号拆分成句子。"Flip a coin. If heads, ..." 这样的两句合并为
+/// 一个 `CoinFlip`，其余每句产生一个效果。
+pub fn parse_effect_text(text: &str) -> Vec<ParsedEffect> {
+    let sentences: Vec<&str> = text
+        .split(['.', '。'])
+        .map(str::trim)
+        .filter(|sentence| !sentence.is_empty())
+        .collect();
+
+    let mut effects = Vec::new();
+    let mut index = 0;
+    while index < sentences.len() {
+        let sentence = sentences[index];
+        let heads = sentences
+            .get(index + 1)
+            .filter(|_| is_coin_flip(sentence))
+            .and_then(|next| strip_heads(next))
+            .and_then(|clause| parse_clause(&clause));
+        if let Some(heads) = heads {
+            effects.push(ParsedEffect::CoinFlip {
+                heads: Box::new(heads),
+            });
+            index += 2;
+            continue;
+        }
+
+        effects.push(parse_clause(sentence).unwrap_or_else(|| ParsedEffect::Unrecognized(sentence.to_string())));
+        index += 1;
+    }
+
+    effects
+}
+
+fn is_coin_flip(sentence: &str) -> bool {
+    sentence.eq_ignore_ascii_case("flip a coin") || sentence == "投掷硬币" || sentence == "掷硬币"
+}
+
+/// 去掉 "If heads," / "如果正面，" 前缀，返回正面时的效果句
+fn strip_heads(sentence: &str) -> Option<String> {
+    let lower = sentence.to_lowercase();
+    ["if heads,", "如果正面，", "如果是正面，"]
+        .iter()
+        .find_map(|prefix| lower.strip_prefix(prefix))
+        .map(|clause| clause.trim().to_string())
+}
+
+/// 解析单个效果句
+fn parse_clause(clause: &str) -> Option<ParsedEffect> {
+    let lower = clause.to_lowercase();
+
+    // "The Defending Pokémon is now Paralyzed"
+    if let Some((_, rest)) = lower.split_once(" is now ") {
+        let name = rest.split_whitespace().next()?;
+        return condition_from_name(name).map(|condition| ParsedEffect::ApplyCondition { condition });
+    }
+    // "对方的宝可梦陷入麻痹状态"
+    if let Some((_, rest)) = clause.split_once("陷入") {
+        let name = rest.strip_suffix("状态").unwrap_or(rest);
+        return condition_from_name(name).map(|condition| ParsedEffect::ApplyCondition { condition });
+    }
+
+    // "Discard 2 Energy from this Pokémon"
+    if let Some(rest) = lower.strip_prefix("discard ") {
+        let mut words = rest.split_whitespace();
+        let count = parse_count(words.next()?)?;
+        if words.next()? != "energy" {
+            return None;
+        }
+        return Some(ParsedEffect::DiscardEnergy { count });
+    }
+    // "丢弃这只宝可梦身上的2个能量"
+    if clause.starts_with("丢弃") && clause.ends_with("个能量") {
+        let before = clause.strip_suffix("个能量")?;
+        let count = before.chars().last().and_then(|digit| parse_count(&digit.to_string()))?;
+        return Some(ParsedEffect::DiscardEnergy { count });
+    }
+
+    None
+}
+
+/// 英文或中文的特殊状态名称
+fn condition_from_name(name: &str) -> Option<SpecialCondition> {
+    match name.trim_matches(|c: char| !c.is_alphanumeric()) {
+        "paralyzed" | "麻痹" => Some(SpecialCondition::Paralyzed),
+        "asleep" | "睡眠" => Some(SpecialCondition::Asleep),
+        "confused" | "混乱" => Some(SpecialCondition::Confused),
+        "poisoned" | "中毒" => Some(SpecialCondition::Poisoned {
+            damage_per_turn: DEFAULT_POISON_DAMAGE,
+        }),
+        "burned" | "灼伤" => Some(SpecialCondition::Burned {
+            damage_per_turn: DEFAULT_BURN_DAMAGE,
+        }),
+        _ => None,
+    }
+}
+
+/// 数字、英文数词或中文数字
+fn parse_count(word: &str) -> Option<u32> {
+    if let Ok(count) = word.parse() {
+        return Some(count);
+    }
+    match word {
+        "a" | "an" | "one" | "一" => Some(1),
+        "two" | "二" | "两" => Some(2),
+        "three" | "三" => Some(3),
+        "four" | "四" => Some(4),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin_flip(condition: SpecialCondition) -> ParsedEffect {
+        ParsedEffect::CoinFlip {
+            heads: Box::new(ParsedEffect::ApplyCondition { condition }),
+        }
+    }
+
+    #[test]
+    fn test_parse_example_coin_flip_effects() {
+        // 示例中皮卡丘和小火龙的招式
+        assert_eq!(
+            parse_effect_text("投掷硬币。如果正面，对方的宝可梦陷入麻痹状态。"),
+            vec![coin_flip(SpecialCondition::Paralyzed)]
+        );
+        assert_eq!(
+            parse_effect_text("投掷硬币。如果正面，对方的宝可梦陷入灼伤状态。"),
+            vec![coin_flip(SpecialCondition::Burned {
+                damage_per_turn: DEFAULT_BURN_DAMAGE
+            })]
+        );
+        assert_eq!(
+            parse_effect_text("Flip a coin. If heads, the Defending Pokémon is now Paralyzed."),
+            vec![coin_flip(SpecialCondition::Paralyzed)]
+        );
+    }
+
+    #[test]
+    fn test_parse_discard_energy_and_fallback() {
+        assert_eq!(
+            parse_effect_text("Discard 2 Energy from this Pokémon. Your opponent's Active Pokémon is now Confused."),
+            vec![
+                ParsedEffect::DiscardEnergy { count: 2 },
+                ParsedEffect::ApplyCondition {
+                    condition: SpecialCondition::Confused
+                },
+            ]
+        );
+        assert_eq!(
+            parse_effect_text("丢弃这只宝可梦身上的1个能量。"),
+            vec![ParsedEffect::DiscardEnergy { count: 1 }]
+        );
+        assert_eq!(
+            parse_effect_text("Flip a coin. If heads, this attack does 20 more damage."),
+            vec![
+                ParsedEffect::Unrecognized("Flip a coin".to_string()),
+                ParsedEffect::Unrecognized("If heads, this attack does 20 more damage".to_string()),
+            ]
+        );
+        assert!(parse_effect_text("").is_empty());
+    }
+}
@@ -74,6 +74,117 @@ impl Effect for TrainerEffect {
     }
 }
 
+/// 按卡牌名称登记的训练家效果
+///
+/// 打出训练家卡时，`Game::play_trainer` 按卡牌名称查找效果并结算。
+#[derive(Clone, Default)]
+pub struct TrainerEffectRegistry {
+    effects: HashMap<String, Box<dyn Effect>>,
+}
+
+impl TrainerEffectRegistry {
+    /// 创建空的注册表
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 为卡牌名称登记效果，替换已有的效果
+    pub fn register<E: Effect + 'static>(&mut self, card_name: impl Into<String>, effect: E) {
+        self.effects.insert(card_name.into(), Box::new(effect));
+    }
+
+    /// 卡牌名称是否登记了效果
+    pub fn contains(&self, card_name: &str) -> bool {
+        self.effects.contains_key(card_name)
+    }
+
+    /// 获取卡牌名称对应的效果
+    pub fn get(&self, card_name: &str) -> Option<&(dyn Effect + 'static)> {
+        self.effects.get(card_name).map(|effect| effect.as_ref())
+    }
+}
+
+impl std::fmt::Debug for TrainerEffectRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut names: Vec<&String> = self.effects.keys().collect();
+        names.sort();
+        f.debug_struct("TrainerEffectRegistry").field("effects", &names).finish()
+    }
+}
+
+/// 抽卡效果：可以先丢弃全部手牌，再抽指定数量的卡
+#[derive(Clone)]
+pub struct DrawCardsEffect {
+    base: BaseEffect,
+    count: usize,
+    discard_hand: bool,
+}
+
+impl DrawCardsEffect {
+    /// 抽 `count` 张卡
+    pub fn draw(count: usize) -> Self {
+        Self {
+            base: BaseEffect::new("抽卡".to_string(), format!("抽{}张卡。", count)),
+            count,
+            discard_hand: false,
+        }
+    }
+
+    /// 丢弃全部手牌，然后抽 `count` 张卡（例如"大木博士"抽7张）
+    pub fn discard_hand_and_draw(count: usize) -> Self {
+        Self {
+            base: BaseEffect::new(
+                "丢弃手牌并抽卡".to_string(),
+                format!("丢弃你的手牌，然后抽{}张卡。", count),
+            ),
+            count,
+            discard_hand: true,
+        }
+    }
+}
+
+impl Effect for DrawCardsEffect {
+    fn id(&self) -> EffectId {
+        self.base.id
+    }
+
+    fn name(&self) -> &str {
+        &self.base.name
+    }
+
+    fn description(&self) -> &str {
+        &self.base.description
+    }
+
+    fn can_apply(&self, game: &Game, context: &EffectContext) -> bool {
+        game.get_player(context.controller).is_some()
+    }
+
+    fn apply(&self, game: &mut Game, context: &EffectContext) -> Result<Vec<EffectOutcome>, EffectError> {
+        let player = game
+            .get_player_mut(context.controller)
+            .ok_or_else(|| EffectError::InvalidGameState {
+                reason: "玩家不存在".to_string(),
+            })?;
+        if self.discard_hand {
+            player.discard_hand();
+        }
+        let drawn = player.draw_cards(self.count);
+        Ok(vec![EffectOutcome::CardsDrawn {
+            player: context.controller,
+            count: drawn.len() as u32,
+        }])
+    }
+
+    fn triggers(&self) -> Vec<crate::EffectTrigger> {
+        vec![crate::EffectTrigger::OnPlay]
+    }
+
+    fn target_requirements(&self) -> Vec<crate::TargetRequirement> {
+        vec![]
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -113,6 +113,8 @@ pub type EffectResult = Result<Vec<EffectOutcome>, EffectError>;
 pub enum EffectOutcome {
     /// 造成了伤害
     DamageDealt { target: CardId, amount: u32 },
+    /// 放置了伤害指示物（不计算弱点和抗性）
+    CountersPlaced { target: CardId, counters: u32 },
     /// 应用了治疗
     Healing { target: CardId, amount: u32 },
     /// 抽取了卡牌
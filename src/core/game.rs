@@ -2,16 +2,31 @@
 //!
 //! 此模块包含所有与游戏相关的功能。
 
+pub mod error;
 pub mod state;
 pub mod turn;
 pub mod setup;
 pub mod actions;
 pub mod events;
+pub mod rng;
+pub mod state_hash;
+pub mod choices;
+pub mod triggers;
+pub mod invariants;
+pub mod targeting;
+
+#[cfg(feature = "bincode")]
+pub mod snapshot;
 
 // 重新导出常用类型
+pub use error::*;
 pub use state::*;
+pub use turn::*;
+pub use events::*;
 pub use setup::*;
 pub use actions::*;
+pub use rng::*;
+pub use choices::*;
 
 #[cfg(test)]
 mod tests {
@@ -1,8 +1,38 @@
 //! 攻击相关动作处理
 
-use crate::core::card::CardId;
-use crate::core::player::PlayerId;
-use crate::core::game::state::Game;
+use crate::core::card::{Attack, AttackTargetType, CardId, CardType, DamageMode};
+use crate::core::effects::EffectOutcome;
+use crate::core::player::{PlayerId, SpecialCondition};
+use crate::core::game::error::GameError;
+use crate::core::game::state::{Game, GameEvent};
+use serde::{Deserialize, Serialize};
+
+/// 弱点的伤害倍率
+pub const WEAKNESS_MULTIPLIER: u32 = 2;
+/// 抗性减少的伤害
+pub const RESISTANCE_REDUCTION: u32 = 30;
+
+/// 卡牌提供的伤害修正
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DamageModifier {
+    /// 修正来源宝可梦造成的伤害（正数增加，负数减少）
+    Outgoing(i32),
+    /// 修正来源宝可梦受到的伤害（正数增加，负数减少）
+    Incoming(i32),
+}
+
+/// 一次伤害计算的上下文
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DamageContext {
+    /// 基础伤害
+    pub base: u32,
+    /// 攻击方宝可梦
+    pub attacker: CardId,
+    /// 受到伤害的宝可梦
+    pub defender: CardId,
+    /// 在弱点和抗性之前加到基础伤害上的修正值
+    pub modifiers: Vec<i32>,
+}
 
 /// 攻击动作
 #[derive(Debug, Clone)]
@@ -32,37 +62,828 @@ impl AttackAction {
     }
 
     /// 执行攻击动作
-    pub fn execute(&self, game: &mut Game) -> Result<(), String> {
-        // 检查攻击玩家是否存在
-        let attacker_player = game.get_player(self.attacker_player_id)
-            .ok_or("Attacker player not found")?;
-        
+    pub fn execute(&self, game: &mut Game) -> Result<(), GameError> {
         // 检查目标玩家是否存在
-        let target_player = game.get_player(self.target_player_id)
-            .ok_or("Target player not found")?;
-        
-        // 检查攻击者是否为活跃宝可梦
-        if attacker_player.active_pokemon != Some(self.attacker_pokemon_id) {
-            return Err("Attacker Pokemon is not active".to_string());
-        }
-        
-        // 检查目标是否为活跃宝可梦
-        if target_player.active_pokemon != Some(self.target_pokemon_id) {
-            return Err("Target Pokemon is not active".to_string());
-        }
-        
-        // 在实际实现中，这里会执行攻击逻辑，包括：
-        // 1. 检查能量是否足够
-        // 2. 计算伤害
-        // 3. 应用状态效果
-        // 4. 处理特殊效果
-        
+        game.get_player(self.target_player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+
+        game.resolve_attack(
+            self.attacker_player_id,
+            self.attacker_pokemon_id,
+            self.attack_index,
+            Some(self.target_pokemon_id),
+        )?;
+
+        Ok(())
+    }
+}
+
+impl Game {
+    /// 结算攻击：检查能量、按攻击的目标类型选择目标并造成伤害
+    ///
+    /// # 参数
+    /// * `target` - 指定的目标宝可梦；`Choose` 和 `Bench` 类型的攻击必须提供，
+    ///   `Active` 类型的攻击可以省略
+    ///
+    /// # 返回值
+    /// 返回每个目标及其受到的伤害
+    pub fn resolve_attack(
+        &mut self,
+        player_id: PlayerId,
+        pokemon_id: CardId,
+        attack_index: usize,
+        target: Option<CardId>,
+    ) -> Result<Vec<(CardId, u32)>, GameError> {
+        let (attack, energy_count) = self.checked_attack(player_id, pokemon_id, attack_index)?;
+
+        let opponent_id = self.get_opponent_id(player_id)?;
+        let targets = self.attack_targets(opponent_id, &attack.target_type, target)?;
+
+        let coin_results: Vec<bool> = match attack.damage_mode {
+            Some(DamageMode::CoinFlip { flips, .. }) => {
+                (0..flips).map(|_| self.rng.flip_coin()).collect()
+            }
+            _ => Vec::new(),
+        };
+        let base_damage = match attack.damage_mode {
+            Some(DamageMode::Variable { .. }) => {
+                attack.roll_damage(energy_count, &coin_results, &mut self.rng)
+            }
+            _ => self.attack_base_damage(player_id, &attack, energy_count, &coin_results)?,
+        };
+
+        if let Some(player) = self.players.get_mut(&player_id) {
+            player.has_attacked = true;
+        }
+        self.add_event(GameEvent::AttackUsed {
+            player_id,
+            pokemon_id,
+            attack_name: attack.name.clone(),
+        });
+
+        let mut results = Vec::new();
+        for target_id in targets {
+            let damage = self.apply_attack_damage(pokemon_id, opponent_id, target_id, base_damage)?;
+            results.push((target_id, damage));
+        }
+
+        Ok(results)
+    }
+
+    /// 用给定的硬币结果结算一次打多个目标的攻击（如 `AttackTargetType::All`）
+    ///
+    /// 按 `targets` 的顺序对每个目标分别计算伤害修正、弱点和抗性（弱点和抗性
+    /// 只对活跃宝可梦生效），全部伤害放置完后才统一处理昏厥，
+    /// 所以同时被击倒的宝可梦会在同一次 `process_knockouts` 中结算。
+    ///
+    /// # 参数
+    /// * `attacker` - 攻击方的活跃宝可梦
+    /// * `targets` - 对手场上的宝可梦
+    /// * `flips` - 投掷硬币的结果，用于 `DamageMode::CoinFlip` 等模式
+    ///
+    /// # 返回值
+    /// 返回每个目标的 `EffectOutcome::DamageDealt`
+    pub fn resolve_multi_target_attack(
+        &mut self,
+        attacker: CardId,
+        attack_index: usize,
+        targets: Vec<CardId>,
+        flips: &[bool],
+    ) -> Result<Vec<EffectOutcome>, GameError> {
+        let player_id = self
+            .players
+            .values()
+            .find(|player| player.active_pokemon == Some(attacker))
+            .map(|player| player.id)
+            .ok_or_else(|| GameError::InvalidAction("Attacker Pokemon is not active".to_string()))?;
+        let (attack, energy_count) = self.checked_attack(player_id, attacker, attack_index)?;
+
+        let opponent_id = self.get_opponent_id(player_id)?;
+        let opponent = self
+            .get_player(opponent_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        let in_play = opponent.pokemon_in_play();
+        if let Some(target) = targets.iter().find(|target| !in_play.contains(target)) {
+            return Err(GameError::InvalidTarget(
+                format!("Target {} is not one of the opponent's Pokemon", target),
+            ));
+        }
+
+        let base_damage = match attack.damage_mode {
+            Some(DamageMode::Variable { .. }) => attack.roll_damage(energy_count, flips, &mut self.rng),
+            _ => self.attack_base_damage(player_id, &attack, energy_count, flips)?,
+        };
+
+        if let Some(player) = self.players.get_mut(&player_id) {
+            player.has_attacked = true;
+        }
+        self.add_event(GameEvent::AttackUsed {
+            player_id,
+            pokemon_id: attacker,
+            attack_name: attack.name.clone(),
+        });
+
+        let mut outcomes = Vec::new();
+        for target in targets {
+            let amount = self.apply_attack_damage(attacker, opponent_id, target, base_damage)?;
+            outcomes.push(EffectOutcome::DamageDealt { target, amount });
+        }
+
+        self.process_knockouts()?;
+        Ok(outcomes)
+    }
+
+    /// 检查宝可梦能否使用这个攻击，返回攻击和附着的能量数
+    fn checked_attack(
+        &self,
+        player_id: PlayerId,
+        pokemon_id: CardId,
+        attack_index: usize,
+    ) -> Result<(Attack, u32), GameError> {
+        let player = self
+            .get_player(player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        if player.active_pokemon != Some(pokemon_id) {
+            return Err(GameError::InvalidAction("Attacker Pokemon is not active".to_string()));
+        }
+        if player.has_attacked {
+            return Err(GameError::InvalidAction("Already attacked this turn".to_string()));
+        }
+        if !player.can_pokemon_attack(pokemon_id) {
+            return Err(GameError::InvalidAction("Attacker Pokemon cannot attack".to_string()));
+        }
+
+        let card = self
+            .get_card(pokemon_id)
+            .ok_or(GameError::CardNotFound)?;
+        let attack = card
+            .attacks
+            .get(attack_index)
+            .cloned()
+            .ok_or_else(|| GameError::InvalidAction("Attack not found".to_string()))?;
+        let attached = player.get_attached_energy_types(pokemon_id, &self.card_database);
+        if !card
+            .get_usable_attacks(&attached)
+            .iter()
+            .any(|(index, _)| *index == attack_index)
+        {
+            return Err(GameError::InvalidAction("Not enough energy for this attack".to_string()));
+        }
+
+        Ok((attack, attached.len() as u32))
+    }
+
+    /// 统计指定位置的宝可梦数量（相对于 `player_id`）
+    ///
+    /// 支持的位置："active"、"bench"、"in_play"，以及加上 "opponent_"
+    /// 前缀的对手对应位置，和双方场上全部宝可梦 "all"。
+    pub fn count_pokemon_at(&self, player_id: PlayerId, location: &str) -> Result<u32, GameError> {
+        let (owner_ids, zone) = match location.strip_prefix("opponent_") {
+            Some(zone) => (vec![self.get_opponent_id(player_id)?], zone),
+            None if location == "all" => (self.players.keys().copied().collect(), "in_play"),
+            None => (vec![player_id], location),
+        };
+
+        let mut count = 0;
+        for owner_id in owner_ids {
+            let owner = self
+                .get_player(owner_id)
+                .ok_or(GameError::PlayerNotFound)?;
+            let active = owner.active_pokemon.is_some() as usize;
+            count += match zone {
+                "active" => active,
+                "bench" => owner.bench.len(),
+                "in_play" => active + owner.bench.len(),
+                _ => return Err(GameError::InvalidAction(format!("Unknown Pokemon location: {}", location))),
+            };
+        }
+
+        Ok(count as u32)
+    }
+
+    /// 根据场上状态计算攻击的基础伤害（计算弱点和抗性之前）
+    ///
+    /// `DamageMode::PerPokemon` 按 `count_pokemon_at` 统计的实际数量加成，
+    /// 其他模式与 `Attack::calculate_damage` 相同。
+    pub fn attack_base_damage(
+        &self,
+        player_id: PlayerId,
+        attack: &Attack,
+        energy_count: u32,
+        coin_results: &[bool],
+    ) -> Result<u32, GameError> {
+        match &attack.damage_mode {
+            Some(DamageMode::PerPokemon {
+                per_pokemon,
+                location,
+            }) => {
+                let count = self.count_pokemon_at(player_id, location)?;
+                Ok(attack.damage.saturating_add(per_pokemon.saturating_mul(count)))
+            }
+            _ => Ok(attack.calculate_damage(energy_count, coin_results)),
+        }
+    }
+
+    /// 根据攻击的目标类型确定并验证目标
+    fn attack_targets(
+        &self,
+        opponent_id: PlayerId,
+        target_type: &AttackTargetType,
+        target: Option<CardId>,
+    ) -> Result<Vec<CardId>, GameError> {
+        let opponent = self
+            .get_player(opponent_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        let active = opponent.active_pokemon;
+
+        match target_type {
+            AttackTargetType::Active => {
+                let active = active.ok_or_else(|| {
+                    GameError::InvalidTarget("Opponent has no active Pokemon".to_string())
+                })?;
+                if target.is_some_and(|target| target != active) {
+                    return Err(GameError::InvalidTarget(
+                        "This attack can only target the active Pokemon".to_string(),
+                    ));
+                }
+                Ok(vec![active])
+            }
+            AttackTargetType::All => Ok(active.into_iter().chain(opponent.bench.iter().copied()).collect()),
+            AttackTargetType::Bench => {
+                let target = target.ok_or_else(|| {
+                    GameError::InvalidTarget("This attack requires a target".to_string())
+                })?;
+                if !opponent.bench.contains(&target) {
+                    return Err(GameError::InvalidTarget("Target must be a benched Pokemon".to_string()));
+                }
+                Ok(vec![target])
+            }
+            AttackTargetType::Choose => {
+                let target = target.ok_or_else(|| {
+                    GameError::InvalidTarget("This attack requires a target".to_string())
+                })?;
+                if active != Some(target) && !opponent.bench.contains(&target) {
+                    return Err(GameError::InvalidTarget(
+                        "Target must be one of the opponent's Pokemon".to_string(),
+                    ));
+                }
+                Ok(vec![target])
+            }
+            // 作用于自身的攻击不对对手造成伤害
+            AttackTargetType::Self_ => Ok(Vec::new()),
+        }
+    }
+
+    /// 注册卡牌提供的伤害修正
+    ///
+    /// 来源卡牌是宝可梦本身（特性）或附着在宝可梦身上的道具时生效。
+    pub fn register_damage_modifier(&mut self, source_card: CardId, modifier: DamageModifier) {
+        self.damage_modifiers.insert(source_card, modifier);
+    }
+
+    /// 移除已注册的伤害修正
+    pub fn remove_damage_modifier(&mut self, source_card: CardId) {
+        self.damage_modifiers.remove(&source_card);
+    }
+
+    /// 收集一次攻击伤害适用的修正，生成伤害上下文
+    ///
+    /// 攻击方及其道具提供的 `Outgoing` 修正和受伤方及其道具提供的
+    /// `Incoming` 修正都会加入，按来源卡牌ID排序以保证结果稳定。
+    pub fn damage_context(&self, attacker: CardId, defender: CardId, base: u32) -> DamageContext {
+        let sources = |pokemon_id: CardId| {
+            let mut sources = vec![pokemon_id];
+            for player in self.players.values() {
+                if let Some(&tool_id) = player.attached_tools.get(&pokemon_id) {
+                    sources.push(tool_id);
+                }
+            }
+            sources.sort();
+            sources
+        };
+
+        let mut modifiers = Vec::new();
+        for source in sources(attacker) {
+            if let Some(DamageModifier::Outgoing(delta)) = self.damage_modifiers.get(&source) {
+                modifiers.push(*delta);
+            }
+        }
+        for source in sources(defender) {
+            if let Some(DamageModifier::Incoming(delta)) = self.damage_modifiers.get(&source) {
+                modifiers.push(*delta);
+            }
+        }
+
+        DamageContext {
+            base,
+            attacker,
+            defender,
+            modifiers,
+        }
+    }
+
+    /// 按伤害上下文计算最终伤害
+    ///
+    /// 先把所有修正加到基础伤害上（最低为0），再对活跃宝可梦计算弱点（×2）
+    /// 和抗性（-30），对备战区宝可梦的伤害不计算弱点和抗性。
+    pub fn apply_damage_modifiers(&self, ctx: &DamageContext) -> u32 {
+        let modified = ctx.modifiers.iter().fold(ctx.base as i64, |damage, delta| damage + *delta as i64);
+        let damage = modified.clamp(0, u32::MAX as i64) as u32;
+
+        let is_active = self
+            .players
+            .values()
+            .any(|player| player.active_pokemon == Some(ctx.defender));
+        let attacker_type = self.get_card(ctx.attacker).and_then(|card| card.pokemon_type());
+
+        let (Some(attacker_type), true) = (attacker_type, is_active) else {
+            return damage;
+        };
+        let Some(CardType::Pokemon {
+            weakness,
+            resistance,
+            ..
+        }) = self.get_card(ctx.defender).map(|card| &card.card_type)
+        else {
+            return damage;
+        };
+
+        let mut damage = damage;
+        if weakness.as_ref() == Some(&attacker_type) {
+            damage = damage.saturating_mul(WEAKNESS_MULTIPLIER);
+        }
+        if resistance.as_ref() == Some(&attacker_type) {
+            damage = damage.saturating_sub(RESISTANCE_REDUCTION);
+        }
+        damage
+    }
+
+    /// 计算攻击对目标造成的最终伤害
+    ///
+    /// 经过 `apply_damage_modifiers`，计算已注册的伤害修正、弱点和抗性。
+    /// 放置伤害指示物请使用 `Player::place_damage_counters`，它不经过此计算。
+    pub fn calculate_attack_damage(
+        &self,
+        attacker_pokemon_id: CardId,
+        target_player_id: PlayerId,
+        target_pokemon_id: CardId,
+        base_damage: u32,
+    ) -> u32 {
+        if self.get_player(target_player_id).is_none() {
+            return base_damage;
+        }
+        let ctx = self.damage_context(attacker_pokemon_id, target_pokemon_id, base_damage);
+        self.apply_damage_modifiers(&ctx)
+    }
+
+    /// 对目标造成攻击伤害（计算弱点和抗性），返回实际造成的伤害
+    ///
+    /// 本回合受到保护（`Player::is_protected`）的宝可梦不受伤害，返回0。
+    pub fn apply_attack_damage(
+        &mut self,
+        attacker_pokemon_id: CardId,
+        target_player_id: PlayerId,
+        target_pokemon_id: CardId,
+        base_damage: u32,
+    ) -> Result<u32, GameError> {
+        let damage = self.calculate_attack_damage(
+            attacker_pokemon_id,
+            target_player_id,
+            target_pokemon_id,
+            base_damage,
+        );
+
+        let turn_number = self.turn_number;
+        let target_player = self
+            .players
+            .get_mut(&target_player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        if target_player.is_protected(target_pokemon_id, turn_number) {
+            return Ok(0);
+        }
+        target_player.add_damage(target_pokemon_id, damage);
+
+        self.add_event(GameEvent::DamageDealt {
+            player_id: target_player_id,
+            pokemon_id: target_pokemon_id,
+            damage,
+        });
+
+        Ok(damage)
+    }
+
+    /// 让场上的宝可梦陷入特殊状态（持续到被治愈）
+    ///
+    /// 本回合受到保护的宝可梦不会陷入特殊状态，也不记录事件。
+    pub fn apply_special_condition(
+        &mut self,
+        player_id: PlayerId,
+        pokemon_id: CardId,
+        condition: SpecialCondition,
+    ) -> Result<(), GameError> {
+        let turn_number = self.turn_number;
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        if !player.pokemon_in_play().contains(&pokemon_id) {
+            return Err(GameError::InvalidAction("Pokemon is not in play".to_string()));
+        }
+        if player.is_protected(pokemon_id, turn_number) {
+            return Ok(());
+        }
+        player.add_special_condition(pokemon_id, condition.clone(), -1, turn_number);
+
+        self.add_event(GameEvent::SpecialConditionApplied {
+            player_id,
+            pokemon_id,
+            condition,
+        });
         Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::core::card::{Attack, Card, EnergyType, EvolutionStage};
+    use crate::core::player::Player;
+    use crate::test_fixtures::{basic_energy, pokemon, with_pokemon_type, with_resistance, with_weakness};
+
+    /// 小智的活跃宝可梦带着一个火能量，对手有活跃宝可梦和两只备战宝可梦
+    fn attack_board(attack: Attack) -> (Game, PlayerId, CardId, Vec<CardId>) {
+        let mut attacker = with_pokemon_type(pokemon("小火龙", 100), EnergyType::Fire);
+        attacker.add_attack(attack);
+        let energy = basic_energy(EnergyType::Fire);
+        let defenders: Vec<Card> = (0..3)
+            .map(|_| with_weakness(pokemon("妙蛙种子", 100), EnergyType::Fire))
+            .collect();
+        let defender_ids: Vec<CardId> = defenders.iter().map(|card| card.id).collect();
+
+        let mut ash = Player::new("小智".to_string());
+        let mut gary = Player::new("小茂".to_string());
+        let ash_id = ash.id;
+        let attacker_id = attacker.id;
+        ash.active_pokemon = Some(attacker_id);
+        ash.attached_energy.insert(attacker_id, vec![energy.id]);
+        gary.active_pokemon = Some(defender_ids[0]);
+        gary.bench = defender_ids[1..].to_vec();
+
+        let mut game = Game::new();
+        for card in [attacker, energy].into_iter().chain(defenders) {
+            game.add_card_to_database(card);
+        }
+        game.add_player(ash).unwrap();
+        game.add_player(gary).unwrap();
+
+        (game, ash_id, attacker_id, defender_ids)
+    }
+
+    #[test]
+    fn test_all_attack_hits_active_and_bench() {
+        let mut attack = Attack::simple("火焰漩涡".to_string(), vec![EnergyType::Fire], 20);
+        attack.set_target_type(AttackTargetType::All);
+        let (mut game, ash_id, attacker_id, defenders) = attack_board(attack);
+
+        let results = game.resolve_attack(ash_id, attacker_id, 0, None).unwrap();
+
+        // 只有活跃宝可梦计算弱点
+        assert_eq!(
+            results,
+            vec![(defenders[0], 40), (defenders[1], 20), (defenders[2], 20)]
+        );
+        assert!(game.resolve_attack(ash_id, attacker_id, 0, None).is_err());
+    }
+
+    #[test]
+    fn test_spread_attack_knocks_out_several_benched_pokemon() {
+        let mut attack = Attack::simple("火焰漩涡".to_string(), vec![EnergyType::Fire], 20);
+        attack.set_target_type(AttackTargetType::All);
+        let (mut game, ash_id, attacker_id, defenders) = attack_board(attack);
+        // 第二只备战宝可梦是V，被击倒时对手拿两张奖赏卡
+        if let Some(CardType::Pokemon { stage, .. }) =
+            game.card_database.get_mut(&defenders[2]).map(|card| &mut card.card_type)
+        {
+            *stage = EvolutionStage::V;
+        }
+        let gary_id = *game.players.keys().find(|&&id| id != ash_id).unwrap();
+        for &benched in &defenders[1..] {
+            game.get_player_mut(gary_id).unwrap().add_damage(benched, 90);
+        }
+
+        game.resolve_attack(ash_id, attacker_id, 0, None).unwrap();
+        let knocked_out = game.process_knockouts().unwrap();
+
+        // 按击倒顺序处理，奖赏卡数量累加
+        assert_eq!(knocked_out, vec![defenders[1], defenders[2]]);
+        assert_eq!(game.get_player(ash_id).unwrap().prize_cards, 3);
+        let gary = game.get_player(gary_id).unwrap();
+        assert_eq!(gary.active_pokemon, Some(defenders[0]));
+        assert!(gary.bench.is_empty());
+        assert!(game.pending_promotions.is_empty());
+    }
+
+    #[test]
+    fn test_choose_attack_hits_specified_bench_pokemon() {
+        let mut attack = Attack::simple("狙击".to_string(), vec![EnergyType::Fire], 30);
+        attack.set_target_type(AttackTargetType::Choose);
+        let (mut game, ash_id, attacker_id, defenders) = attack_board(attack);
+
+        assert!(game.resolve_attack(ash_id, attacker_id, 0, None).is_err());
+        assert!(game.resolve_attack(ash_id, attacker_id, 0, Some(attacker_id)).is_err());
+
+        let results = game
+            .resolve_attack(ash_id, attacker_id, 0, Some(defenders[2]))
+            .unwrap();
+
+        assert_eq!(results, vec![(defenders[2], 30)]);
+        let opponent_id = game.get_opponent_id(ash_id).unwrap();
+        let opponent = game.get_player(opponent_id).unwrap();
+        assert_eq!(opponent.damage_counters.get(&defenders[2]), Some(&30));
+        assert_eq!(opponent.damage_counters.get(&defenders[0]), None);
+    }
+
+    #[test]
+    fn test_bench_attack_rejects_active_target() {
+        let mut attack = Attack::simple("偷袭".to_string(), vec![EnergyType::Fire], 10);
+        attack.set_target_type(AttackTargetType::Bench);
+        let (mut game, ash_id, attacker_id, defenders) = attack_board(attack);
+
+        assert!(game.resolve_attack(ash_id, attacker_id, 0, Some(defenders[0])).is_err());
+        assert!(game.resolve_attack(ash_id, attacker_id, 0, Some(defenders[1])).is_ok());
+    }
+
+    #[test]
+    fn test_per_pokemon_damage_counts_bench() {
+        let mut attack = Attack::simple("群体攻击".to_string(), vec![EnergyType::Fire], 10);
+        attack.set_damage_mode(DamageMode::PerPokemon {
+            per_pokemon: 20,
+            location: "opponent_bench".to_string(),
+        });
+        attack.set_target_type(AttackTargetType::Bench);
+        let (mut game, ash_id, attacker_id, defenders) = attack_board(attack.clone());
+
+        // 对手备战区有两只宝可梦：10 + 2 × 20
+        assert_eq!(game.attack_base_damage(ash_id, &attack, 1, &[]), Ok(50));
+        assert_eq!(
+            game.resolve_attack(ash_id, attacker_id, 0, Some(defenders[1])).unwrap(),
+            vec![(defenders[1], 50)]
+        );
+
+        // 备战区减少一只后伤害随之下降
+        let opponent_id = game.get_opponent_id(ash_id).unwrap();
+        game.get_player_mut(opponent_id).unwrap().bench.pop();
+        assert_eq!(game.attack_base_damage(ash_id, &attack, 1, &[]), Ok(30));
+        assert_eq!(game.count_pokemon_at(ash_id, "bench"), Ok(0));
+        assert_eq!(game.count_pokemon_at(ash_id, "all"), Ok(3));
+        assert!(game.count_pokemon_at(ash_id, "hand").is_err());
+    }
+
+    #[test]
+    fn test_counters_ignore_weakness() {
+        let mut attacker = with_pokemon_type(pokemon("小火龙", 100), EnergyType::Fire);
+        attacker.add_attack(Attack::simple("火花".to_string(), vec![EnergyType::Fire], 30));
+        let defender = with_weakness(pokemon("妙蛙种子", 100), EnergyType::Fire);
+        let (attacker_id, defender_id) = (attacker.id, defender.id);
+
+        let mut game = Game::new();
+        let mut player = Player::new("小智".to_string());
+        let player_id = player.id;
+        player.active_pokemon = Some(defender_id);
+        game.add_card_to_database(attacker);
+        game.add_card_to_database(defender);
+        game.add_player(player).unwrap();
+
+        // 放置3个伤害指示物：30点伤害，不计算弱点
+        let outcome = game
+            .get_player_mut(player_id)
+            .unwrap()
+            .place_damage_counters(defender_id, 3);
+        assert_eq!(
+            outcome,
+            EffectOutcome::CountersPlaced {
+                target: defender_id,
+                counters: 3
+            }
+        );
+        assert_eq!(game.get_player(player_id).unwrap().damage_counters[&defender_id], 30);
+
+        // 攻击伤害计算弱点：30 × 2
+        let dealt = game
+            .apply_attack_damage(attacker_id, player_id, defender_id, 30)
+            .unwrap();
+        assert_eq!(dealt, 60);
+        assert_eq!(game.get_player(player_id).unwrap().damage_counters[&defender_id], 90);
+    }
+
+    #[test]
+    fn test_weakness_uses_printed_pokemon_type() {
+        let typed = |name: &str, pokemon_type: EnergyType| {
+            let mut card = with_pokemon_type(pokemon(name, 100), pokemon_type);
+            card.add_attack(Attack::simple("撞击".to_string(), vec![EnergyType::Colorless], 30));
+            card
+        };
+        let fire = typed("小火龙", EnergyType::Fire);
+        let water = typed("杰尼龟", EnergyType::Water);
+        let defender = with_weakness(pokemon("妙蛙种子", 100), EnergyType::Fire);
+        let (fire_id, water_id, defender_id) = (fire.id, water.id, defender.id);
+
+        let mut game = Game::new();
+        let mut player = Player::new("小茂".to_string());
+        let player_id = player.id;
+        player.active_pokemon = Some(defender_id);
+        for card in [fire, water, defender] {
+            game.add_card_to_database(card);
+        }
+        game.add_player(player).unwrap();
+
+        // 招式只需无色能量，仍按火属性计算弱点：30 × 2
+        assert_eq!(game.get_card(fire_id).unwrap().pokemon_type(), Some(EnergyType::Fire));
+        assert_eq!(game.calculate_attack_damage(fire_id, player_id, defender_id, 30), 60);
+        assert_eq!(game.calculate_attack_damage(water_id, player_id, defender_id, 30), 30);
+    }
+
+    #[test]
+    fn test_untyped_pokemon_does_not_get_type_from_attack_cost() {
+        let mut attacker = pokemon("百变怪", 100);
+        attacker.add_attack(Attack::simple("火花".to_string(), vec![EnergyType::Fire], 30));
+        let defender = with_weakness(pokemon("妙蛙种子", 100), EnergyType::Fire);
+        let (attacker_id, defender_id) = (attacker.id, defender.id);
+
+        let mut game = Game::new();
+        let mut player = Player::new("小茂".to_string());
+        let player_id = player.id;
+        player.active_pokemon = Some(defender_id);
+        game.add_card_to_database(attacker);
+        game.add_card_to_database(defender);
+        game.add_player(player).unwrap();
+
+        // 没有印刷属性的宝可梦不会因为招式需要火能量而触发火弱点
+        assert_eq!(game.get_card(attacker_id).unwrap().pokemon_type(), None);
+        assert_eq!(game.calculate_attack_damage(attacker_id, player_id, defender_id, 30), 30);
+    }
+
+    #[test]
+    fn test_damage_modifiers_apply_before_weakness() {
+        let (mut game, ash_id, attacker_id, defenders) =
+            attack_board(Attack::simple("火花".to_string(), vec![EnergyType::Fire], 30));
+        let gary_id = game.get_opponent_id(ash_id).unwrap();
+        let tool_id = CardId::new_v4();
+        game.get_player_mut(ash_id).unwrap().attached_tools.insert(attacker_id, tool_id);
+
+        // 攻击方的道具 +20，受伤方的特性 -30
+        game.register_damage_modifier(tool_id, DamageModifier::Outgoing(20));
+        game.register_damage_modifier(defenders[0], DamageModifier::Incoming(-30));
+        let ctx = game.damage_context(attacker_id, defenders[0], 30);
+        assert_eq!(ctx.modifiers.len(), 2);
+        // (30 + 20 - 30) × 2
+        assert_eq!(game.apply_damage_modifiers(&ctx), 40);
+        assert_eq!(
+            game.resolve_attack(ash_id, attacker_id, 0, None).unwrap(),
+            vec![(defenders[0], 40)]
+        );
+
+        // 修正后的伤害最低为0
+        game.register_damage_modifier(defenders[1], DamageModifier::Incoming(-100));
+        assert_eq!(game.calculate_attack_damage(attacker_id, gary_id, defenders[1], 30), 0);
+
+        // 受伤方自己的 Outgoing 修正不影响它受到的伤害
+        game.remove_damage_modifier(defenders[0]);
+        game.register_damage_modifier(defenders[0], DamageModifier::Outgoing(100));
+        assert_eq!(game.calculate_attack_damage(attacker_id, gary_id, defenders[0], 30), 100);
+    }
+
+    #[test]
+    fn test_protected_pokemon_takes_no_damage_until_next_turn() {
+        let mut attack = Attack::simple("火花".to_string(), vec![EnergyType::Fire], 30);
+        attack.set_target_type(AttackTargetType::All);
+        let (mut game, ash_id, attacker_id, defenders) = attack_board(attack);
+        let gary_id = game.get_opponent_id(ash_id).unwrap();
+        game.get_player_mut(gary_id).unwrap().protect_pokemon(defenders[0], 1);
+
+        let results = game.resolve_attack(ash_id, attacker_id, 0, None).unwrap();
+        assert_eq!(results[0], (defenders[0], 0));
+        assert_eq!(results[1], (defenders[1], 30));
+        let gary = game.get_player(gary_id).unwrap();
+        assert_eq!(gary.damage_counters.get(&defenders[0]), None);
+
+        // 下一回合保护失效
+        game.turn_number = 2;
+        game.get_player_mut(ash_id).unwrap().has_attacked = false;
+        let results = game.resolve_attack(ash_id, attacker_id, 0, None).unwrap();
+        assert_eq!(results[0], (defenders[0], 60));
+        assert!(!game.get_player(gary_id).unwrap().is_protected(defenders[0], 2));
+    }
+
+    #[test]
+    fn test_protected_pokemon_gets_no_special_condition_until_next_turn() {
+        let attack = Attack::simple("火花".to_string(), vec![EnergyType::Fire], 30);
+        let (mut game, ash_id, _, defenders) = attack_board(attack);
+        let gary_id = game.get_opponent_id(ash_id).unwrap();
+        game.get_player_mut(gary_id).unwrap().protect_pokemon(defenders[0], 1);
+        let history_len = game.get_history().len();
+
+        game.apply_special_condition(gary_id, defenders[0], SpecialCondition::Asleep).unwrap();
+        assert!(!game
+            .get_player(gary_id)
+            .unwrap()
+            .has_special_condition_type(defenders[0], &SpecialCondition::Asleep));
+        assert_eq!(game.get_history().len(), history_len);
+
+        // 下一回合保护失效
+        game.turn_number = 2;
+        game.apply_special_condition(gary_id, defenders[0], SpecialCondition::Asleep).unwrap();
+        assert!(game
+            .get_player(gary_id)
+            .unwrap()
+            .has_special_condition_type(defenders[0], &SpecialCondition::Asleep));
+    }
+
+    #[test]
+    fn test_multi_target_attack_modifies_damage_per_target() {
+        let mut attack = Attack::simple("火焰漩涡".to_string(), vec![EnergyType::Fire], 30);
+        attack.set_target_type(AttackTargetType::All);
+        let (mut game, ash_id, attacker_id, defenders) = attack_board(attack);
+        let gary_id = game.get_opponent_id(ash_id).unwrap();
+
+        // 备战区：弱火、弱水、抗火且带着受到伤害+20的道具
+        let water_weak = with_weakness(pokemon("杰尼龟", 100), EnergyType::Water);
+        let fire_resistant = with_resistance(pokemon("暴鲤龙", 100), EnergyType::Fire);
+        let (water_id, resistant_id, tool_id) = (water_weak.id, fire_resistant.id, CardId::new_v4());
+        game.add_card_to_database(water_weak);
+        game.add_card_to_database(fire_resistant);
+        game.register_damage_modifier(tool_id, DamageModifier::Incoming(20));
+        let gary = game.get_player_mut(gary_id).unwrap();
+        gary.bench = vec![defenders[1], water_id, resistant_id];
+        gary.attached_tools.insert(resistant_id, tool_id);
+        gary.add_damage(defenders[0], 50);
+
+        let targets = game.random_target_candidates("opponent_pokemon", ash_id).unwrap();
+        let outcomes = game
+            .resolve_multi_target_attack(attacker_id, 0, targets, &[])
+            .unwrap();
+
+        // 弱点和抗性只对活跃宝可梦生效，修正对每个目标分别计算
+        let damage = |target, amount| EffectOutcome::DamageDealt { target, amount };
+        assert_eq!(
+            outcomes,
+            vec![
+                damage(defenders[0], 60),
+                damage(defenders[1], 30),
+                damage(water_id, 30),
+                damage(resistant_id, 50),
+            ]
+        );
+
+        // 活跃宝可梦在全部伤害放置后被击倒
+        let gary = game.get_player(gary_id).unwrap();
+        assert_eq!(gary.active_pokemon, None);
+        assert!(gary.discard_pile.contains(&defenders[0]));
+        assert_eq!(gary.damage_counters[&resistant_id], 50);
+        assert_eq!(game.pending_promotions, vec![gary_id]);
+        assert!(game.resolve_multi_target_attack(attacker_id, 0, Vec::new(), &[]).is_err());
+    }
+
+    #[test]
+    fn test_coin_flip_multi_target_uses_given_flips() {
+        let mut attack = Attack::coin_flip_damage("连续火花".to_string(), vec![EnergyType::Fire], 0, 10, 3);
+        attack.set_target_type(AttackTargetType::Bench);
+        let (mut game, _, attacker_id, defenders) = attack_board(attack);
+
+        let outcomes = game
+            .resolve_multi_target_attack(attacker_id, 0, defenders[1..].to_vec(), &[true, false, true])
+            .unwrap();
+        assert_eq!(
+            outcomes,
+            defenders[1..]
+                .iter()
+                .map(|&target| EffectOutcome::DamageDealt { target, amount: 20 })
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_special_condition_is_recorded() {
+        let attack = Attack::simple("火花".to_string(), vec![EnergyType::Fire], 30);
+        let (mut game, ash_id, _, defenders) = attack_board(attack);
+        let gary_id = game.get_opponent_id(ash_id).unwrap();
+
+        game.apply_special_condition(gary_id, defenders[0], SpecialCondition::Asleep).unwrap();
+        assert!(game
+            .get_player(gary_id)
+            .unwrap()
+            .has_special_condition_type(defenders[0], &SpecialCondition::Asleep));
+        assert!(game.get_history().contains(&GameEvent::SpecialConditionApplied {
+            player_id: gary_id,
+            pokemon_id: defenders[0],
+            condition: SpecialCondition::Asleep,
+        }));
+
+        // 不在场上的宝可梦不能陷入特殊状态
+        assert!(game
+            .apply_special_condition(gary_id, CardId::new_v4(), SpecialCondition::Asleep)
+            .is_err());
+    }
 
     #[test]
     fn test_attack_actions_module() {
@@ -1,19 +1,31 @@
 //! Card-related game actions
 
-use crate::core::game::state::{Game, GameEvent};
+use crate::core::card::{Card, CardId, CardType, TrainerType};
+use crate::core::effects::{EffectContext, EffectOutcome, EffectTarget, EffectTrigger};
+use crate::core::game::error::GameError;
+use crate::core::game::state::{Game, GameEvent, GameState};
 use crate::core::player::PlayerId;
 
 impl Game {
     /// Shuffle a player's deck
-    pub fn shuffle_deck(&mut self, player_id: PlayerId) -> Result<(), String> {
+    ///
+    /// Same as `shuffle_player_deck_with_event`.
+    pub fn shuffle_deck(&mut self, player_id: PlayerId) -> Result<(), GameError> {
+        self.shuffle_player_deck_with_event(player_id)
+    }
+
+    /// Shuffle a player's deck with the game RNG and record a `DeckShuffled` event
+    ///
+    /// Every search or tutor must end with this so both players see the shuffle.
+    pub fn shuffle_player_deck_with_event(&mut self, player_id: PlayerId) -> Result<(), GameError> {
         // Check if the player exists
         if !self.players.contains_key(&player_id) {
-            return Err("Player not found".to_string());
+            return Err(GameError::PlayerNotFound);
         }
 
         // Get mutable reference to the player and shuffle their deck
         if let Some(player) = self.players.get_mut(&player_id) {
-            player.shuffle_deck();
+            player.shuffle_deck_with(&mut self.rng);
         }
 
         // Add event for shuffling deck
@@ -23,15 +35,638 @@ impl Game {
     }
 
     /// Shuffle both players' decks
-    pub fn shuffle_both_decks(&mut self) -> Result<(), String> {
+    pub fn shuffle_both_decks(&mut self) -> Result<(), GameError> {
         // Collect player IDs first to avoid borrowing issues
         let player_ids: Vec<PlayerId> = self.players.keys().cloned().collect();
 
         // Shuffle each player's deck
         for player_id in player_ids {
-            self.shuffle_deck(player_id)?;
+            self.shuffle_player_deck_with_event(player_id)?;
+        }
+
+        Ok(())
+    }
+
+    /// Shuffle a player's discard pile into their deck
+    pub fn shuffle_discard_into_deck(&mut self, player_id: PlayerId) -> Result<(), GameError> {
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+
+        player.shuffle_discard_into_deck(&mut self.rng);
+        self.add_event(GameEvent::DeckShuffled { player_id });
+
+        Ok(())
+    }
+
+    /// Put a Basic Pokemon from a player's hand onto their bench
+    ///
+    /// Evolution cards cannot be played directly to the bench, and the bench
+    /// may hold at most `rules.max_bench_size` Pokemon.
+    pub fn play_basic_to_bench(&mut self, player_id: PlayerId, card_id: CardId) -> Result<(), GameError> {
+        let card = self
+            .card_database
+            .get(&card_id)
+            .ok_or(GameError::CardNotFound)?;
+        if !card.is_basic_pokemon() {
+            return Err(GameError::InvalidAction("Only Basic Pokemon can be played to the bench".to_string()));
         }
 
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        if player.bench.len() >= self.rules.max_bench_size {
+            return Err(GameError::BenchFull);
+        }
+        let pos = player
+            .hand
+            .iter()
+            .position(|&id| id == card_id)
+            .ok_or_else(|| GameError::InvalidAction("Card not in hand".to_string()))?;
+        player.hand.remove(pos);
+        player.bench.push(card_id);
+
+        self.add_event(GameEvent::PokemonBenched { player_id, card_id });
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// Evolve a player's Pokemon in play with an evolution card from their hand
+    ///
+    /// Only allowed while the game is in progress and on the player's own
+    /// turn. The evolution card must name the Pokemon in `evolves_from`.
+    pub fn evolve_pokemon(
+        &mut self,
+        player_id: PlayerId,
+        card_id: CardId,
+        pokemon_id: CardId,
+    ) -> Result<(), GameError> {
+        if self.state != GameState::InProgress {
+            return Err(GameError::WrongPhase(self.state.clone()));
+        }
+        if !self.is_player_turn(player_id) {
+            return Err(GameError::NotYourTurn);
+        }
+
+        let evolution = self
+            .card_database
+            .get(&card_id)
+            .ok_or(GameError::CardNotFound)?;
+        let base = self
+            .card_database
+            .get(&pokemon_id)
+            .ok_or(GameError::CardNotFound)?;
+        if !evolution.evolves_from_card(base) {
+            return Err(GameError::InvalidAction(
+                format!("{} does not evolve from {}", evolution.name, base.name),
+            ));
+        }
+
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        if !player.evolve_pokemon(pokemon_id, card_id) {
+            return Err(GameError::InvalidAction(
+                "Evolution card must be in hand and the Pokemon in play".to_string(),
+            ));
+        }
+
+        self.add_event(GameEvent::PokemonEvolved {
+            player_id,
+            pokemon_id,
+            evolution_id: card_id,
+        });
+        Ok(())
+    }
+
+    /// Play a Stadium card from hand
+    ///
+    /// A stadium already in play, from either player, goes to its owner's
+    /// discard pile.
+    pub fn play_stadium(&mut self, player_id: PlayerId, card_id: CardId) -> Result<(), GameError> {
+        let is_stadium = self.card_database.get(&card_id).is_some_and(|card| {
+            matches!(
+                card.card_type,
+                CardType::Trainer {
+                    trainer_type: TrainerType::Stadium
+                }
+            )
+        });
+        if !is_stadium {
+            return Err(GameError::InvalidAction("Card is not a Stadium".to_string()));
+        }
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        let pos = player
+            .hand
+            .iter()
+            .position(|&id| id == card_id)
+            .ok_or_else(|| GameError::InvalidAction("Card is not in hand".to_string()))?;
+        player.hand.remove(pos);
+
+        for player in self.players.values_mut() {
+            if let Some(old) = player.stadium.take() {
+                player.discard_pile.push(old);
+            }
+        }
+        self.players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?
+            .stadium = Some(card_id);
+
+        self.add_event(GameEvent::StadiumPlayed { player_id, card_id });
+        Ok(())
+    }
+
+    /// Play a Trainer card whose effect is registered in `trainer_effects`
+    ///
+    /// The card leaves the hand before its effect resolves and goes to the
+    /// discard pile afterwards, so an effect that discards or shuffles away
+    /// the hand does not take the card with it. If the effect fails, the card
+    /// returns to the hand and nothing is recorded. Only one Supporter may be
+    /// played per turn.
+    pub fn play_trainer(
+        &mut self,
+        player_id: PlayerId,
+        card_id: CardId,
+        target: Option<CardId>,
+    ) -> Result<Vec<EffectOutcome>, GameError> {
+        let (card_name, is_supporter) = match self.card_database.get(&card_id) {
+            Some(card) if card.is_trainer() => (
+                card.name.clone(),
+                matches!(
+                    card.card_type,
+                    CardType::Trainer {
+                        trainer_type: TrainerType::Supporter
+                    }
+                ),
+            ),
+            _ => return Err(GameError::InvalidAction("Card is not a Trainer".to_string())),
+        };
+        let player = self.get_player(player_id).ok_or(GameError::PlayerNotFound)?;
+        if is_supporter && player.supporter_played_this_turn {
+            return Err(GameError::InvalidAction(
+                "A Supporter has already been played this turn".to_string(),
+            ));
+        }
+        let effect = self
+            .trainer_effects
+            .get(&card_name)
+            .map(dyn_clone::clone_box)
+            .ok_or_else(|| GameError::InvalidAction(format!("No effect registered for {}", card_name)))?;
+        let context = EffectContext {
+            source_card: card_id,
+            controller: player_id,
+            target: target.map(EffectTarget::Card),
+            parameters: Default::default(),
+            trigger: Some(EffectTrigger::OnPlay),
+        };
+        if !effect.can_apply(self, &context) {
+            return Err(GameError::InvalidAction(format!("{} cannot be played now", card_name)));
+        }
+
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        let pos = player
+            .hand
+            .iter()
+            .position(|&id| id == card_id)
+            .ok_or_else(|| GameError::InvalidAction("Card is not in hand".to_string()))?;
+        player.hand.remove(pos);
+
+        let result = effect.apply(self, &context);
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        match result {
+            Ok(outcomes) => {
+                player.discard_pile.push(card_id);
+                player.supporter_played_this_turn |= is_supporter;
+                self.add_event(GameEvent::CardPlayed { player_id, card_id });
+                Ok(outcomes)
+            }
+            Err(error) => {
+                player.hand.insert(pos.min(player.hand.len()), card_id);
+                Err(GameError::InvalidAction(format!("{:?}", error)))
+            }
+        }
+    }
+
+    /// Attach a Pokemon Tool from hand to one of the player's Pokemon
+    pub fn attach_tool(
+        &mut self,
+        player_id: PlayerId,
+        tool_id: CardId,
+        pokemon_id: CardId,
+    ) -> Result<(), GameError> {
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        if !player.attach_tool(tool_id, pokemon_id) {
+            return Err(GameError::InvalidAction(
+                "Tool must be in hand and the Pokemon in play without a tool".to_string(),
+            ));
+        }
+
+        self.add_event(GameEvent::ToolAttached {
+            player_id,
+            tool_id,
+            pokemon_id,
+        });
+        Ok(())
+    }
+
+    /// Search a player's deck for a card, put it into their hand, then shuffle
+    pub fn tutor(&mut self, player_id: PlayerId, card_id: CardId) -> Result<(), GameError> {
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+
+        if !player.move_from_deck_to_hand(card_id) {
+            return Err(GameError::InvalidAction("Card not found in deck".to_string()));
+        }
+
+        self.shuffle_player_deck_with_event(player_id)
+    }
+
+    /// Search a player's deck for up to `max` cards matching a predicate,
+    /// put them into their hand, then shuffle
+    ///
+    /// The deck is shuffled even if nothing matched. Returns the cards taken.
+    pub fn search_deck_to_hand(
+        &mut self,
+        player_id: PlayerId,
+        predicate: impl Fn(&Card) -> bool,
+        max: usize,
+    ) -> Result<Vec<CardId>, GameError> {
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+
+        let taken = player.search_deck_to_hand(predicate, &self.card_database, max, &mut self.rng);
+
+        self.add_event(GameEvent::DeckShuffled { player_id });
+        Ok(taken)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::card::{EnergyType, TrainerType};
+    use crate::core::game::state::GamePhase;
+    use crate::core::player::Player;
+    use crate::core::rules::{GameAction, StandardRules};
+    use crate::test_fixtures::{basic_energy, pokemon, stage1, trainer};
+
+    #[test]
+    fn test_evolution_only_on_owners_turn() {
+        let mut game = Game::new();
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+
+        let raichu = stage1("Raichu", 60, "Pikachu");
+        let pikachu = pokemon("Pikachu", 60);
+        let (raichu_id, pikachu_id) = (raichu.id, pikachu.id);
+        alice.active_pokemon = Some(pikachu_id);
+        alice.add_damage(pikachu_id, 20);
+        alice.hand = vec![raichu_id];
+        bob.active_pokemon = Some(uuid::Uuid::new_v4());
+        game.add_card_to_database(raichu);
+        game.add_card_to_database(pikachu);
+        game.turn_order = vec![alice_id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+
+        let engine = StandardRules::create_engine();
+        let evolve = GameAction::Evolve {
+            player_id: alice_id,
+            card_id: raichu_id,
+            pokemon_id: pikachu_id,
+        };
+        let evolve_violation = |game: &mut Game| {
+            game.execute_action(&engine, &evolve)
+                .unwrap_err()
+                .into_iter()
+                .find(|violation| violation.rule_name == "Evolve")
+                .map(|violation| violation.message)
+        };
+
+        assert_eq!(evolve_violation(&mut game), Some("Cannot evolve during setup".to_string()));
+
+        game.state = GameState::InProgress;
+        game.current_player_index = 1;
+        assert_eq!(
+            evolve_violation(&mut game),
+            Some("Can only evolve on your own turn".to_string())
+        );
+
+        game.current_player_index = 0;
+        game.phase = GamePhase::Main;
+        game.execute_action(&engine, &evolve).unwrap();
+        let alice = game.get_player(alice_id).unwrap();
+        assert_eq!(alice.active_pokemon, Some(raichu_id));
+        assert_eq!(alice.evolution_stacks[&raichu_id], vec![pikachu_id]);
+        assert_eq!(alice.damage_counters[&raichu_id], 20);
+        assert!(alice.hand.is_empty());
+        assert!(game.get_history().contains(&GameEvent::PokemonEvolved {
+            player_id: alice_id,
+            pokemon_id: pikachu_id,
+            evolution_id: raichu_id,
+        }));
+    }
+
+    #[test]
+    fn test_stadium_and_tool_record_events() {
+        let mut game = Game::new();
+        let mut player = Player::new("Alice".to_string());
+        let player_id = player.id;
+
+        let (first, second, tool) = (
+            trainer("Stadium", TrainerType::Stadium),
+            trainer("Stadium", TrainerType::Stadium),
+            trainer("Tool", TrainerType::Tool),
+        );
+        let (first_id, second_id, tool_id) = (first.id, second.id, tool.id);
+        let pikachu_id = uuid::Uuid::new_v4();
+        player.active_pokemon = Some(pikachu_id);
+        player.hand = vec![first_id, second_id, tool_id];
+        for card in [first, second, tool] {
+            game.add_card_to_database(card);
+        }
+        game.turn_order = vec![player_id];
+        game.add_player(player).unwrap();
+        game.state = GameState::InProgress;
+
+        let engine = StandardRules::create_engine();
+        let play = |card_id, target| GameAction::PlayCard {
+            player_id,
+            card_id,
+            target,
+        };
+        game.execute_action(&engine, &play(first_id, None)).unwrap();
+        game.execute_action(&engine, &play(second_id, None)).unwrap();
+        game.execute_action(&engine, &play(tool_id, Some(pikachu_id))).unwrap();
+
+        let player = game.get_player(player_id).unwrap();
+        assert_eq!(player.stadium, Some(second_id));
+        assert_eq!(player.discard_pile, vec![first_id]);
+        assert_eq!(player.attached_tools[&pikachu_id], tool_id);
+        let history = game.get_history();
+        assert!(history.contains(&GameEvent::StadiumPlayed {
+            player_id,
+            card_id: second_id,
+        }));
+        assert!(history.contains(&GameEvent::ToolAttached {
+            player_id,
+            tool_id,
+            pokemon_id: pikachu_id,
+        }));
+        assert!(game.play_stadium(player_id, tool_id).is_err());
+    }
+
+    #[test]
+    fn test_registered_trainer_effect_refreshes_hand() {
+        use crate::core::effects::DrawCardsEffect;
+
+        let mut game = Game::new();
+        let mut player = Player::new("Alice".to_string());
+        let player_id = player.id;
+        let oak = trainer("Professor Oak", TrainerType::Supporter);
+        let oak_id = oak.id;
+        game.add_card_to_database(oak);
+        player.active_pokemon = Some(uuid::Uuid::new_v4());
+        player.set_deck((0..12).map(|_| uuid::Uuid::new_v4()).collect());
+        let old_hand = player.draw_cards(4);
+        player.hand.push(oak_id);
+        game.turn_order = vec![player_id];
+        game.add_player(player).unwrap();
+        game.state = GameState::InProgress;
+
+        let engine = StandardRules::create_engine();
+        let play = GameAction::PlayCard {
+            player_id,
+            card_id: oak_id,
+            target: None,
+        };
+        // Without a registered effect the card cannot be played and stays in hand
+        let history_len = game.history.len();
+        assert_eq!(game.execute_action(&engine, &play).unwrap_err()[0].rule_name, "Trainer");
+        assert!(game.get_player(player_id).unwrap().hand.contains(&oak_id));
+        assert_eq!(game.history.len(), history_len);
+
+        game.trainer_effects
+            .register("Professor Oak", DrawCardsEffect::discard_hand_and_draw(7));
+        game.execute_action(&engine, &play).unwrap();
+
+        let player = game.get_player(player_id).unwrap();
+        assert_eq!(player.hand.len(), 7);
+        assert!(old_hand.iter().all(|card_id| !player.hand.contains(card_id)));
+        assert_eq!(player.discard_pile.len(), 5);
+        assert_eq!(player.discard_pile.last(), Some(&oak_id));
+        assert_eq!(player.deck.len(), 1);
+        assert!(game.play_trainer(player_id, oak_id, None).is_err());
+
+        // A second Supporter in the same turn is rejected and stays in hand
+        let second_oak = trainer("Professor Oak", TrainerType::Supporter);
+        let second_oak_id = second_oak.id;
+        game.add_card_to_database(second_oak);
+        game.get_player_mut(player_id).unwrap().hand.push(second_oak_id);
+        assert!(game.play_trainer(player_id, second_oak_id, None).is_err());
+        assert!(game.get_player(player_id).unwrap().hand.contains(&second_oak_id));
+
+        game.get_player_mut(player_id).unwrap().start_turn();
+        game.play_trainer(player_id, second_oak_id, None).unwrap();
+        assert_eq!(game.get_player(player_id).unwrap().discard_pile.last(), Some(&second_oak_id));
+    }
+
+    #[test]
+    fn test_failed_trainer_effect_returns_card_to_hand() {
+        use crate::core::effects::{BaseEffect, Effect, EffectError, EffectId, TargetRequirement};
+
+        #[derive(Clone)]
+        struct FailingEffect(BaseEffect);
+
+        impl Effect for FailingEffect {
+            fn id(&self) -> EffectId {
+                self.0.id
+            }
+            fn name(&self) -> &str {
+                &self.0.name
+            }
+            fn description(&self) -> &str {
+                &self.0.description
+            }
+            fn can_apply(&self, _game: &Game, _context: &EffectContext) -> bool {
+                true
+            }
+            fn apply(&self, _game: &mut Game, _context: &EffectContext) -> Result<Vec<EffectOutcome>, EffectError> {
+                Err(EffectError::General {
+                    message: "fizzled".to_string(),
+                })
+            }
+            fn triggers(&self) -> Vec<EffectTrigger> {
+                vec![EffectTrigger::OnPlay]
+            }
+            fn target_requirements(&self) -> Vec<TargetRequirement> {
+                vec![]
+            }
+        }
+
+        let mut game = Game::new();
+        let mut player = Player::new("Alice".to_string());
+        let player_id = player.id;
+        let oak = trainer("Professor Oak", TrainerType::Supporter);
+        let oak_id = oak.id;
+        game.add_card_to_database(oak);
+        let other = uuid::Uuid::new_v4();
+        player.hand = vec![other, oak_id];
+        game.add_player(player).unwrap();
+        game.trainer_effects.register(
+            "Professor Oak",
+            FailingEffect(BaseEffect::new("Fizzle".to_string(), String::new())),
+        );
+        let history_len = game.history.len();
+
+        assert!(game.play_trainer(player_id, oak_id, None).is_err());
+        let player = game.get_player(player_id).unwrap();
+        assert_eq!(player.hand, vec![other, oak_id]);
+        assert!(player.discard_pile.is_empty());
+        assert!(!player.supporter_played_this_turn);
+        assert_eq!(game.history.len(), history_len);
+    }
+
+    #[test]
+    fn test_only_basic_pokemon_can_be_benched() {
+        let mut game = Game::new();
+        let mut player = Player::new("Alice".to_string());
+        let player_id = player.id;
+
+        let raichu = stage1("Raichu", 60, "Pikachu");
+        let pikachu = pokemon("Pikachu", 60);
+        let (raichu_id, pikachu_id) = (raichu.id, pikachu.id);
+        player.active_pokemon = Some(uuid::Uuid::new_v4());
+        player.hand = vec![raichu_id, pikachu_id];
+        game.add_card_to_database(raichu);
+        game.add_card_to_database(pikachu);
+        game.turn_order = vec![player_id];
+        game.add_player(player).unwrap();
+        game.state = GameState::InProgress;
+
+        let engine = StandardRules::create_engine();
+        let play = |card_id| GameAction::PlayCard {
+            player_id,
+            card_id,
+            target: None,
+        };
+        let violations = game.execute_action(&engine, &play(raichu_id)).unwrap_err();
+        assert_eq!(violations[0].rule_name, "Bench");
+        assert!(game.play_basic_to_bench(player_id, raichu_id).is_err());
+
+        game.execute_action(&engine, &play(pikachu_id)).unwrap();
+        let player = game.get_player(player_id).unwrap();
+        assert_eq!(player.bench, vec![pikachu_id]);
+        assert_eq!(player.hand, vec![raichu_id]);
+    }
+
+    #[test]
+    fn test_tutor_basic_pokemon() {
+        let mut game = Game::new();
+        let mut player = Player::new("Alice".to_string());
+        let player_id = player.id;
+
+        let pikachu = pokemon("Pikachu", 60);
+        let pikachu_id = pikachu.id;
+        let energy_ids: Vec<CardId> = (0..4)
+            .map(|_| {
+                let card = basic_energy(EnergyType::Lightning);
+                let id = card.id;
+                game.add_card_to_database(card);
+                id
+            })
+            .collect();
+        game.add_card_to_database(pikachu);
+
+        let mut deck = energy_ids.clone();
+        deck.insert(2, pikachu_id);
+        player.set_deck(deck);
+        game.add_player(player).unwrap();
+
+        let found = game
+            .get_player(player_id)
+            .unwrap()
+            .search_deck(&game.card_database, |card| card.name == "Pikachu");
+        assert_eq!(found, vec![pikachu_id]);
+
+        game.tutor(player_id, pikachu_id).unwrap();
+
+        let player = game.get_player(player_id).unwrap();
+        assert_eq!(player.deck.len(), 4);
+        assert_eq!(player.hand, vec![pikachu_id]);
+        assert_eq!(
+            game.get_history().last(),
+            Some(&GameEvent::DeckShuffled { player_id })
+        );
+        let shuffles = |game: &Game| {
+            game.get_history()
+                .iter()
+                .filter(|event| matches!(event, GameEvent::DeckShuffled { .. }))
+                .count()
+        };
+        assert_eq!(shuffles(&game), 1);
+
+        // Searching shuffles once, even when nothing matches
+        let taken = game.search_deck_to_hand(player_id, |card| card.is_energy(), 2).unwrap();
+        assert_eq!(taken.len(), 2);
+        assert!(taken.iter().all(|id| energy_ids.contains(id)));
+        assert_eq!(game.get_player(player_id).unwrap().hand.len(), 3);
+        assert_eq!(game.search_deck_to_hand(player_id, |card| card.name == "Mew", 1), Ok(vec![]));
+        assert_eq!(shuffles(&game), 3);
+    }
+
+    #[test]
+    fn test_search_deck_to_hand_takes_up_to_max() {
+        let mut game = Game::new();
+        let mut player = Player::new("Alice".to_string());
+        let player_id = player.id;
+
+        let cards = [
+            pokemon("Pikachu", 60),
+            stage1("Raichu", 60, "Pikachu"),
+            pokemon("Eevee", 60),
+            pokemon("Snorlax", 60),
+        ];
+        let card_ids: Vec<CardId> = cards.iter().map(|card| card.id).collect();
+        for card in cards {
+            game.add_card_to_database(card);
+        }
+        player.set_deck(card_ids.clone());
+        game.add_player(player).unwrap();
+
+        let taken = game
+            .search_deck_to_hand(player_id, |card| card.is_basic_pokemon(), 2)
+            .unwrap();
+
+        let player = game.get_player(player_id).unwrap();
+        assert_eq!(taken.len(), 2);
+        assert_eq!(player.hand, taken);
+        assert_eq!(player.deck.len(), 2);
+        assert!(player.deck.contains(&card_ids[1]));
+        assert!(taken.iter().all(|card_id| !player.deck.contains(card_id)));
+        assert_eq!(
+            game.get_history().last(),
+            Some(&GameEvent::DeckShuffled { player_id })
+        );
+    }
+}
@@ -1,8 +1,9 @@
 //! 能量相关动作处理
 
 use crate::core::card::CardId;
-use crate::core::player::PlayerId;
-use crate::core::game::state::Game;
+use crate::core::player::{CardLocation, PlayerId};
+use crate::core::game::error::GameError;
+use crate::core::game::state::{Game, GameEvent};
 
 /// 能量附加动作
 #[derive(Debug, Clone)]
@@ -22,41 +23,89 @@ impl AttachEnergyAction {
     }
 
     /// 执行能量附加动作
-    pub fn execute(&self, game: &mut Game) -> Result<(), String> {
-        // 检查玩家是否存在
-        let player = game.get_player_mut(self.player_id)
-            .ok_or("Player not found")?;
-        
-        // 检查能量卡是否在玩家手中
-        if !player.hand.contains(&self.energy_card_id) {
-            return Err("Energy card not in player's hand".to_string());
+    pub fn execute(&self, game: &mut Game) -> Result<(), GameError> {
+        game.attach_energy(self.player_id, self.energy_card_id, self.target_pokemon_id)
+    }
+}
+
+impl Game {
+    /// 从手牌附加一张能量到场上的宝可梦
+    ///
+    /// 根据卡牌数据库检查：附加的卡必须是能量卡，目标必须是宝可梦
+    /// （数据库中没有的卡不做类型检查）。成功后计入本回合的附加次数。
+    pub fn attach_energy(
+        &mut self,
+        player_id: PlayerId,
+        energy_id: CardId,
+        pokemon_id: CardId,
+    ) -> Result<(), GameError> {
+        if self.get_card(energy_id).is_some_and(|card| !card.is_energy()) {
+            return Err(GameError::InvalidAction("Card is not an energy".to_string()));
         }
-        
-        // 检查目标宝可梦是否在玩家场上
-        let is_active = player.active_pokemon == Some(self.target_pokemon_id);
-        let is_on_bench = player.bench.contains(&self.target_pokemon_id);
-        
-        if !is_active && !is_on_bench {
-            return Err("Target Pokemon not on player's field".to_string());
+        if self.get_card(pokemon_id).is_some_and(|card| !card.is_pokemon()) {
+            return Err(GameError::InvalidTarget("Target is not a Pokemon".to_string()));
         }
-        
-        // 移除手牌中的能量卡
-        player.hand.retain(|&id| id != self.energy_card_id);
-        
-        // 将能量卡附加到目标宝可梦
-        // 注意：在实际实现中，我们可能需要将能量卡移动到一个专门的附加能量区域
-        // 这里我们简化处理，只是从手牌中移除
-        
+
+        let player = self
+            .get_player_mut(player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        player.attach_energy_from(energy_id, pokemon_id, CardLocation::Hand)
+            .map_err(GameError::InvalidAction)?;
+        player.energy_attached_this_turn += 1;
+
+        self.add_event(GameEvent::EnergyAttached {
+            player_id,
+            energy_id,
+            pokemon_id,
+        });
         Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::core::card::{EnergyType, TrainerType};
+    use crate::core::player::Player;
+    use crate::test_fixtures::{basic_energy, pokemon, trainer};
 
     #[test]
     fn test_energy_actions_module() {
         // 这是一个占位测试，确保模块结构正确
         assert_eq!(2 + 2, 4);
     }
+
+    #[test]
+    fn test_attach_energy_checks_card_types() {
+        let mut game = Game::new();
+        let mut alice = Player::new("Alice".to_string());
+        let alice_id = alice.id;
+        let energy = basic_energy(EnergyType::Fire);
+        let trainer = trainer("Potion", TrainerType::Item);
+        let pokemon = pokemon("Charmander", 50);
+        let (energy_id, trainer_id, pokemon_id) = (energy.id, trainer.id, pokemon.id);
+        // 训练家卡被错误地放在了备战区
+        alice.active_pokemon = Some(pokemon_id);
+        alice.bench.push(trainer_id);
+        alice.hand = vec![energy_id, pokemon_id];
+        for card in [energy, trainer, pokemon] {
+            game.add_card_to_database(card);
+        }
+        game.add_player(alice).unwrap();
+
+        assert_eq!(
+            game.attach_energy(alice_id, energy_id, trainer_id),
+            Err(GameError::InvalidTarget("Target is not a Pokemon".to_string()))
+        );
+        assert_eq!(
+            game.attach_energy(alice_id, pokemon_id, pokemon_id),
+            Err(GameError::InvalidAction("Card is not an energy".to_string()))
+        );
+        assert!(game.get_player(alice_id).unwrap().attached_energy.is_empty());
+
+        game.attach_energy(alice_id, energy_id, pokemon_id).unwrap();
+        let alice = game.get_player(alice_id).unwrap();
+        assert_eq!(alice.attached_energy[&pokemon_id], vec![energy_id]);
+        assert_eq!(alice.energy_attached_this_turn, 1);
+    }
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 //! Game action execution
 
+use crate::core::game::error::GameError;
 use crate::core::game::state::{Game, GameEvent};
 
 impl Game {
@@ -17,6 +18,21 @@ impl Game {
         rule_engine: &crate::core::rules::RuleEngine,
         action: &crate::core::rules::GameAction,
     ) -> Result<(), Vec<crate::core::rules::RuleViolation>> {
+        // Knockouts must be resolved before anything else happens
+        if !self.pending_promotions.is_empty()
+            && !matches!(
+                action,
+                crate::core::rules::GameAction::PromoteActive { .. }
+                    | crate::core::rules::GameAction::Concede { .. }
+            )
+        {
+            return Err(vec![crate::core::rules::RuleViolation {
+                rule_name: "PendingPromotion".to_string(),
+                message: "A new active Pokemon must be promoted first".to_string(),
+                severity: crate::core::rules::ViolationSeverity::Error,
+            }]);
+        }
+
         // First validate the action
         let violations = rule_engine.validate_action(self, action);
 
@@ -52,63 +68,390 @@ impl Game {
             crate::core::rules::GameAction::PlayCard {
                 player_id,
                 card_id,
-                target: _,
+                target,
             } => {
-                // TODO: Implement playing cards
-                self.add_event(GameEvent::CardPlayed {
-                    player_id: *player_id,
-                    card_id: *card_id,
+                let trainer_type = self.card_database.get(card_id).and_then(|card| match &card.card_type {
+                    crate::core::card::CardType::Trainer { trainer_type } => Some(trainer_type.clone()),
+                    _ => None,
                 });
+                let has_trainer_effect = self
+                    .card_database
+                    .get(card_id)
+                    .is_some_and(|card| card.is_trainer() && self.trainer_effects.contains(&card.name));
+                if self.card_database.get(card_id).is_some_and(|card| card.is_pokemon()) {
+                    self.play_basic_to_bench(*player_id, *card_id).map_err(|error| {
+                        vec![crate::core::rules::RuleViolation {
+                            rule_name: "Bench".to_string(),
+                            message: error.to_string(),
+                            severity: crate::core::rules::ViolationSeverity::Error,
+                        }]
+                    })?;
+                } else if trainer_type == Some(crate::core::card::TrainerType::Stadium) {
+                    self.play_stadium(*player_id, *card_id).map_err(|error| {
+                        vec![crate::core::rules::RuleViolation {
+                            rule_name: "Stadium".to_string(),
+                            message: error.to_string(),
+                            severity: crate::core::rules::ViolationSeverity::Error,
+                        }]
+                    })?;
+                } else if let (Some(crate::core::card::TrainerType::Tool), Some(pokemon_id)) =
+                    (&trainer_type, target)
+                {
+                    self.attach_tool(*player_id, *card_id, *pokemon_id).map_err(|error| {
+                        vec![crate::core::rules::RuleViolation {
+                            rule_name: "Tool".to_string(),
+                            message: error.to_string(),
+                            severity: crate::core::rules::ViolationSeverity::Error,
+                        }]
+                    })?;
+                } else if has_trainer_effect {
+                    self.play_trainer(*player_id, *card_id, *target).map_err(|error| {
+                        vec![crate::core::rules::RuleViolation {
+                            rule_name: "Trainer".to_string(),
+                            message: error.to_string(),
+                            severity: crate::core::rules::ViolationSeverity::Error,
+                        }]
+                    })?;
+                } else {
+                    let name = self
+                        .card_database
+                        .get(card_id)
+                        .map_or("Unknown card", |card| card.name.as_str());
+                    return Err(vec![crate::core::rules::RuleViolation {
+                        rule_name: "Trainer".to_string(),
+                        message: format!("{} has no playable effect", name),
+                        severity: crate::core::rules::ViolationSeverity::Error,
+                    }]);
+                }
             }
             crate::core::rules::GameAction::AttachEnergy {
                 player_id,
                 energy_id,
                 pokemon_id,
             } => {
-                if let Some(player) = self.players.get_mut(player_id)
-                    && player.attach_energy(*energy_id, *pokemon_id) {
-                        self.add_event(GameEvent::EnergyAttached {
-                            player_id: *player_id,
-                            energy_id: *energy_id,
-                            pokemon_id: *pokemon_id,
-                        });
-                    }
+                self.attach_energy(*player_id, *energy_id, *pokemon_id).map_err(|error| {
+                    vec![crate::core::rules::RuleViolation {
+                        rule_name: "EnergyAttachment".to_string(),
+                        message: error.to_string(),
+                        severity: crate::core::rules::ViolationSeverity::Error,
+                    }]
+                })?;
             }
             crate::core::rules::GameAction::UseAttack {
                 player_id,
                 pokemon_id,
                 attack_index,
+                target,
             } => {
-                // TODO: Implement attack logic
-                self.add_event(GameEvent::AttackUsed {
-                    player_id: *player_id,
-                    pokemon_id: *pokemon_id,
-                    attack_name: format!("Attack {}", attack_index),
-                });
+                let to_violation = |error: GameError| {
+                    vec![crate::core::rules::RuleViolation {
+                        rule_name: "Attack".to_string(),
+                        message: error.to_string(),
+                        severity: crate::core::rules::ViolationSeverity::Error,
+                    }]
+                };
+                self.resolve_attack(*player_id, *pokemon_id, *attack_index, *target)
+                    .map_err(to_violation)?;
+                self.process_knockouts().map_err(to_violation)?;
             }
             crate::core::rules::GameAction::Retreat {
-                player_id: _,
-                pokemon_id: _,
+                player_id,
+                pokemon_id,
             } => {
-                // TODO: Implement retreat logic
+                self.retreat_active(*player_id, *pokemon_id).map_err(|error| {
+                    vec![crate::core::rules::RuleViolation {
+                        rule_name: "Retreat".to_string(),
+                        message: error.to_string(),
+                        severity: crate::core::rules::ViolationSeverity::Error,
+                    }]
+                })?;
+            }
+            crate::core::rules::GameAction::Evolve {
+                player_id,
+                card_id,
+                pokemon_id,
+            } => {
+                self.evolve_pokemon(*player_id, *card_id, *pokemon_id).map_err(|error| {
+                    vec![crate::core::rules::RuleViolation {
+                        rule_name: "Evolve".to_string(),
+                        message: error.to_string(),
+                        severity: crate::core::rules::ViolationSeverity::Error,
+                    }]
+                })?;
+            }
+            crate::core::rules::GameAction::PromoteActive {
+                player_id,
+                bench_index,
+            } => {
+                self.promote_active(*player_id, *bench_index).map_err(|error| {
+                    vec![crate::core::rules::RuleViolation {
+                        rule_name: "PromoteActive".to_string(),
+                        message: error.to_string(),
+                        severity: crate::core::rules::ViolationSeverity::Error,
+                    }]
+                })?;
             }
             crate::core::rules::GameAction::EndTurn { player_id } => {
                 self.add_event(GameEvent::TurnEnded {
                     player_id: *player_id,
                 });
-                // Move to next player
-                self.current_player_index = (self.current_player_index + 1) % self.turn_order.len();
-                self.turn_number += 1;
-                // Reset turn-based flags for the next player
-                if let Some(player) = self.players.get_mut(player_id) {
-                    player.start_turn();
+                self.advance_to_next_player();
+                self.phase = crate::core::game::state::GamePhase::BeginningOfTurn;
+                // The timer starts on the first clock reading of the new turn
+                self.turn_started_at = None;
+                if let Ok(next_player_id) = self.get_current_player_id() {
+                    // Reset turn-based flags for the next player
+                    if let Some(player) = self.players.get_mut(&next_player_id) {
+                        player.start_turn();
+                    }
+                    self.add_event(GameEvent::TurnStarted {
+                        player_id: next_player_id,
+                        turn_number: self.turn_number,
+                    });
                 }
             }
             crate::core::rules::GameAction::Pass { player_id: _ } => {
                 // TODO: Implement pass logic
             }
+            crate::core::rules::GameAction::Concede { player_id } => {
+                self.concede(*player_id).map_err(|error| {
+                    vec![crate::core::rules::RuleViolation {
+                        rule_name: "Concede".to_string(),
+                        message: error.to_string(),
+                        severity: crate::core::rules::ViolationSeverity::Error,
+                    }]
+                })?;
+            }
         }
 
+        debug_assert_eq!(self.validate_board_invariants(), Ok(()));
+
         Ok(())
     }
-}
\ No newline at end of file
+
+    /// Execute a game action and return the events it emitted
+    ///
+    /// Same as `execute_action`, but hands back the new history entries so
+    /// callers do not have to diff the history themselves.
+    pub fn apply_action_collecting(
+        &mut self,
+        rule_engine: &crate::core::rules::RuleEngine,
+        action: &crate::core::rules::GameAction,
+    ) -> Result<Vec<GameEvent>, Vec<crate::core::rules::RuleViolation>> {
+        let history_len = self.history.len();
+        self.execute_action(rule_engine, action)?;
+        Ok(self.events_since(history_len).to_vec())
+    }
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::card::{Attack, AttackTargetType, Card, TrainerType};
+    use crate::core::effects::DrawCardsEffect;
+    use crate::core::game::state::{GameRules, GameState};
+    use crate::core::player::{Player, PlayerId};
+    use crate::core::rules::{GameAction, StandardRules};
+    use crate::test_fixtures::{pokemon, trainer};
+    use uuid::Uuid;
+
+    fn pokemon_with_free_attack() -> Card {
+        let mut card = pokemon("Rattata", 40);
+        card.add_attack(Attack::simple("Tackle".to_string(), Vec::new(), 10));
+        card
+    }
+
+    /// Alice (first player) and Bob each have an active Pokemon with a free 10-damage attack
+    fn in_progress_game(rules: GameRules) -> (Game, PlayerId) {
+        let mut game = Game::with_rules(rules);
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let alice_id = alice.id;
+        for player in [&mut alice, &mut bob] {
+            let pokemon = pokemon_with_free_attack();
+            player.active_pokemon = Some(pokemon.id);
+            game.add_card_to_database(pokemon);
+        }
+        game.turn_order = vec![alice_id, bob.id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.state = GameState::InProgress;
+        (game, alice_id)
+    }
+
+    #[test]
+    fn test_standard_rules_forbid_first_turn_attack() {
+        let engine = StandardRules::create_engine();
+        let (mut game, alice_id) = in_progress_game(GameRules::standard());
+        let pokemon_id = game.get_player(alice_id).unwrap().active_pokemon.unwrap();
+        let action = GameAction::UseAttack {
+            player_id: alice_id,
+            pokemon_id,
+            attack_index: 0,
+            target: None,
+        };
+
+        assert!(!engine.is_action_legal(&game, &action));
+        assert!(!game.legal_actions(&engine).contains(&action));
+        let violations = game.execute_action(&engine, &action).unwrap_err();
+        assert_eq!(violations[0].rule_name, "FirstTurn");
+        assert_eq!(violations[0].message, "The first player cannot attack until after turn 1");
+
+        let (mut game, alice_id) = in_progress_game(GameRules::default());
+        let action = GameAction::UseAttack {
+            player_id: alice_id,
+            pokemon_id: game.get_player(alice_id).unwrap().active_pokemon.unwrap(),
+            attack_index: 0,
+            target: None,
+        };
+        assert!(game.execute_action(&engine, &action).is_ok());
+    }
+
+    #[test]
+    fn test_first_player_restriction_flags() {
+        let engine = StandardRules::create_engine();
+        let attack = |game: &mut Game, alice_id: PlayerId| {
+            let action = GameAction::UseAttack {
+                player_id: alice_id,
+                pokemon_id: game.get_player(alice_id).unwrap().active_pokemon.unwrap(),
+                attack_index: 0,
+                target: None,
+            };
+            game.execute_action(&engine, &action)
+        };
+        let play_supporter = |game: &mut Game, alice_id: PlayerId| {
+            let supporter = trainer("Professor's Research", TrainerType::Supporter);
+            let card_id = supporter.id;
+            game.add_card_to_database(supporter);
+            game.trainer_effects.register("Professor's Research", DrawCardsEffect::draw(1));
+            game.get_player_mut(alice_id).unwrap().hand.push(card_id);
+            game.execute_action(
+                &engine,
+                &GameAction::PlayCard {
+                    player_id: alice_id,
+                    card_id,
+                    target: None,
+                },
+            )
+        };
+
+        let rules = GameRules::builder().first_player_can_attack(false).build().unwrap();
+        let (mut game, alice_id) = in_progress_game(rules);
+        assert_eq!(attack(&mut game, alice_id).unwrap_err()[0].rule_name, "FirstTurn");
+        assert!(play_supporter(&mut game, alice_id).is_ok());
+
+        let rules = GameRules::builder().first_player_can_supporter(false).build().unwrap();
+        let (mut game, alice_id) = in_progress_game(rules);
+        let violations = play_supporter(&mut game, alice_id).unwrap_err();
+        assert_eq!(violations[0].rule_name, "FirstTurn");
+        assert_eq!(violations[0].message, "The first player cannot play a Supporter until after turn 1");
+        assert!(attack(&mut game, alice_id).is_ok());
+
+        let rules = GameRules::builder()
+            .first_player_can_attack(false)
+            .first_turn_number_threshold(2)
+            .build()
+            .unwrap();
+        let (mut game, alice_id) = in_progress_game(rules.clone());
+        game.turn_number = 2;
+        assert_eq!(
+            attack(&mut game, alice_id).unwrap_err()[0].message,
+            "The first player cannot attack until after turn 2"
+        );
+        let (mut game, alice_id) = in_progress_game(rules);
+        game.turn_number = 3;
+        assert!(attack(&mut game, alice_id).is_ok());
+    }
+
+    #[test]
+    fn test_apply_action_collecting_returns_new_events() {
+        let engine = StandardRules::create_engine();
+        let (mut game, alice_id) = in_progress_game(GameRules::default());
+        let card_id = Uuid::new_v4();
+        game.get_player_mut(alice_id).unwrap().deck.push(card_id);
+
+        let events = game
+            .apply_action_collecting(&engine, &GameAction::DrawCard { player_id: alice_id })
+            .unwrap();
+        assert_eq!(
+            events,
+            vec![GameEvent::CardDrawn {
+                player_id: alice_id,
+                card_id: Some(card_id),
+            }]
+        );
+        assert_eq!(game.history.last(), events.last());
+    }
+
+    #[test]
+    fn test_concede_on_opponents_turn_ends_game() {
+        let engine = StandardRules::create_engine();
+        let (mut game, alice_id) = in_progress_game(GameRules::default());
+        let bob_id = game.get_opponent_id(alice_id).unwrap();
+        game.pending_promotions.push(bob_id);
+
+        game.execute_action(&engine, &GameAction::Concede { player_id: bob_id })
+            .unwrap();
+
+        assert_eq!(game.state, GameState::Finished { winner: Some(alice_id) });
+        assert_eq!(
+            game.history.last(),
+            Some(&GameEvent::GameEnded {
+                winner: Some(alice_id),
+                reason: "Bob conceded".to_string(),
+            })
+        );
+        let violations = game
+            .execute_action(&engine, &GameAction::Concede { player_id: alice_id })
+            .unwrap_err();
+        assert_eq!(violations[0].rule_name, "Concede");
+    }
+
+    #[test]
+    fn test_choose_attack_uses_supplied_target() {
+        let engine = StandardRules::create_engine();
+        let (mut game, alice_id) = in_progress_game(GameRules::default());
+        let bob_id = game.get_opponent_id(alice_id).unwrap();
+        let attacker_id = game.get_player(alice_id).unwrap().active_pokemon.unwrap();
+        let bob_active = game.get_player(bob_id).unwrap().active_pokemon.unwrap();
+
+        let mut snipe = Attack::simple("Snipe".to_string(), Vec::new(), 20);
+        snipe.set_target_type(AttackTargetType::Choose);
+        game.card_database.get_mut(&attacker_id).unwrap().add_attack(snipe);
+
+        let benched = pokemon_with_free_attack();
+        let benched_id = benched.id;
+        game.add_card_to_database(benched);
+        game.get_player_mut(bob_id).unwrap().bench.push(benched_id);
+
+        let action = GameAction::UseAttack {
+            player_id: alice_id,
+            pokemon_id: attacker_id,
+            attack_index: 1,
+            target: Some(benched_id),
+        };
+        game.execute_action(&engine, &action).unwrap();
+
+        let bob = game.get_player(bob_id).unwrap();
+        assert_eq!(bob.damage_counters.get(&benched_id), Some(&20));
+        assert_eq!(bob.damage_counters.get(&bob_active), None);
+    }
+
+    #[test]
+    fn test_energy_attachment_limited_per_turn() {
+        let engine = StandardRules::create_engine();
+        let (mut game, alice_id) = in_progress_game(GameRules::standard());
+        let pokemon_id = game.get_player(alice_id).unwrap().active_pokemon.unwrap();
+        let energy = [Uuid::new_v4(), Uuid::new_v4()];
+        game.get_player_mut(alice_id).unwrap().hand.extend(energy);
+
+        let attach = |energy_id| GameAction::AttachEnergy {
+            player_id: alice_id,
+            energy_id,
+            pokemon_id,
+        };
+
+        assert!(game.execute_action(&engine, &attach(energy[0])).is_ok());
+        assert!(game.execute_action(&engine, &attach(energy[1])).is_err());
+        assert_eq!(game.get_player(alice_id).unwrap().get_attached_energy_count(pokemon_id), 1);
+    }
+}
@@ -0,0 +1,319 @@
+//! Knockout processing and promotion of a new active Pokemon
+
+use crate::core::card::CardId;
+use crate::core::game::error::GameError;
+use crate::core::game::state::{Game, GameEvent};
+use crate::core::player::PlayerId;
+
+impl Game {
+    /// Knock out every Pokemon in play whose damage has reached its HP
+    ///
+    /// Knocked-out Pokemon are discarded together with the cards they evolved
+    /// from and their attached cards,
+    /// each opponent takes as many prize cards as the Pokemon's
+    /// `Card::prize_value` (capped at their remaining prizes), and a player
+    /// whose active Pokemon was knocked out is queued in `pending_promotions`
+    /// if they have a benched Pokemon to promote. Win conditions are checked
+    /// afterwards.
+    ///
+    /// Returns the knocked-out Pokemon.
+    pub fn process_knockouts(&mut self) -> Result<Vec<CardId>, GameError> {
+        let mut player_ids: Vec<PlayerId> = self.players.keys().copied().collect();
+        player_ids.sort();
+
+        // Find knocked-out Pokemon first, then resolve them
+        let mut knockouts = Vec::new();
+        for &player_id in &player_ids {
+            let player = &self.players[&player_id];
+            for pokemon_id in player.active_pokemon.iter().chain(player.bench.iter()) {
+                if let Some(card) = self.card_database.get(pokemon_id)
+                    && player.is_pokemon_knocked_out(*pokemon_id, card)
+                {
+                    let was_active = player.active_pokemon == Some(*pokemon_id);
+                    knockouts.push((player_id, *pokemon_id, was_active, card.prize_value()));
+                }
+            }
+        }
+
+        for &(owner_id, pokemon_id, was_active, prizes) in &knockouts {
+            let owner = self
+                .players
+                .get_mut(&owner_id)
+                .ok_or(GameError::PlayerNotFound)?;
+            owner.discard_from_field(pokemon_id);
+            let needs_promotion = was_active && !owner.bench.is_empty();
+
+            self.add_event(GameEvent::PokemonKnockedOut {
+                player_id: owner_id,
+                pokemon_id,
+            });
+
+            for &opponent_id in player_ids.iter().filter(|&&id| id != owner_id) {
+                for _ in 0..prizes {
+                    let taken = self
+                        .players
+                        .get_mut(&opponent_id)
+                        .is_some_and(|opponent| opponent.take_prize_card());
+                    if !taken {
+                        break;
+                    }
+                    self.add_event(GameEvent::PrizeTaken {
+                        player_id: opponent_id,
+                    });
+                }
+            }
+
+            if needs_promotion && !self.pending_promotions.contains(&owner_id) {
+                self.pending_promotions.push(owner_id);
+            }
+        }
+
+        if !knockouts.is_empty() {
+            for player in self.players.values_mut() {
+                player.prune_orphaned_state();
+            }
+            self.check_win_conditions()?;
+        }
+
+        Ok(knockouts.into_iter().map(|(_, pokemon_id, _, _)| pokemon_id).collect())
+    }
+
+    /// Players who must promote a benched Pokemon before play continues
+    ///
+    /// While this is non-empty, `execute_action` rejects everything except
+    /// `GameAction::PromoteActive`. A player whose active Pokemon is knocked
+    /// out with an empty bench is never listed: they lose instead.
+    pub fn needs_active_promotion(&self) -> Vec<PlayerId> {
+        self.pending_promotions.clone()
+    }
+
+    /// Promote a benched Pokemon to the active spot after a knockout
+    pub fn promote_active(&mut self, player_id: PlayerId, bench_index: usize) -> Result<(), GameError> {
+        if !self.pending_promotions.contains(&player_id) {
+            return Err(GameError::InvalidAction("Player does not owe a promotion".to_string()));
+        }
+
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        if player.active_pokemon.is_some() {
+            return Err(GameError::InvalidAction("Player already has an active Pokemon".to_string()));
+        }
+        if bench_index >= player.bench.len() {
+            return Err(GameError::InvalidAction("Invalid bench index".to_string()));
+        }
+
+        let pokemon_id = player.bench.remove(bench_index);
+        player.active_pokemon = Some(pokemon_id);
+        self.pending_promotions.retain(|&id| id != player_id);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::card::EvolutionStage;
+    use crate::core::game::state::{GameRules, GameState, TieBreak};
+    use crate::core::player::Player;
+    use crate::core::rules::{GameAction, StandardRules};
+    use crate::test_fixtures::{pokemon, pokemon_at_stage};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_knockout_requires_promotion() {
+        let mut game = Game::with_rules(GameRules::default());
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+
+        let alice_active = pokemon("Magikarp", 30);
+        let bob_active = pokemon("Magikarp", 30);
+        let bob_benched = pokemon("Magikarp", 30);
+        let (bob_active_id, bob_benched_id) = (bob_active.id, bob_benched.id);
+        alice.active_pokemon = Some(alice_active.id);
+        bob.active_pokemon = Some(bob_active_id);
+        bob.bench.push(bob_benched_id);
+        bob.add_damage(bob_active_id, 30);
+
+        for card in [alice_active, bob_active, bob_benched] {
+            game.add_card_to_database(card);
+        }
+        game.turn_order = vec![alice_id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.state = GameState::InProgress;
+
+        assert_eq!(game.process_knockouts().unwrap(), vec![bob_active_id]);
+        assert_eq!(game.needs_active_promotion(), vec![bob_id]);
+        assert_eq!(game.get_player(alice_id).unwrap().prize_cards, 5);
+        assert!(game.get_player(bob_id).unwrap().discard_pile.contains(&bob_active_id));
+
+        let engine = StandardRules::create_engine();
+        let end_turn = GameAction::EndTurn { player_id: alice_id };
+        assert!(game.execute_action(&engine, &end_turn).is_err());
+
+        let promote = |bench_index| GameAction::PromoteActive {
+            player_id: bob_id,
+            bench_index,
+        };
+        assert!(game.execute_action(&engine, &promote(1)).is_err());
+        game.execute_action(&engine, &promote(0)).unwrap();
+
+        assert!(game.needs_active_promotion().is_empty());
+        assert_eq!(game.get_player(bob_id).unwrap().active_pokemon, Some(bob_benched_id));
+        assert!(game.execute_action(&engine, &end_turn).is_ok());
+    }
+
+    #[test]
+    fn test_knockout_with_empty_bench_loses() {
+        let mut game = Game::with_rules(GameRules::default());
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+
+        let alice_active = pokemon("Magikarp", 30);
+        let bob_active = pokemon("Magikarp", 30);
+        alice.active_pokemon = Some(alice_active.id);
+        bob.active_pokemon = Some(bob_active.id);
+        bob.add_damage(bob_active.id, 30);
+
+        for card in [alice_active, bob_active] {
+            game.add_card_to_database(card);
+        }
+        game.turn_order = vec![alice_id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.state = GameState::InProgress;
+
+        game.process_knockouts().unwrap();
+
+        assert!(game.needs_active_promotion().is_empty());
+        assert!(game.promote_active(bob_id, 0).is_err());
+        assert_eq!(
+            game.state,
+            GameState::Finished {
+                winner: Some(alice_id)
+            }
+        );
+    }
+
+    #[test]
+    fn test_knockout_discards_evolution_stack() {
+        let mut game = Game::with_rules(GameRules::default());
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+
+        let alice_active = pokemon("Magikarp", 30);
+        let bob_stage2 = pokemon_at_stage("Magikarp", 150, EvolutionStage::Stage2);
+        let bob_stage2_id = bob_stage2.id;
+        let (basic_id, stage1_id) = (Uuid::new_v4(), Uuid::new_v4());
+        let energy: Vec<CardId> = (0..2).map(|_| Uuid::new_v4()).collect();
+        let tool_id = Uuid::new_v4();
+        alice.active_pokemon = Some(alice_active.id);
+        bob.active_pokemon = Some(bob_stage2_id);
+        bob.evolution_stacks.insert(bob_stage2_id, vec![basic_id, stage1_id]);
+        bob.attached_energy.insert(bob_stage2_id, energy.clone());
+        bob.attached_tools.insert(bob_stage2_id, tool_id);
+        bob.add_damage(bob_stage2_id, 150);
+
+        for card in [alice_active, bob_stage2] {
+            game.add_card_to_database(card);
+        }
+        game.turn_order = vec![alice_id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.state = GameState::InProgress;
+
+        assert_eq!(game.process_knockouts().unwrap(), vec![bob_stage2_id]);
+
+        let bob = game.get_player(bob_id).unwrap();
+        for card_id in [bob_stage2_id, basic_id, stage1_id, energy[0], energy[1], tool_id] {
+            assert!(bob.discard_pile.contains(&card_id));
+        }
+        assert_eq!(bob.discard_pile.len(), 6);
+        assert!(bob.evolution_stacks.is_empty());
+        assert!(bob.attached_tools.is_empty());
+        assert!(bob.attached_energy.is_empty() && bob.damage_counters.is_empty());
+    }
+
+    #[test]
+    fn test_knockout_awards_prize_value() {
+        let mut game = Game::with_rules(GameRules::default());
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+
+        let alice_active = pokemon("Magikarp", 30);
+        let bob_v = pokemon_at_stage("Magikarp", 200, EvolutionStage::V);
+        let bob_basic = pokemon("Magikarp", 30);
+        let (bob_v_id, bob_basic_id) = (bob_v.id, bob_basic.id);
+        alice.active_pokemon = Some(alice_active.id);
+        bob.active_pokemon = Some(bob_v_id);
+        bob.bench.push(bob_basic_id);
+        bob.add_damage(bob_v_id, 200);
+
+        for card in [alice_active, bob_v, bob_basic] {
+            game.add_card_to_database(card);
+        }
+        game.turn_order = vec![alice_id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.state = GameState::InProgress;
+
+        game.process_knockouts().unwrap();
+        assert_eq!(game.get_player(alice_id).unwrap().prize_cards, 4);
+
+        game.promote_active(bob_id, 0).unwrap();
+        game.get_player_mut(bob_id).unwrap().add_damage(bob_basic_id, 30);
+        game.process_knockouts().unwrap();
+        assert_eq!(game.get_player(alice_id).unwrap().prize_cards, 3);
+    }
+
+    #[test]
+    fn test_both_players_taking_last_prize_uses_tiebreak() {
+        let resolve = |tiebreak| {
+            let mut game = Game::with_rules(GameRules::builder().tiebreak(tiebreak).build().unwrap());
+            let mut alice = Player::new("Alice".to_string());
+            let mut bob = Player::new("Bob".to_string());
+            let alice_id = alice.id;
+            for player in [&mut alice, &mut bob] {
+                let (active, benched) = (pokemon("Magikarp", 30), pokemon("Magikarp", 30));
+                player.active_pokemon = Some(active.id);
+                player.bench.push(benched.id);
+                player.add_damage(active.id, 30);
+                game.add_card_to_database(active);
+                game.add_card_to_database(benched);
+            }
+            game.turn_order = vec![alice_id, bob.id];
+            game.add_player(alice).unwrap();
+            game.add_player(bob).unwrap();
+            for player in game.players.values_mut() {
+                player.prize_cards = 1;
+            }
+            game.state = GameState::InProgress;
+
+            game.process_knockouts().unwrap();
+            let Some(GameEvent::GameEnded { winner, reason }) = game.history.last().cloned() else {
+                panic!("game did not end");
+            };
+            assert_eq!(game.state, GameState::Finished { winner });
+            (winner, reason, alice_id)
+        };
+
+        let (winner, reason, alice_id) = resolve(TieBreak::TurnPlayerWins);
+        assert_eq!(winner, Some(alice_id));
+        assert_eq!(reason, "Both players won at once; the turn player wins");
+
+        let (winner, reason, _) = resolve(TieBreak::SuddenDeath);
+        assert_eq!(winner, None);
+        assert_eq!(reason, "Both players won at once; sudden death");
+
+        let (winner, reason, _) = resolve(TieBreak::Draw);
+        assert_eq!(winner, None);
+        assert_eq!(reason, "Both players won at once; draw");
+    }
+}
@@ -0,0 +1,288 @@
+//! Enumeration of legal actions for the current player
+
+use crate::core::card::{AttackTargetType, CardId};
+use crate::core::game::state::{Game, GamePhase, GameState};
+use crate::core::player::PlayerId;
+use crate::core::rules::{GameAction, RuleEngine};
+
+impl Game {
+    /// List every action the current player can legally take right now
+    ///
+    /// Candidate actions are generated from the board (draw, energy
+    /// attachments, affordable attacks, retreats and ending the turn) and
+    /// then filtered through the rule engine, keeping only those without
+    /// `Error` or `Fatal` violations. While a knockout is unresolved, only the
+    /// promotions of the first player in `pending_promotions` are listed.
+    pub fn legal_actions(&self, rule_engine: &RuleEngine) -> Vec<GameAction> {
+        self.candidate_actions()
+            .into_iter()
+            .filter(|action| rule_engine.is_action_legal(self, action))
+            .collect()
+    }
+
+    /// Pokemon of `player_id` that can attack now, with the attacks they can pay for
+    ///
+    /// Only the active Pokemon attacks; it is left out once the player has
+    /// attacked this turn, while a special condition prevents attacking, or
+    /// during the first player's restricted first turn. Attack indices are
+    /// those whose energy cost the attached energy covers.
+    pub fn attackable_pokemon(&self, player_id: PlayerId) -> Vec<(CardId, Vec<usize>)> {
+        let Some(player) = self.get_player(player_id) else {
+            return Vec::new();
+        };
+        let first_turn_blocked = !self.rules.first_player_can_attack
+            && self.first_player_restricted()
+            && self.get_current_player_id() == Ok(player_id);
+        if player.has_attacked || first_turn_blocked {
+            return Vec::new();
+        }
+
+        player
+            .active_pokemon
+            .into_iter()
+            .filter(|&pokemon_id| player.can_pokemon_attack(pokemon_id))
+            .filter_map(|pokemon_id| {
+                let card = self.get_card(pokemon_id)?;
+                let attached = player.get_attached_energy_types(pokemon_id, &self.card_database);
+                let indices: Vec<usize> = card
+                    .get_usable_attacks(&attached)
+                    .into_iter()
+                    .map(|(index, _)| index)
+                    .collect();
+                (!indices.is_empty()).then_some((pokemon_id, indices))
+            })
+            .collect()
+    }
+
+    /// Generate candidate actions for the current player before rule validation
+    fn candidate_actions(&self) -> Vec<GameAction> {
+        let mut actions = Vec::new();
+
+        if self.state != GameState::InProgress {
+            return actions;
+        }
+
+        if let Some(&player_id) = self.pending_promotions.first() {
+            if let Some(player) = self.get_player(player_id) {
+                actions.extend((0..player.bench.len()).map(|bench_index| {
+                    GameAction::PromoteActive {
+                        player_id,
+                        bench_index,
+                    }
+                }));
+            }
+            return actions;
+        }
+
+        let Ok(player) = self.get_current_player() else {
+            return actions;
+        };
+        let player_id = player.id;
+
+        // Draw for turn
+        if self.phase == GamePhase::BeginningOfTurn && !player.deck.is_empty() {
+            actions.push(GameAction::DrawCard { player_id });
+        }
+
+        // Attach each energy card in hand to each Pokemon in play
+        let in_play: Vec<_> = player
+            .active_pokemon
+            .iter()
+            .chain(player.bench.iter())
+            .copied()
+            .collect();
+        for &energy_id in &player.hand {
+            if !self.get_card(energy_id).is_some_and(|card| card.is_energy()) {
+                continue;
+            }
+            for &pokemon_id in &in_play {
+                actions.push(GameAction::AttachEnergy {
+                    player_id,
+                    energy_id,
+                    pokemon_id,
+                });
+            }
+        }
+
+        if let Some(active_id) = player.active_pokemon {
+            // Attacks the active Pokemon can pay for
+            let opponent = self
+                .get_opponent_id(player_id)
+                .ok()
+                .and_then(|opponent_id| self.get_player(opponent_id));
+            for (pokemon_id, attack_indices) in self.attackable_pokemon(player_id) {
+                let Some(card) = self.get_card(pokemon_id) else {
+                    continue;
+                };
+                for attack_index in attack_indices {
+                    let attack = &card.attacks[attack_index];
+                    // One action per possible target for attacks that choose one
+                    let targets: Vec<Option<CardId>> = match (&attack.target_type, opponent) {
+                        (AttackTargetType::Choose, Some(opponent)) => opponent
+                            .active_pokemon
+                            .iter()
+                            .chain(opponent.bench.iter())
+                            .map(|&id| Some(id))
+                            .collect(),
+                        (AttackTargetType::Bench, Some(opponent)) => {
+                            opponent.bench.iter().map(|&id| Some(id)).collect()
+                        }
+                        (AttackTargetType::Choose | AttackTargetType::Bench, None) => Vec::new(),
+                        _ => vec![None],
+                    };
+                    for target in targets {
+                        actions.push(GameAction::UseAttack {
+                            player_id,
+                            pokemon_id,
+                            attack_index,
+                            target,
+                        });
+                    }
+                }
+            }
+
+            // Retreat to each benched Pokemon
+            let may_retreat = !player.has_retreated
+                && player.can_pokemon_retreat(active_id)
+                && player.get_attached_energy_count(active_id)
+                    >= self.effective_retreat_cost(active_id) as usize;
+            if may_retreat {
+                for &pokemon_id in &player.bench {
+                    actions.push(GameAction::Retreat {
+                        player_id,
+                        pokemon_id,
+                    });
+                }
+            }
+        }
+
+        actions.push(GameAction::EndTurn { player_id });
+
+        actions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::card::{Attack, Card, EnergyType};
+    use crate::core::game::state::GameRules;
+    use crate::core::player::Player;
+    use crate::core::rules::StandardRules;
+    use crate::test_fixtures::{basic_energy, pokemon, with_weakness};
+
+    fn pikachu() -> Card {
+        let mut card = with_weakness(pokemon("Pikachu", 60), EnergyType::Fighting);
+        card.add_attack(Attack::simple(
+            "Thunder Shock".to_string(),
+            vec![EnergyType::Lightning],
+            20,
+        ));
+        card.add_attack(Attack::simple(
+            "Thunderbolt".to_string(),
+            vec![EnergyType::Lightning, EnergyType::Lightning],
+            100,
+        ));
+        card
+    }
+
+    /// Alice has an active Pikachu with one Lightning attached, a benched
+    /// Pikachu and one Lightning energy in hand
+    fn simple_board() -> (Game, PlayerId, CardId, CardId, CardId) {
+        let mut game = Game::with_rules(GameRules::default());
+        let mut alice = Player::new("Alice".to_string());
+        let bob = Player::new("Bob".to_string());
+        let alice_id = alice.id;
+
+        let active = pikachu();
+        let benched = pikachu();
+        let attached = basic_energy(EnergyType::Lightning);
+        let in_hand = basic_energy(EnergyType::Lightning);
+        let (active_id, benched_id, in_hand_id) = (active.id, benched.id, in_hand.id);
+
+        alice.active_pokemon = Some(active_id);
+        alice.bench.push(benched_id);
+        alice.attached_energy.insert(active_id, vec![attached.id]);
+        alice.hand.push(in_hand_id);
+
+        for card in [active, benched, attached, in_hand] {
+            game.add_card_to_database(card);
+        }
+        game.turn_order = vec![alice_id, bob.id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.state = GameState::InProgress;
+        game.phase = GamePhase::Main;
+
+        (game, alice_id, active_id, benched_id, in_hand_id)
+    }
+
+    #[test]
+    fn test_legal_actions_on_simple_board() {
+        let (game, alice_id, active_id, benched_id, energy_id) = simple_board();
+        let engine = StandardRules::create_engine();
+
+        let actions = game.legal_actions(&engine);
+        let expected = vec![
+            GameAction::AttachEnergy {
+                player_id: alice_id,
+                energy_id,
+                pokemon_id: active_id,
+            },
+            GameAction::AttachEnergy {
+                player_id: alice_id,
+                energy_id,
+                pokemon_id: benched_id,
+            },
+            GameAction::UseAttack {
+                player_id: alice_id,
+                pokemon_id: active_id,
+                attack_index: 0,
+                target: None,
+            },
+            GameAction::Retreat {
+                player_id: alice_id,
+                pokemon_id: benched_id,
+            },
+            GameAction::EndTurn { player_id: alice_id },
+        ];
+
+        assert_eq!(actions, expected);
+    }
+
+    #[test]
+    fn test_legal_actions_respect_turn_limits() {
+        let (mut game, alice_id, _, _, _) = simple_board();
+        let engine = StandardRules::create_engine();
+        let alice = game.get_player_mut(alice_id).unwrap();
+        alice.energy_attached_this_turn = 1;
+        alice.has_retreated = true;
+        alice.has_attacked = true;
+
+        assert_eq!(
+            game.legal_actions(&engine),
+            vec![GameAction::EndTurn { player_id: alice_id }]
+        );
+    }
+
+    #[test]
+    fn test_attackable_pokemon_lists_affordable_attacks_of_active() {
+        let (mut game, alice_id, active_id, benched_id, _) = simple_board();
+
+        // The benched Pikachu could pay for both attacks but is not active
+        let energy: Vec<Card> = (0..2).map(|_| basic_energy(EnergyType::Lightning)).collect();
+        let energy_ids = energy.iter().map(|card| card.id).collect();
+        for card in energy {
+            game.add_card_to_database(card);
+        }
+        game.get_player_mut(alice_id)
+            .unwrap()
+            .attached_energy
+            .insert(benched_id, energy_ids);
+
+        assert_eq!(game.attackable_pokemon(alice_id), vec![(active_id, vec![0])]);
+
+        game.get_player_mut(alice_id).unwrap().has_attacked = true;
+        assert!(game.attackable_pokemon(alice_id).is_empty());
+    }
+}
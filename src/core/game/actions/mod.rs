@@ -4,7 +4,11 @@ pub mod execution;
 pub mod card_actions;
 pub mod energy_actions;
 pub mod attack_actions;
+pub mod retreat_actions;
+pub mod legal_actions;
+pub mod knockout_actions;
 
 // Re-export commonly used types
 pub use energy_actions::*;
-pub use attack_actions::*;
\ No newline at end of file
+pub use attack_actions::*;
+pub use retreat_actions::*;
\ No newline at end of file
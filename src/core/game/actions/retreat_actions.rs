@@ -0,0 +1,393 @@
+//! Retreat-related game actions
+
+use crate::core::card::{CardId, CardType};
+use crate::core::game::error::GameError;
+use crate::core::game::state::{Game, GameEvent};
+use crate::core::player::PlayerId;
+use serde::{Deserialize, Serialize};
+
+/// A modification to a Pokemon's retreat cost
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RetreatCostModifier {
+    /// Increase (positive) or decrease (negative) the retreat cost
+    Adjust(i32),
+    /// Set the retreat cost to a fixed value (e.g. 0 for "free retreat" tools)
+    Fixed(u32),
+}
+
+impl Game {
+    /// Register a retreat cost modifier provided by a card
+    ///
+    /// The modifier applies while the source card is in play: as the Pokemon
+    /// itself (abilities), as a tool attached to it, or as a stadium.
+    pub fn register_retreat_modifier(&mut self, source_card: CardId, modifier: RetreatCostModifier) {
+        self.retreat_modifiers.insert(source_card, modifier);
+    }
+
+    /// Remove a previously registered retreat cost modifier
+    pub fn remove_retreat_modifier(&mut self, source_card: CardId) {
+        self.retreat_modifiers.remove(&source_card);
+    }
+
+    /// Compute the retreat cost of a Pokemon after applying modifiers
+    ///
+    /// Starts from the card's printed retreat cost, applies every fixed cost
+    /// and then every adjustment, with a floor of zero.
+    pub fn effective_retreat_cost(&self, pokemon_id: CardId) -> u32 {
+        let base = match self.get_card(pokemon_id).map(|card| &card.card_type) {
+            Some(CardType::Pokemon { retreat_cost, .. }) => *retreat_cost,
+            _ => 0,
+        };
+
+        // Collect the cards whose modifiers apply to this Pokemon
+        let mut sources = vec![pokemon_id];
+        for player in self.players.values() {
+            if let Some(&tool_id) = player.attached_tools.get(&pokemon_id) {
+                sources.push(tool_id);
+            }
+            // Both players may record the same stadium; it only counts once
+            if let Some(stadium_id) = player.stadium
+                && !sources.contains(&stadium_id)
+            {
+                sources.push(stadium_id);
+            }
+        }
+
+        let modifiers: Vec<&RetreatCostModifier> = sources
+            .iter()
+            .filter_map(|id| self.retreat_modifiers.get(id))
+            .collect();
+
+        let mut cost = modifiers
+            .iter()
+            .filter_map(|modifier| match modifier {
+                RetreatCostModifier::Fixed(value) => Some(*value),
+                RetreatCostModifier::Adjust(_) => None,
+            })
+            .min()
+            .unwrap_or(base) as i64;
+
+        for modifier in &modifiers {
+            if let RetreatCostModifier::Adjust(delta) = modifier {
+                cost += *delta as i64;
+            }
+        }
+
+        cost.max(0) as u32
+    }
+
+    /// Retreat the active Pokemon, promoting a benched Pokemon in its place
+    ///
+    /// Pays the retreat cost with the energy attached first, then behaves
+    /// like `retreat_active_paying`.
+    pub fn retreat_active(&mut self, player_id: PlayerId, bench_pokemon_id: CardId) -> Result<(), GameError> {
+        let energy_to_discard: Vec<CardId> = match self.get_player(player_id) {
+            Some(player) => player
+                .active_pokemon
+                .and_then(|active_id| {
+                    let cost = self.effective_retreat_cost(active_id) as usize;
+                    player
+                        .attached_energy
+                        .get(&active_id)
+                        .map(|energy| energy.iter().take(cost).copied().collect())
+                })
+                .unwrap_or_default(),
+            None => Vec::new(),
+        };
+
+        self.retreat_active_paying(player_id, bench_pokemon_id, &energy_to_discard)
+    }
+
+    /// Retreat the active Pokemon, discarding the chosen energy as the cost
+    ///
+    /// Exactly as many energy cards as the effective retreat cost must be
+    /// chosen, all attached to the active Pokemon. The retreating Pokemon
+    /// takes the promoted Pokemon's bench slot and, if
+    /// `rules.cure_conditions_on_bench` is set, is cured of special conditions.
+    pub fn retreat_active_paying(
+        &mut self,
+        player_id: PlayerId,
+        bench_pokemon_id: CardId,
+        energy_to_discard: &[CardId],
+    ) -> Result<(), GameError> {
+        {
+            let player = self
+                .players
+                .get(&player_id)
+                .ok_or(GameError::PlayerNotFound)?;
+            let active_id = player
+                .active_pokemon
+                .ok_or_else(|| GameError::InvalidAction("No active Pokemon to retreat".to_string()))?;
+
+            if player.has_retreated {
+                return Err(GameError::InvalidAction("Already retreated this turn".to_string()));
+            }
+            if !player.bench.contains(&bench_pokemon_id) {
+                return Err(GameError::InvalidTarget("Target Pokemon is not on the bench".to_string()));
+            }
+            if !player.can_pokemon_retreat(active_id) {
+                return Err(GameError::InvalidAction("Active Pokemon cannot retreat".to_string()));
+            }
+
+            let cost = self.effective_retreat_cost(active_id) as usize;
+            if player.get_attached_energy_count(active_id) < cost {
+                return Err(GameError::InvalidAction("Not enough energy to pay retreat cost".to_string()));
+            }
+            if energy_to_discard.len() != cost {
+                return Err(GameError::InvalidAction(format!(
+                    "Retreat cost is {} energy, but {} were chosen",
+                    cost,
+                    energy_to_discard.len()
+                )));
+            }
+        }
+
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        let active_id = player
+            .active_pokemon
+            .ok_or_else(|| GameError::InvalidAction("No active Pokemon to retreat".to_string()))?;
+
+        player
+            .pay_energy_cost(active_id, energy_to_discard)
+            .map_err(GameError::InvalidAction)?;
+        if self.rules.cure_conditions_on_bench {
+            player.clear_special_conditions(active_id);
+        }
+
+        if let Some(pos) = player.bench.iter().position(|&id| id == bench_pokemon_id) {
+            player.bench[pos] = active_id;
+        }
+        player.active_pokemon = Some(bench_pokemon_id);
+        player.has_retreated = true;
+
+        self.add_event(GameEvent::PokemonRetreated {
+            player_id,
+            pokemon_id: active_id,
+            replacement_id: bench_pokemon_id,
+            discarded_energy: energy_to_discard.to_vec(),
+        });
+        Ok(())
+    }
+
+    /// Swap the active Pokemon with the benched Pokemon at `bench_index`
+    ///
+    /// For trainer effects such as Switch: no retreat cost is paid and the
+    /// player's retreat for the turn is not used up. Trapped blocks the switch
+    /// unless `ignore_trapped` is set. Both Pokemon keep their energy, tools
+    /// and damage; special conditions are cured as for a retreat.
+    pub fn switch_active(
+        &mut self,
+        player_id: PlayerId,
+        bench_index: usize,
+        ignore_trapped: bool,
+    ) -> Result<(), GameError> {
+        let cure_conditions = self.rules.cure_conditions_on_bench;
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+        let active_id = player
+            .active_pokemon
+            .ok_or_else(|| GameError::InvalidAction("No active Pokemon to switch".to_string()))?;
+        let bench_pokemon_id = *player
+            .bench
+            .get(bench_index)
+            .ok_or_else(|| GameError::InvalidAction("Bench index out of range".to_string()))?;
+        if !ignore_trapped && !player.can_pokemon_retreat(active_id) {
+            return Err(GameError::InvalidAction("Active Pokemon is trapped".to_string()));
+        }
+
+        if cure_conditions {
+            player.clear_special_conditions(active_id);
+        }
+        player.bench[bench_index] = active_id;
+        player.active_pokemon = Some(bench_pokemon_id);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::card::TrainerType;
+    use crate::core::player::Player;
+    use crate::test_fixtures::{pokemon, trainer, with_retreat_cost};
+
+    fn game_with_active(retreat_cost: u32) -> (Game, PlayerId, CardId) {
+        let mut game = Game::new();
+        let mut player = Player::new("Alice".to_string());
+        let player_id = player.id;
+        let pokemon = with_retreat_cost(pokemon("Snorlax", 120), retreat_cost);
+        let pokemon_id = pokemon.id;
+        player.active_pokemon = Some(pokemon_id);
+        game.add_card_to_database(pokemon);
+        game.add_player(player).unwrap();
+        (game, player_id, pokemon_id)
+    }
+
+    #[test]
+    fn test_free_retreat_tool() {
+        let (mut game, player_id, pokemon_id) = game_with_active(3);
+        let tool = trainer("Tool", TrainerType::Tool);
+        let tool_id = tool.id;
+        game.add_card_to_database(tool);
+        game.register_retreat_modifier(tool_id, RetreatCostModifier::Fixed(0));
+
+        assert_eq!(game.effective_retreat_cost(pokemon_id), 3);
+
+        let player = game.get_player_mut(player_id).unwrap();
+        player.hand.push(tool_id);
+        assert!(player.attach_tool(tool_id, pokemon_id));
+
+        assert_eq!(game.effective_retreat_cost(pokemon_id), 0);
+    }
+
+    #[test]
+    fn test_stadium_reduces_retreat_cost() {
+        let (mut game, player_id, pokemon_id) = game_with_active(2);
+        let stadium = trainer("Stadium", TrainerType::Stadium);
+        let stadium_id = stadium.id;
+        game.add_card_to_database(stadium);
+        game.register_retreat_modifier(stadium_id, RetreatCostModifier::Adjust(-1));
+        game.get_player_mut(player_id).unwrap().stadium = Some(stadium_id);
+
+        assert_eq!(game.effective_retreat_cost(pokemon_id), 1);
+    }
+
+    #[test]
+    fn test_shared_stadium_applies_once() {
+        let (mut game, player_id, pokemon_id) = game_with_active(3);
+        let opponent = Player::new("Bob".to_string());
+        let opponent_id = opponent.id;
+        game.add_player(opponent).unwrap();
+
+        let stadium = trainer("Stadium", TrainerType::Stadium);
+        let stadium_id = stadium.id;
+        game.add_card_to_database(stadium);
+        game.register_retreat_modifier(stadium_id, RetreatCostModifier::Adjust(-1));
+        for id in [player_id, opponent_id] {
+            game.get_player_mut(id).unwrap().stadium = Some(stadium_id);
+        }
+
+        assert_eq!(game.effective_retreat_cost(pokemon_id), 2);
+    }
+
+    #[test]
+    fn test_retreat_discards_effective_cost() {
+        let (mut game, player_id, pokemon_id) = game_with_active(2);
+        let benched = with_retreat_cost(pokemon("Snorlax", 120), 1);
+        let benched_id = benched.id;
+        game.add_card_to_database(benched);
+
+        let energy: Vec<CardId> = (0..3).map(|_| CardId::new_v4()).collect();
+        let player = game.get_player_mut(player_id).unwrap();
+        player.bench.push(benched_id);
+        player.attached_energy.insert(pokemon_id, energy.clone());
+
+        game.retreat_active(player_id, benched_id).unwrap();
+
+        let player = game.get_player(player_id).unwrap();
+        assert_eq!(player.active_pokemon, Some(benched_id));
+        assert_eq!(player.bench, vec![pokemon_id]);
+        assert_eq!(player.get_attached_energy_count(pokemon_id), 1);
+        assert_eq!(player.discard_pile, vec![energy[0], energy[1]]);
+        assert!(game.retreat_active(player_id, pokemon_id).is_err());
+    }
+
+    #[test]
+    fn test_retreat_pays_chosen_energy() {
+        let (mut game, player_id, pokemon_id) = game_with_active(2);
+        let benched = with_retreat_cost(pokemon("Snorlax", 120), 1);
+        let benched_id = benched.id;
+        game.add_card_to_database(benched);
+
+        let energy: Vec<CardId> = (0..3).map(|_| CardId::new_v4()).collect();
+        let unattached = CardId::new_v4();
+        let player = game.get_player_mut(player_id).unwrap();
+        player.bench.push(benched_id);
+        player.attached_energy.insert(pokemon_id, energy.clone());
+
+        let err = game
+            .retreat_active_paying(player_id, benched_id, &[energy[2], unattached])
+            .unwrap_err();
+        assert_eq!(err.to_string(), format!("Energy {} is not attached to this Pokemon", unattached));
+        assert!(game.retreat_active_paying(player_id, benched_id, &[energy[2]]).is_err());
+        let player = game.get_player(player_id).unwrap();
+        assert_eq!(player.attached_energy[&pokemon_id], energy);
+        assert!(player.discard_pile.is_empty());
+
+        game.retreat_active_paying(player_id, benched_id, &[energy[2], energy[0]]).unwrap();
+        let player = game.get_player(player_id).unwrap();
+        assert_eq!(player.attached_energy[&pokemon_id], vec![energy[1]]);
+        assert_eq!(player.discard_pile, vec![energy[2], energy[0]]);
+        assert_eq!(
+            game.get_history().last(),
+            Some(&GameEvent::PokemonRetreated {
+                player_id,
+                pokemon_id,
+                replacement_id: benched_id,
+                discarded_energy: vec![energy[2], energy[0]],
+            })
+        );
+    }
+
+    #[test]
+    fn test_retreat_cures_conditions_only_if_rules_say_so() {
+        use crate::core::game::state::GameRules;
+        use crate::core::player::SpecialCondition;
+
+        for cure in [true, false] {
+            let (mut game, player_id, pokemon_id) = game_with_active(0);
+            game.rules = GameRules::builder().cure_conditions_on_bench(cure).build().unwrap();
+            let benched = with_retreat_cost(pokemon("Snorlax", 120), 1);
+            let benched_id = benched.id;
+            game.add_card_to_database(benched);
+            let player = game.get_player_mut(player_id).unwrap();
+            player.bench.push(benched_id);
+            player.add_special_condition(pokemon_id, SpecialCondition::Confused, -1, 1);
+
+            game.retreat_active(player_id, benched_id).unwrap();
+            let player = game.get_player(player_id).unwrap();
+            assert_eq!(player.bench, vec![pokemon_id]);
+            assert_eq!(
+                player.has_special_condition_type(pokemon_id, &SpecialCondition::Confused),
+                !cure
+            );
+        }
+    }
+
+    #[test]
+    fn test_switch_ignores_trapped_and_keeps_attachments() {
+        use crate::core::player::SpecialCondition;
+
+        let (mut game, player_id, pokemon_id) = game_with_active(3);
+        let (benched_id, tool_id) = (CardId::new_v4(), CardId::new_v4());
+        let energy: Vec<CardId> = (0..2).map(|_| CardId::new_v4()).collect();
+        let player = game.get_player_mut(player_id).unwrap();
+        player.bench = vec![CardId::new_v4(), benched_id];
+        player.attached_energy.insert(pokemon_id, energy.clone());
+        player.attached_tools.insert(benched_id, tool_id);
+        player.add_damage(pokemon_id, 30);
+        player.add_special_condition(pokemon_id, SpecialCondition::Trapped, -1, 1);
+
+        assert_eq!(
+            game.switch_active(player_id, 1, false),
+            Err(GameError::InvalidAction("Active Pokemon is trapped".to_string()))
+        );
+        assert!(game.switch_active(player_id, 2, true).is_err());
+        game.switch_active(player_id, 1, true).unwrap();
+
+        let player = game.get_player(player_id).unwrap();
+        assert_eq!(player.active_pokemon, Some(benched_id));
+        assert_eq!(player.bench[1], pokemon_id);
+        assert_eq!(player.attached_energy[&pokemon_id], energy);
+        assert_eq!(player.attached_tools[&benched_id], tool_id);
+        assert_eq!(player.damage_counters[&pokemon_id], 30);
+        assert!(player.discard_pile.is_empty());
+        assert!(!player.has_retreated);
+    }
+}
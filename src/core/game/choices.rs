@@ -0,0 +1,173 @@
+//! Pending player decisions
+//!
+//! Effects that need a player to pick cards (discard two cards, choose a
+//! Pokemon to damage, ...) push a `PendingChoice` onto the game instead of
+//! picking automatically. Resolution continues once the player answers via
+//! `Game::resolve_choice`.
+
+use crate::core::card::CardId;
+use crate::core::game::error::GameError;
+use crate::core::game::state::Game;
+use crate::core::player::PlayerId;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// What happens to the selected cards once a choice is resolved
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ChoiceResolution {
+    /// Discard the selected cards from the player's hand
+    DiscardFromHand,
+    /// Return the selection to the caller, which finishes the effect itself
+    Custom { effect: String },
+}
+
+/// A decision the engine is waiting on
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PendingChoice {
+    /// Player who must make the choice
+    pub player: PlayerId,
+    /// Text shown to the player
+    pub prompt: String,
+    /// Cards the player may select from
+    pub options: Vec<CardId>,
+    /// Minimum number of cards to select
+    pub min: usize,
+    /// Maximum number of cards to select
+    pub max: usize,
+    /// Identifier used to answer this choice
+    pub choice_id: Uuid,
+    /// How the selection is applied once validated
+    pub resolution: ChoiceResolution,
+}
+
+impl Game {
+    /// Queue a choice for a player and return its identifier
+    pub fn push_choice(
+        &mut self,
+        player: PlayerId,
+        prompt: String,
+        options: Vec<CardId>,
+        min: usize,
+        max: usize,
+        resolution: ChoiceResolution,
+    ) -> Result<Uuid, GameError> {
+        if !self.players.contains_key(&player) {
+            return Err(GameError::PlayerNotFound);
+        }
+        if min > max || min > options.len() {
+            return Err(GameError::InvalidAction("Choice bounds cannot be satisfied".to_string()));
+        }
+
+        let choice_id = Uuid::new_v4();
+        self.pending_choices.push(PendingChoice {
+            player,
+            prompt,
+            options,
+            min,
+            max,
+            choice_id,
+            resolution,
+        });
+        Ok(choice_id)
+    }
+
+    /// The next choice waiting for an answer, if any
+    pub fn next_choice(&self) -> Option<&PendingChoice> {
+        self.pending_choices.first()
+    }
+
+    /// Answer a pending choice
+    ///
+    /// The selection must contain between `min` and `max` distinct cards, all
+    /// taken from the choice's options. On success the choice is removed from
+    /// the queue, its resolution is applied, and the selection is returned.
+    /// An invalid selection leaves the choice pending.
+    pub fn resolve_choice(
+        &mut self,
+        choice_id: Uuid,
+        selected: Vec<CardId>,
+    ) -> Result<Vec<CardId>, GameError> {
+        let index = self
+            .pending_choices
+            .iter()
+            .position(|choice| choice.choice_id == choice_id)
+            .ok_or_else(|| GameError::InvalidAction("Choice not found".to_string()))?;
+        let choice = &self.pending_choices[index];
+
+        if selected.len() < choice.min || selected.len() > choice.max {
+            return Err(GameError::InvalidAction(format!(
+                "Expected between {} and {} cards, got {}",
+                choice.min,
+                choice.max,
+                selected.len()
+            )));
+        }
+        for (i, card_id) in selected.iter().enumerate() {
+            if !choice.options.contains(card_id) {
+                return Err(GameError::InvalidTarget("Selected card is not one of the options".to_string()));
+            }
+            if selected[..i].contains(card_id) {
+                return Err(GameError::InvalidTarget("A card was selected more than once".to_string()));
+            }
+        }
+
+        let choice = self.pending_choices.remove(index);
+        match choice.resolution {
+            ChoiceResolution::DiscardFromHand => {
+                let player = self
+                    .players
+                    .get_mut(&choice.player)
+                    .ok_or(GameError::PlayerNotFound)?;
+                for &card_id in &selected {
+                    player.discard_from_hand(card_id);
+                }
+            }
+            ChoiceResolution::Custom { .. } => {}
+        }
+
+        Ok(selected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::player::Player;
+
+    #[test]
+    fn test_discard_two_choice() {
+        let mut game = Game::new();
+        let mut alice = Player::new("Alice".to_string());
+        let alice_id = alice.id;
+        let hand: Vec<CardId> = (0..3).map(|_| Uuid::new_v4()).collect();
+        alice.hand = hand.clone();
+        alice.discard_pile.push(Uuid::new_v4());
+        let in_discard = alice.discard_pile[0];
+        game.add_player(alice).unwrap();
+
+        let choice_id = game
+            .push_choice(
+                alice_id,
+                "Discard 2 cards".to_string(),
+                hand.clone(),
+                2,
+                2,
+                ChoiceResolution::DiscardFromHand,
+            )
+            .unwrap();
+
+        assert!(game.resolve_choice(choice_id, vec![hand[0]]).is_err());
+        assert!(game.resolve_choice(choice_id, vec![hand[0], in_discard]).is_err());
+        assert!(game.resolve_choice(choice_id, vec![hand[0], hand[0]]).is_err());
+        assert_eq!(game.next_choice().map(|c| c.choice_id), Some(choice_id));
+
+        assert_eq!(
+            game.resolve_choice(choice_id, vec![hand[0], hand[2]]).unwrap(),
+            vec![hand[0], hand[2]]
+        );
+        assert!(game.pending_choices.is_empty());
+        let alice = game.get_player(alice_id).unwrap();
+        assert_eq!(alice.hand, vec![hand[1]]);
+        assert_eq!(alice.discard_pile, vec![in_discard, hand[0], hand[2]]);
+    }
+}
@@ -0,0 +1,33 @@
+//! Typed errors for game setup and actions
+
+use crate::core::game::state::GameState;
+use thiserror::Error;
+
+/// Why a setup step or game action was rejected
+///
+/// The `Display` text is the human-readable message, so callers that only
+/// show errors can keep using `to_string()`.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum GameError {
+    #[error("Player not found")]
+    PlayerNotFound,
+
+    #[error("Not allowed while the game is {0:?}")]
+    WrongPhase(GameState),
+
+    #[error("Not your turn")]
+    NotYourTurn,
+
+    #[error("Card not found")]
+    CardNotFound,
+
+    #[error("Invalid target: {0}")]
+    InvalidTarget(String),
+
+    #[error("Bench is full")]
+    BenchFull,
+
+    /// Any other rule the action breaks, described by its message
+    #[error("{0}")]
+    InvalidAction(String),
+}
@@ -1 +1,317 @@
-//! Game events and history tracking
\ No newline at end of file
+//! Game events and history tracking
+
+use crate::core::game::error::GameError;
+use crate::core::game::state::{Game, GameEvent, GamePhase, GameState};
+use crate::core::player::PlayerId;
+use serde::{Deserialize, Serialize};
+
+/// The events of one player's turn, for UI timelines
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TurnLogEntry {
+    pub turn_number: u32,
+    pub player_id: PlayerId,
+    /// Events from `TurnStarted` up to (not including) the next `TurnStarted`
+    pub events: Vec<GameEvent>,
+}
+
+impl Game {
+    /// Group the history into turns
+    ///
+    /// Each `TurnStarted` event opens a new entry. Events recorded before the
+    /// first turn (setup, `GameStarted`) belong to no turn and are left out.
+    pub fn turn_log(&self) -> Vec<TurnLogEntry> {
+        let mut log: Vec<TurnLogEntry> = Vec::new();
+
+        for event in &self.history {
+            if let GameEvent::TurnStarted {
+                player_id,
+                turn_number,
+            } = *event
+            {
+                log.push(TurnLogEntry {
+                    turn_number,
+                    player_id,
+                    events: Vec::new(),
+                });
+            }
+            if let Some(entry) = log.last_mut() {
+                entry.events.push(event.clone());
+            }
+        }
+
+        log
+    }
+
+    /// Events recorded after the first `index` entries of the history
+    ///
+    /// A server remembers how much history each client has seen and sends
+    /// only the delta. An index past the end yields an empty slice.
+    pub fn events_since(&self, index: usize) -> &[GameEvent] {
+        self.history.get(index..).unwrap_or(&[])
+    }
+
+    /// Fold a delta of events from `events_since` into a client copy
+    ///
+    /// Each event's state change is replayed and the event is appended to the
+    /// history; triggers are not dispatched, since their effects arrive as
+    /// events of their own. Events only describe part of the state (coin
+    /// flips, promotions and discard reshuffles leave no exact record), so
+    /// clients should compare `state_hash` with the server afterwards and
+    /// request a full sync on a mismatch.
+    pub fn apply_events(&mut self, events: &[GameEvent]) -> Result<(), GameError> {
+        for event in events {
+            self.apply_event(event)?;
+            self.history.push(event.clone());
+        }
+        Ok(())
+    }
+
+    fn apply_event(&mut self, event: &GameEvent) -> Result<(), GameError> {
+        match event {
+            GameEvent::GameStarted => {
+                self.state = GameState::InProgress;
+            }
+            GameEvent::TurnStarted {
+                player_id,
+                turn_number,
+            } => {
+                self.current_player_index = self
+                    .turn_order
+                    .iter()
+                    .position(|id| id == player_id)
+                    .ok_or_else(|| GameError::InvalidAction("Player not in turn order".to_string()))?;
+                self.turn_number = *turn_number;
+                self.phase = GamePhase::BeginningOfTurn;
+                self.turn_started_at = None;
+                self.player_mut(*player_id)?.start_turn();
+            }
+            GameEvent::CardDrawn { player_id, card_id } => {
+                let player = self.player_mut(*player_id)?;
+                match card_id {
+                    Some(card_id) => {
+                        if !player.move_from_deck_to_hand(*card_id) {
+                            let message = format!("Card {} is not in the deck", card_id);
+                            return Err(GameError::InvalidAction(message));
+                        }
+                    }
+                    None => {
+                        player.draw_card();
+                    }
+                }
+            }
+            GameEvent::PokemonBenched { player_id, card_id } => {
+                let player = self.player_mut(*player_id)?;
+                let pos = player
+                    .hand
+                    .iter()
+                    .position(|id| id == card_id)
+                    .ok_or_else(|| GameError::InvalidAction(format!("Card {} is not in hand", card_id)))?;
+                player.hand.remove(pos);
+                player.bench.push(*card_id);
+            }
+            GameEvent::EnergyAttached {
+                player_id,
+                energy_id,
+                pokemon_id,
+            } => {
+                let player = self.player_mut(*player_id)?;
+                if !player.attach_energy(*energy_id, *pokemon_id) {
+                    return Err(GameError::InvalidAction(format!("Cannot attach energy {}", energy_id)));
+                }
+                player.energy_attached_this_turn += 1;
+            }
+            GameEvent::PokemonEvolved {
+                player_id,
+                pokemon_id,
+                evolution_id,
+            } => {
+                if !self.player_mut(*player_id)?.evolve_pokemon(*pokemon_id, *evolution_id) {
+                    return Err(GameError::InvalidAction(format!("Cannot evolve {}", pokemon_id)));
+                }
+            }
+            GameEvent::PokemonRetreated {
+                player_id,
+                pokemon_id,
+                replacement_id,
+                discarded_energy,
+            } => {
+                let cure = self.rules.cure_conditions_on_bench;
+                let player = self.player_mut(*player_id)?;
+                player
+                    .pay_energy_cost(*pokemon_id, discarded_energy)
+                    .map_err(GameError::InvalidAction)?;
+                if cure {
+                    player.clear_special_conditions(*pokemon_id);
+                }
+                let pos = player
+                    .bench
+                    .iter()
+                    .position(|id| id == replacement_id)
+                    .ok_or_else(|| {
+                        GameError::InvalidAction(format!("Card {} is not on the bench", replacement_id))
+                    })?;
+                player.bench[pos] = *pokemon_id;
+                player.active_pokemon = Some(*replacement_id);
+                player.has_retreated = true;
+            }
+            GameEvent::StadiumPlayed { player_id, card_id } => {
+                for player in self.players.values_mut() {
+                    if let Some(old) = player.stadium.take() {
+                        player.discard_pile.push(old);
+                    }
+                }
+                let player = self.player_mut(*player_id)?;
+                let pos = player
+                    .hand
+                    .iter()
+                    .position(|id| id == card_id)
+                    .ok_or_else(|| GameError::InvalidAction(format!("Card {} is not in hand", card_id)))?;
+                player.hand.remove(pos);
+                player.stadium = Some(*card_id);
+            }
+            GameEvent::ToolAttached {
+                player_id,
+                tool_id,
+                pokemon_id,
+            } => {
+                if !self.player_mut(*player_id)?.attach_tool(*tool_id, *pokemon_id) {
+                    return Err(GameError::InvalidAction(format!("Cannot attach tool {}", tool_id)));
+                }
+            }
+            GameEvent::SpecialConditionApplied {
+                player_id,
+                pokemon_id,
+                condition,
+            } => {
+                let turn_number = self.turn_number;
+                self.player_mut(*player_id)?
+                    .add_special_condition(*pokemon_id, condition.clone(), -1, turn_number);
+            }
+            GameEvent::AttackUsed { player_id, .. } => {
+                self.player_mut(*player_id)?.has_attacked = true;
+            }
+            GameEvent::DamageDealt {
+                player_id,
+                pokemon_id,
+                damage,
+            } => {
+                self.player_mut(*player_id)?.add_damage(*pokemon_id, *damage);
+            }
+            GameEvent::PokemonKnockedOut {
+                player_id,
+                pokemon_id,
+            } => {
+                let player = self.player_mut(*player_id)?;
+                let was_active = player.active_pokemon == Some(*pokemon_id);
+                player.discard_from_field(*pokemon_id);
+                let needs_promotion = was_active && !player.bench.is_empty();
+                if needs_promotion && !self.pending_promotions.contains(player_id) {
+                    self.pending_promotions.push(*player_id);
+                }
+            }
+            GameEvent::PrizeTaken { player_id } => {
+                self.player_mut(*player_id)?.take_prize_card();
+            }
+            GameEvent::DeckShuffled { player_id } => {
+                let player = self
+                    .players
+                    .get_mut(player_id)
+                    .ok_or(GameError::PlayerNotFound)?;
+                player.shuffle_deck_with(&mut self.rng);
+            }
+            GameEvent::GameEnded { winner, .. } => {
+                self.state = GameState::Finished { winner: *winner };
+            }
+            GameEvent::CardPlayed { .. } | GameEvent::TurnEnded { .. } => {}
+        }
+        Ok(())
+    }
+
+    fn player_mut(&mut self, player_id: PlayerId) -> Result<&mut crate::core::player::Player, GameError> {
+        self.players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::player::Player;
+    use crate::core::rules::{GameAction, StandardRules};
+    use uuid::Uuid;
+
+    #[test]
+    fn test_turn_log_groups_events_by_turn() {
+        let mut game = Game::new();
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+        for player in [&mut alice, &mut bob] {
+            player.active_pokemon = Some(Uuid::new_v4());
+            player.set_deck((0..5).map(|_| Uuid::new_v4()).collect());
+        }
+        game.turn_order = vec![alice_id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+
+        game.start().unwrap();
+        game.add_event(GameEvent::DeckShuffled { player_id: alice_id });
+        game.end_turn().unwrap();
+        game.end_turn().unwrap();
+
+        let log = game.turn_log();
+        let turns: Vec<(u32, PlayerId, usize)> = log
+            .iter()
+            .map(|entry| (entry.turn_number, entry.player_id, entry.events.len()))
+            .collect();
+        assert_eq!(turns, vec![(1, alice_id, 4), (2, bob_id, 3), (3, alice_id, 2)]);
+        assert_eq!(log[0].events[2], GameEvent::DeckShuffled { player_id: alice_id });
+        assert_eq!(log[0].events[3], GameEvent::TurnEnded { player_id: alice_id });
+        assert!(!log.iter().any(|entry| entry.events.contains(&GameEvent::GameStarted)));
+    }
+
+    #[test]
+    fn test_event_delta_brings_client_in_sync() {
+        let mut game = Game::new();
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+        for player in [&mut alice, &mut bob] {
+            player.active_pokemon = Some(Uuid::new_v4());
+            player.set_deck((0..5).map(|_| Uuid::new_v4()).collect());
+        }
+        let energy_id = alice.deck[4];
+        let alice_active = alice.active_pokemon.unwrap();
+        game.turn_order = vec![alice_id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.start().unwrap();
+
+        let baseline = game.history.len();
+        let mut client = game.clone();
+
+        let engine = StandardRules::create_engine();
+        game.execute_action(
+            &engine,
+            &GameAction::AttachEnergy {
+                player_id: alice_id,
+                energy_id,
+                pokemon_id: alice_active,
+            },
+        )
+        .unwrap();
+        game.execute_action(&engine, &GameAction::EndTurn { player_id: alice_id })
+            .unwrap();
+
+        let delta = game.events_since(baseline);
+        assert_eq!(delta.len(), 3);
+        assert!(game.events_since(game.history.len() + 1).is_empty());
+
+        assert_ne!(client.state_hash(), game.state_hash());
+        client.apply_events(delta).unwrap();
+        assert_eq!(client.state_hash(), game.state_hash());
+        assert_eq!(client.history, game.history);
+        assert_eq!(client.get_current_player_id(), Ok(bob_id));
+    }
+}
@@ -0,0 +1,140 @@
+//! Consistency checks for the board state
+//!
+//! Action handlers move cards between zones piecemeal, so a bug in one of
+//! them can leave a board no real game could reach. `execute_action` runs
+//! these checks after every action in debug builds.
+
+use crate::core::card::CardId;
+use crate::core::game::state::{Game, GameState};
+use crate::core::player::PlayerId;
+use std::collections::HashSet;
+
+impl Game {
+    /// Check that the board is in a consistent state
+    ///
+    /// While the game is in progress every player must have an active Pokemon
+    /// or be owed a promotion. No card may be in two places at once, and
+    /// attached energy, tools and evolution stacks must belong to a Pokemon in
+    /// play. Returns every violation found.
+    pub fn validate_board_invariants(&self) -> Result<(), Vec<String>> {
+        let mut violations = Vec::new();
+        let mut player_ids: Vec<PlayerId> = self.players.keys().copied().collect();
+        player_ids.sort();
+
+        let mut seen: HashSet<CardId> = HashSet::new();
+        for player_id in player_ids {
+            let player = &self.players[&player_id];
+
+            if self.state == GameState::InProgress
+                && player.active_pokemon.is_none()
+                && !self.pending_promotions.contains(&player_id)
+            {
+                violations.push(format!(
+                    "Player {} has no active Pokemon and no pending promotion",
+                    player.name
+                ));
+            }
+
+            for card_id in player.owned_card_ids() {
+                if !seen.insert(card_id) {
+                    violations.push(format!("Card {} is in more than one place", card_id));
+                }
+            }
+
+            let in_play = player.pokemon_in_play();
+            let attachments = player
+                .attached_energy
+                .keys()
+                .map(|id| (id, "Energy"))
+                .chain(player.attached_tools.keys().map(|id| (id, "Tool")))
+                .chain(player.evolution_stacks.keys().map(|id| (id, "Evolution stack")));
+            for (pokemon_id, kind) in attachments {
+                if !in_play.contains(pokemon_id) {
+                    violations.push(format!(
+                        "{} attached to {}, which is not in play",
+                        kind, pokemon_id
+                    ));
+                }
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::player::Player;
+    use uuid::Uuid;
+
+    fn game_in_progress() -> (Game, PlayerId) {
+        let mut game = Game::new();
+        let mut alice = Player::new("Alice".to_string());
+        let alice_id = alice.id;
+        alice.active_pokemon = Some(Uuid::new_v4());
+        game.turn_order = vec![alice_id];
+        game.add_player(alice).unwrap();
+        game.state = GameState::InProgress;
+        (game, alice_id)
+    }
+
+    #[test]
+    fn test_consistent_board_passes() {
+        let (mut game, alice_id) = game_in_progress();
+        let alice = game.get_player_mut(alice_id).unwrap();
+        let active = alice.active_pokemon.unwrap();
+        alice.attached_energy.insert(active, vec![Uuid::new_v4()]);
+        alice.hand.push(Uuid::new_v4());
+
+        assert_eq!(game.validate_board_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_missing_active_reported() {
+        let (mut game, alice_id) = game_in_progress();
+        let alice = game.get_player_mut(alice_id).unwrap();
+        let active = alice.active_pokemon.take().unwrap();
+        alice.bench.push(active);
+
+        assert_eq!(
+            game.validate_board_invariants(),
+            Err(vec!["Player Alice has no active Pokemon and no pending promotion".to_string()])
+        );
+
+        game.pending_promotions.push(alice_id);
+        assert_eq!(game.validate_board_invariants(), Ok(()));
+    }
+
+    #[test]
+    fn test_card_in_two_zones_reported() {
+        let (mut game, alice_id) = game_in_progress();
+        let alice = game.get_player_mut(alice_id).unwrap();
+        let active = alice.active_pokemon.unwrap();
+        alice.bench.push(active);
+
+        assert_eq!(
+            game.validate_board_invariants(),
+            Err(vec![format!("Card {} is in more than one place", active)])
+        );
+    }
+
+    #[test]
+    fn test_energy_on_missing_pokemon_reported() {
+        let (mut game, alice_id) = game_in_progress();
+        let missing = Uuid::new_v4();
+        game.get_player_mut(alice_id)
+            .unwrap()
+            .attached_energy
+            .insert(missing, vec![Uuid::new_v4()]);
+
+        assert_eq!(
+            game.validate_board_invariants(),
+            Err(vec![format!("Energy attached to {}, which is not in play", missing)])
+        );
+    }
+}
@@ -0,0 +1,90 @@
+//! Deterministic random number generation for games
+//!
+//! Every random decision in a game (coin flips, shuffles, random targets)
+//! should draw from the game's `GameRng` so a game can be reproduced from
+//! its seed and the generator state survives serialization.
+
+use rand::{Error, RngCore, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// Seedable, serializable random number generator owned by a game
+///
+/// Uses the SplitMix64 algorithm: small state, fast, and good enough for
+/// game simulation (not for cryptography).
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct GameRng {
+    state: u64,
+}
+
+impl GameRng {
+    /// Create a generator seeded from system entropy
+    pub fn from_entropy() -> Self {
+        Self::seed_from_u64(rand::random())
+    }
+
+    /// Flip a coin, returning `true` for heads
+    pub fn flip_coin(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+}
+
+impl RngCore for GameRng {
+    fn next_u32(&mut self) -> u32 {
+        (self.next_u64() >> 32) as u32
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        for chunk in dest.chunks_mut(8) {
+            let bytes = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&bytes[..chunk.len()]);
+        }
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.fill_bytes(dest);
+        Ok(())
+    }
+}
+
+impl SeedableRng for GameRng {
+    type Seed = [u8; 8];
+
+    fn from_seed(seed: Self::Seed) -> Self {
+        Self {
+            state: u64::from_le_bytes(seed),
+        }
+    }
+
+    fn seed_from_u64(state: u64) -> Self {
+        Self { state }
+    }
+}
+
+impl Default for GameRng {
+    fn default() -> Self {
+        Self::from_entropy()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut a = GameRng::seed_from_u64(42);
+        let mut b = GameRng::seed_from_u64(42);
+
+        for _ in 0..16 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+    }
+}
@@ -1,57 +1,74 @@
 //! Deck setup functionality
 
 use crate::core::{
-    game::state::{Game, GameState},
+    game::error::GameError,
+    game::state::{Game, GameState, SetupPhase},
     player::PlayerId,
 };
 
 impl Game {
     /// Start the game setup process
-    pub fn start_setup(&mut self) -> Result<(), String> {
+    pub fn start_setup(&mut self) -> Result<(), GameError> {
         if self.state != GameState::Setup {
-            return Err("Game is not in setup state".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
+        }
+
+        if self.setup_phase.is_some() {
+            return Err(GameError::InvalidAction("Setup has already started".to_string()));
         }
 
         if self.players.len() < 2 {
-            return Err("Need at least 2 players to start setup".to_string());
+            return Err(GameError::InvalidAction("Need at least 2 players to start setup".to_string()));
         }
 
         // Validate all players have decks
         for player in self.players.values() {
             if player.deck.is_empty() {
-                return Err("All players must have decks".to_string());
+                return Err(GameError::InvalidAction("All players must have decks".to_string()));
             }
         }
 
+        self.setup_phase = Some(SetupPhase::DeterminingTurnOrder);
+
         Ok(())
     }
 
     /// 阶段2: 抽取初始手牌
-    pub fn deal_opening_hands(&mut self) -> Result<(), String> {
+    pub fn deal_opening_hands(&mut self) -> Result<(), GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only deal opening hands during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
+        self.require_setup_phase(&[SetupPhase::DealingOpeningHands], "deal opening hands")?;
 
         // 检查是否已经确定了先后手顺序
         if self.turn_order.is_empty() {
-            return Err("Turn order must be determined before dealing hands".to_string());
+            return Err(GameError::InvalidAction(
+                "Turn order must be determined before dealing hands".to_string(),
+            ));
         }
 
-        // 执行发牌逻辑
+        // 执行发牌逻辑，手牌数量由规则决定
+        let hand_size = self.rules.opening_hand_size();
         for player in self.players.values_mut() {
-            player.draw_cards(7);
+            player.draw_cards(hand_size);
         }
 
+        self.advance_setup_phase(SetupPhase::Mulligans);
+
         Ok(())
     }
 
     /// 阶段3: 检查玩家是否拥有基础宝可梦
-    pub fn check_for_basic_pokemon(&self) -> Result<Vec<PlayerId>, String> {
+    pub fn check_for_basic_pokemon(&self) -> Result<Vec<PlayerId>, GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only check for basic Pokemon during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
+        self.require_setup_phase(
+            &[SetupPhase::Mulligans, SetupPhase::SelectingActivePokemon],
+            "check for basic Pokemon",
+        )?;
 
         let mut players_without_basic = Vec::new();
 
@@ -1,7 +1,8 @@
 //! Mulligan setup functionality
 
 use crate::core::{
-    game::state::{Game, GameState},
+    game::error::GameError,
+    game::state::{Game, GameEvent, GameState, SetupPhase},
     player::PlayerId,
 };
 use crate::core::card::CardId;
@@ -17,13 +18,26 @@ pub enum MulliganResult {
     OneWithoutBasic(PlayerId),
 }
 
+/// 玩家在设置阶段的进度，供前端驱动设置流程
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetupStatus {
+    /// 是否已选择活跃宝可梦
+    pub has_active: bool,
+    /// 备战区宝可梦数量
+    pub bench_count: usize,
+    /// 备战区是否已满
+    pub bench_full: bool,
+    /// 是否需要重抽（没有活跃宝可梦且手牌中没有基础宝可梦，或正在等待重抽）
+    pub needs_mulligan: bool,
+}
+
 impl Game {
     /// 阶段5a: 玩家宣告没有基础宝可梦
     /// 返回值：(需要重抽的玩家列表, 是否双方都没有基础宝可梦)
-    pub fn declare_no_basic_pokemon(&mut self) -> Result<(Vec<PlayerId>, bool), String> {
+    pub fn declare_no_basic_pokemon(&mut self) -> Result<(Vec<PlayerId>, bool), GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only declare no basic Pokemon during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
 
         let players_without_basic = self.check_for_basic_pokemon()?;
@@ -37,57 +51,45 @@ impl Game {
 
     /// 阶段5b: 记录需要等待重抽的玩家
     /// 当只有一方没有基础宝可梦时调用此方法
-    pub fn mark_player_for_mulligan(&mut self, player_id: PlayerId) -> Result<(), String> {
+    pub fn mark_player_for_mulligan(&mut self, player_id: PlayerId) -> Result<(), GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only mark player for mulligan during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
+        self.require_setup_phase(
+            &[SetupPhase::Mulligans, SetupPhase::SelectingActivePokemon],
+            "mark a player for mulligan",
+        )?;
 
         // 检查玩家是否存在
         if !self.players.contains_key(&player_id) {
-            return Err("Player not found".to_string());
+            return Err(GameError::PlayerNotFound);
         }
 
         // 记录需要等待重抽的玩家
-        self.player_waiting_for_mulligan = Some(player_id);
+        if !self.players_waiting_for_mulligan.contains(&player_id) {
+            self.players_waiting_for_mulligan.push(player_id);
+        }
 
         Ok(())
     }
 
     /// 在对手完成设置后调用此方法
-    pub fn perform_pending_mulligans(&mut self) -> Result<(), String> {
+    pub fn perform_pending_mulligans(&mut self) -> Result<(), GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only perform mulligans during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
 
-        // 记录执行重抽的次数，用于奖赏卡补偿
-        let mulligan_count = if self.player_waiting_for_mulligan.is_some() {
-            1
-        } else {
-            0
-        };
-
-        // 为等待重抽的玩家执行重抽
-        if let Some(player_id) = self.player_waiting_for_mulligan {
-            // 将手牌放回牌库底部
-            if let Some(player) = self.players.get_mut(&player_id) {
-                for card_id in player.hand.drain(..) {
-                    player.deck.push(card_id);
-                }
-                player.shuffle_deck();
+        // 为每个等待重抽的玩家执行重抽
+        let waiting = std::mem::take(&mut self.players_waiting_for_mulligan);
+        for player_id in waiting {
+            self.perform_mulligan(player_id)?;
 
-                // 重新抽取7张牌
-                player.draw_cards(7);
-            }
+            // 记录重抽次数，用于对手的补偿
+            self.record_mulligan(player_id);
         }
 
-        // 清空等待列表
-        self.player_waiting_for_mulligan = None;
-
-        // 记录重抽次数，用于奖赏卡补偿
-        self.mulligan_count += mulligan_count;
-
         Ok(())
     }
 
@@ -95,22 +97,22 @@ impl Game {
     pub fn perform_mulligan_and_check_basic_pokemon(
         &mut self,
         player_id: PlayerId,
-    ) -> Result<bool, String> {
+    ) -> Result<bool, GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only perform mulligan during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
 
         // 检查玩家是否存在
         if !self.players.contains_key(&player_id) {
-            return Err("Player not found".to_string());
+            return Err(GameError::PlayerNotFound);
         }
 
         // 执行重抽
         self.perform_mulligan(player_id)?;
 
         // 记录重抽次数
-        self.mulligan_count += 1;
+        self.record_mulligan(player_id);
 
         // 检查玩家是否已有基础宝可梦
         if let Some(player) = self.players.get(&player_id) {
@@ -128,10 +130,10 @@ impl Game {
     /// - Ok(MulliganResult::OneWithoutBasic(player_id)): 其中一方没有基础宝可梦，返回该玩家ID
     pub fn perform_mulligan_for_both_and_check_basic_pokemon(
         &mut self,
-    ) -> Result<MulliganResult, String> {
+    ) -> Result<MulliganResult, GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only perform mulligan during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
 
         // 获取所有玩家ID
@@ -140,6 +142,7 @@ impl Game {
         let mut all_without_basic = false;
 
         // 对所有玩家执行重抽
+        // 双方同时重抽时互相抵消，不计入各自的重抽次数
         for &player_id in &player_ids {
             self.perform_mulligan(player_id)?;
 
@@ -173,10 +176,23 @@ impl Game {
 
     /// 获取玩家可以声明的穆勒补偿卡牌数量上限
     /// 这个数量等于对手执行重新抽取手牌的次数
-    pub fn get_mulligan_compensation_limit(&self, _player_id: PlayerId) -> Result<usize, String> {
-        // 在实际实现中，这里应该跟踪每个玩家执行重新抽取手牌的次数
-        // 简化处理，返回一个固定值
-        Ok(self.mulligan_count)
+    pub fn get_mulligan_compensation_limit(&self, player_id: PlayerId) -> Result<usize, GameError> {
+        if !self.players.contains_key(&player_id) {
+            return Err(GameError::PlayerNotFound);
+        }
+
+        let opponent_id = self.get_opponent_id(player_id)?;
+        Ok(self.get_mulligan_count(opponent_id))
+    }
+
+    /// 获取指定玩家执行重新抽取手牌的次数
+    pub fn get_mulligan_count(&self, player_id: PlayerId) -> usize {
+        self.mulligan_counts.get(&player_id).copied().unwrap_or(0)
+    }
+
+    /// 记录一次重抽
+    fn record_mulligan(&mut self, player_id: PlayerId) {
+        *self.mulligan_counts.entry(player_id).or_insert(0) += 1;
     }
 
     /// 处理穆勒规则中的奖赏卡补偿
@@ -185,26 +201,26 @@ impl Game {
         &mut self,
         player_id: PlayerId,
         card_count: usize,
-    ) -> Result<Vec<CardId>, String> {
+    ) -> Result<Vec<CardId>, GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only perform mulligan compensation during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
 
         // 检查声明的卡牌数量是否超过上限
         let limit = self.get_mulligan_compensation_limit(player_id)?;
         if card_count > limit {
-            return Err(format!(
+            return Err(GameError::InvalidAction(format!(
                 "Declared card count {} exceeds limit {}",
                 card_count, limit
-            ));
+            )));
         }
 
         // 获取玩家
         let player = self
             .players
             .get_mut(&player_id)
-            .ok_or_else(|| "Player not found".to_string())?;
+            .ok_or(GameError::PlayerNotFound)?;
 
         // 抽取指定数量的卡牌
         let drawn_cards = player.draw_cards(card_count);
@@ -213,27 +229,32 @@ impl Game {
     }
 
     /// 阶段4: 玩家执行重新抽取手牌操作（穆勒规则）
-    pub fn perform_mulligan(&mut self, player_id: PlayerId) -> Result<(), String> {
+    pub fn perform_mulligan(&mut self, player_id: PlayerId) -> Result<(), GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only perform mulligan during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
+        self.require_setup_phase(
+            &[SetupPhase::Mulligans, SetupPhase::SelectingActivePokemon],
+            "perform a mulligan",
+        )?;
 
         // 获取玩家
         let player = self
             .players
             .get_mut(&player_id)
-            .ok_or_else(|| "Player not found".to_string())?;
+            .ok_or(GameError::PlayerNotFound)?;
 
         // 将手牌放回牌库底部（简化处理）
         for card_id in player.hand.drain(..) {
             player.deck.push(card_id);
         }
 
-        player.shuffle_deck();
+        player.shuffle_deck_with(&mut self.rng);
 
-        // 重新抽取7张牌
-        player.draw_cards(7);
+        // 重新抽取起始手牌数量的牌
+        player.draw_cards(self.rules.opening_hand_size());
+        self.add_event(GameEvent::DeckShuffled { player_id });
 
         Ok(())
     }
@@ -243,27 +264,31 @@ impl Game {
         &mut self,
         player_id: PlayerId,
         pokemon_id: CardId,
-    ) -> Result<(), String> {
+    ) -> Result<(), GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only select active Pokemon during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
+        self.require_setup_phase(
+            &[SetupPhase::Mulligans, SetupPhase::SelectingActivePokemon],
+            "select an active Pokemon",
+        )?;
 
         // 获取玩家
         let player = self
             .players
             .get_mut(&player_id)
-            .ok_or_else(|| "Player not found".to_string())?;
+            .ok_or(GameError::PlayerNotFound)?;
 
         // 检查选择的卡牌是否在玩家手牌中
         if !player.hand.contains(&pokemon_id) {
-            return Err("Selected Pokemon is not in player's hand".to_string());
+            return Err(GameError::InvalidAction("Selected Pokemon is not in player's hand".to_string()));
         }
 
         // 检查选择的卡牌是否是基础宝可梦
         if let Some(card) = self.card_database.get(&pokemon_id) {
             if !card.is_pokemon() {
-                return Err("Selected card is not a Pokemon".to_string());
+                return Err(GameError::InvalidAction("Selected card is not a Pokemon".to_string()));
             }
 
             // 检查是否是基础宝可梦
@@ -275,81 +300,96 @@ impl Game {
                 // 设置为活跃宝可梦
                 player.set_active_pokemon(pokemon_id);
             } else {
-                return Err("Selected Pokemon is not a Basic Pokemon".to_string());
+                return Err(GameError::InvalidAction("Selected Pokemon is not a Basic Pokemon".to_string()));
             }
         } else {
-            return Err("Card not found in database".to_string());
+            return Err(GameError::CardNotFound);
         }
 
+        self.advance_setup_phase(SetupPhase::SelectingActivePokemon);
+
         Ok(())
     }
 
     /// 阶段6: 玩家设置备战区宝可梦
+    ///
+    /// 先验证整批宝可梦（数量不超过 `max_bench_size`、都在手牌中且是宝可梦），
+    /// 全部通过后才放置；任何一只验证失败时不会放置任何宝可梦。
     pub fn setup_bench(
         &mut self,
         player_id: PlayerId,
         pokemon_ids: Vec<CardId>,
-    ) -> Result<(), String> {
+    ) -> Result<(), GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only setup bench during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
+        self.require_setup_phase(&[SetupPhase::SelectingActivePokemon], "set up the bench")?;
 
         // 获取玩家
         let player = self
             .players
             .get_mut(&player_id)
-            .ok_or_else(|| "Player not found".to_string())?;
+            .ok_or(GameError::PlayerNotFound)?;
+
+        // 检查备战区空间
+        if player.bench.len() + pokemon_ids.len() > self.rules.max_bench_size {
+            return Err(GameError::InvalidAction(format!(
+                "Bench can hold at most {} Pokemon",
+                self.rules.max_bench_size
+            )));
+        }
 
-        // 设置备战区宝可梦
-        for &pokemon_id in &pokemon_ids {
-            // 检查卡牌是否在玩家手牌中
-            if !player.hand.contains(&pokemon_id) {
-                return Err("Selected Pokemon is not in player's hand".to_string());
+        // 验证整批宝可梦
+        for (i, &pokemon_id) in pokemon_ids.iter().enumerate() {
+            // 检查卡牌是否在玩家手牌中（同一张卡不能选两次）
+            if !player.hand.contains(&pokemon_id) || pokemon_ids[..i].contains(&pokemon_id) {
+                return Err(GameError::InvalidAction("Selected Pokemon is not in player's hand".to_string()));
             }
 
-            // 检查卡牌是否是宝可梦
-            if let Some(card) = self.card_database.get(&pokemon_id) {
-                if !card.is_pokemon() {
-                    return Err("Selected card is not a Pokemon".to_string());
+            // 检查卡牌是否是基础宝可梦
+            match self.card_database.get(&pokemon_id) {
+                Some(card) if !card.is_basic_pokemon() => {
+                    return Err(GameError::InvalidAction("Selected card is not a Basic Pokemon".to_string()));
                 }
-
-                // 尝试将宝可梦放到备战区
-                if !player.bench_pokemon(pokemon_id) {
-                    return Err("Failed to place Pokemon on bench".to_string());
-                }
-            } else {
-                return Err("Card not found in database".to_string());
+                Some(_) => {}
+                None => return Err(GameError::CardNotFound),
             }
         }
 
+        // 全部验证通过后再放置到备战区
+        player.hand.retain(|card_id| !pokemon_ids.contains(card_id));
+        player.bench.extend(pokemon_ids);
+
         Ok(())
     }
 
     /// 阶段7: 放置奖赏卡
-    pub fn place_prize_cards(&mut self) -> Result<(), String> {
+    pub fn place_prize_cards(&mut self) -> Result<(), GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only place prize cards during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
+        self.require_setup_phase(&[SetupPhase::SelectingActivePokemon], "place prize cards")?;
 
-        // 为每个玩家放置6张奖赏卡
+        // 为每个玩家放置规则规定数量的奖赏卡
+        let prize_count = self.rules.prize_cards as usize;
         for player in self.players.values_mut() {
-            // 从牌库顶部拿6张卡作为奖赏卡
-            let prize_cards = player.draw_prize_cards(6);
-            // 在实际实现中，这些卡牌会被放置在奖赏卡区域
-            // 这里简化处理，只是设置奖赏卡数量
+            // 从牌库顶部拿奖赏卡，放入奖赏卡区
+            let prize_cards = player.draw_prize_cards(prize_count);
             player.prize_cards = prize_cards.len() as u32;
         }
 
+        self.advance_setup_phase(SetupPhase::Complete);
+
         Ok(())
     }
 
     /// 打印玩家手牌，用于穆勒规则重抽时让对手查看
-    pub fn print_player_hand(&self, player_id: PlayerId) -> Result<(), String> {
+    pub fn print_player_hand(&self, player_id: PlayerId) -> Result<(), GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only print player hand during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
 
         // 获取玩家
@@ -364,7 +404,7 @@ impl Game {
             }
             Ok(())
         } else {
-            Err("Player not found".to_string())
+            Err(GameError::PlayerNotFound)
         }
     }
 
@@ -373,15 +413,15 @@ impl Game {
     pub fn declare_and_perform_mulligan(
         &mut self,
         player_id: PlayerId,
-    ) -> Result<MulliganResult, String> {
+    ) -> Result<MulliganResult, GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only declare mulligan during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
 
         // 检查玩家是否存在
         if !self.players.contains_key(&player_id) {
-            return Err("Player not found".to_string());
+            return Err(GameError::PlayerNotFound);
         }
 
         // 打印宣告重抽的玩家手牌
@@ -399,6 +439,7 @@ impl Game {
 
         // 执行重抽
         self.perform_mulligan(player_id)?;
+        self.record_mulligan(player_id);
 
         // 检查重抽后是否已有基础宝可梦
         if let Some(player) = self.players.get(&player_id) {
@@ -411,25 +452,291 @@ impl Game {
                 Ok(MulliganResult::AllWithBasic)
             }
         } else {
-            Err("Player not found after mulligan".to_string())
+            Err(GameError::PlayerNotFound)
         }
     }
 
+    /// 查询玩家的设置进度
+    pub fn setup_status(&self, player_id: PlayerId) -> Result<SetupStatus, GameError> {
+        let player = self
+            .players
+            .get(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
+
+        let has_active = player.active_pokemon.is_some();
+        let bench_count = player.bench.len();
+        let no_basic_in_hand = player.find_basic_pokemon_in_hand(&self.card_database).is_empty();
+
+        Ok(SetupStatus {
+            has_active,
+            bench_count,
+            bench_full: bench_count >= self.rules.max_bench_size,
+            needs_mulligan: (!has_active && no_basic_in_hand)
+                || self.players_waiting_for_mulligan.contains(&player_id),
+        })
+    }
+
     /// 阶段8: 完成设置，开始游戏
-    pub fn complete_setup(&mut self) -> Result<(), String> {
+    pub fn complete_setup(&mut self) -> Result<(), GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only complete setup during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
+        self.require_setup_phase(&[SetupPhase::Complete], "complete setup")?;
 
         // 验证所有玩家都已完成设置
         for player in self.players.values() {
             // 检查每个玩家都有活跃宝可梦
             if player.active_pokemon.is_none() {
-                return Err("All players must have an active Pokemon".to_string());
+                return Err(GameError::InvalidAction("All players must have an active Pokemon".to_string()));
             }
         }
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::card::EnergyType;
+    use crate::core::game::state::GameRules;
+    use crate::core::player::Player;
+    use crate::test_fixtures::{basic_energy, pokemon};
+
+    /// 创建一个双方牌库都只有能量卡（没有基础宝可梦）的游戏
+    fn setup_game_without_basics() -> (Game, PlayerId, PlayerId) {
+        let mut game = Game::new();
+        let alice = Player::new("Alice".to_string());
+        let bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+
+        for player_id in [alice_id, bob_id] {
+            let mut deck = Vec::new();
+            for _ in 0..20 {
+                let card = basic_energy(EnergyType::Water);
+                deck.push(card.id);
+                game.add_card_to_database(card);
+            }
+            game.get_player_mut(player_id).unwrap().set_deck(deck);
+        }
+        game.start_setup().unwrap();
+        game.determine_turn_order().unwrap();
+
+        (game, alice_id, bob_id)
+    }
+
+    #[test]
+    fn test_setup_phase_enforces_step_order() {
+        let mut game = Game::new();
+        let alice = Player::new("Alice".to_string());
+        let bob = Player::new("Bob".to_string());
+        let player_ids = [alice.id, bob.id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        for player_id in player_ids {
+            let mut deck = Vec::new();
+            for _ in 0..20 {
+                let card = pokemon("Pidgey", 40);
+                deck.push(card.id);
+                game.add_card_to_database(card);
+            }
+            game.get_player_mut(player_id).unwrap().set_deck(deck);
+        }
+
+        assert_eq!(game.setup_phase, None);
+        game.start_setup().unwrap();
+        assert_eq!(game.setup_phase, Some(SetupPhase::DeterminingTurnOrder));
+        assert!(game.deal_opening_hands().is_err());
+        assert!(game.place_prize_cards().is_err());
+
+        game.determine_turn_order().unwrap();
+        assert_eq!(game.setup_phase, Some(SetupPhase::DealingOpeningHands));
+        assert!(game.determine_turn_order().is_err());
+
+        game.deal_opening_hands().unwrap();
+        assert_eq!(game.setup_phase, Some(SetupPhase::Mulligans));
+        assert_eq!(game.check_for_basic_pokemon().unwrap(), Vec::<PlayerId>::new());
+        assert!(game.place_prize_cards().is_err());
+
+        for player_id in player_ids {
+            let first = game.get_player(player_id).unwrap().hand[0];
+            game.select_active_pokemon(player_id, first).unwrap();
+        }
+        assert_eq!(game.setup_phase, Some(SetupPhase::SelectingActivePokemon));
+        assert!(game.start().is_err());
+
+        game.place_prize_cards().unwrap();
+        assert_eq!(game.setup_phase, Some(SetupPhase::Complete));
+        assert!(game.perform_mulligan(player_ids[0]).is_err());
+        game.complete_setup().unwrap();
+        game.start().unwrap();
+    }
+
+    #[test]
+    fn test_setup_steps_are_rejected_before_start_setup() {
+        let (mut game, alice_id, _) = setup_game_without_basics();
+        game.setup_phase = None;
+
+        // 没有调用 start_setup，任何设置步骤都不能执行
+        let err = game.deal_opening_hands().unwrap_err();
+        assert!(err.to_string().contains("before setup has started"));
+        assert!(game.determine_turn_order().is_err());
+        assert!(game.place_prize_cards().is_err());
+        assert!(game.setup_bench(alice_id, Vec::new()).is_err());
+        assert!(game.mark_player_for_mulligan(alice_id).is_err());
+        assert!(game.get_player(alice_id).unwrap().hand.is_empty());
+        assert_eq!(game.setup_phase, None);
+    }
+
+    #[test]
+    fn test_full_setup_with_five_card_hands_and_four_prizes() {
+        let rules = GameRules::builder()
+            .prize_cards(4)
+            .starting_hand_size(5)
+            .build()
+            .unwrap();
+        assert_eq!(rules.opening_hand_size(), 5);
+        let mut game = Game::with_rules(rules);
+        let alice = Player::new("Alice".to_string());
+        let bob = Player::new("Bob".to_string());
+        let player_ids = [alice.id, bob.id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        for player_id in player_ids {
+            let mut deck = Vec::new();
+            for _ in 0..20 {
+                let card = pokemon("Pidgey", 40);
+                deck.push(card.id);
+                game.add_card_to_database(card);
+            }
+            game.get_player_mut(player_id).unwrap().set_deck(deck);
+        }
+
+        game.start_setup().unwrap();
+        game.determine_turn_order().unwrap();
+        game.deal_opening_hands().unwrap();
+        // 重抽后同样抽回5张
+        game.perform_mulligan(player_ids[0]).unwrap();
+        assert_eq!(
+            game.history.last(),
+            Some(&GameEvent::DeckShuffled { player_id: player_ids[0] })
+        );
+        for player_id in player_ids {
+            assert_eq!(game.get_player(player_id).unwrap().hand.len(), 5);
+            let first = game.get_player(player_id).unwrap().hand[0];
+            game.select_active_pokemon(player_id, first).unwrap();
+        }
+        game.place_prize_cards().unwrap();
+
+        for player_id in player_ids {
+            let player = game.get_player(player_id).unwrap();
+            assert_eq!(player.hand.len(), 4);
+            assert_eq!(player.prize_cards, 4);
+            assert_eq!(player.deck.len(), 11);
+        }
+    }
+
+    #[test]
+    fn test_setup_bench_is_all_or_nothing() {
+        let mut game = Game::new();
+        let mut alice = Player::new("Alice".to_string());
+        let alice_id = alice.id;
+        for _ in 0..6 {
+            let card = pokemon("Pidgey", 40);
+            alice.hand.push(card.id);
+            game.add_card_to_database(card);
+        }
+        let hand = alice.hand.clone();
+        game.add_player(alice).unwrap();
+        game.setup_phase = Some(SetupPhase::SelectingActivePokemon);
+
+        // 6只超过备战区上限5只，一只都不放置
+        assert!(game.setup_bench(alice_id, hand.clone()).is_err());
+        let alice = game.get_player(alice_id).unwrap();
+        assert!(alice.bench.is_empty());
+        assert_eq!(alice.hand, hand);
+
+        game.setup_bench(alice_id, hand[..5].to_vec()).unwrap();
+        let alice = game.get_player(alice_id).unwrap();
+        assert_eq!(alice.bench, hand[..5].to_vec());
+        assert_eq!(alice.hand, vec![hand[5]]);
+    }
+
+    #[test]
+    fn test_setup_status() {
+        let (mut game, alice_id, bob_id) = setup_game_without_basics();
+        game.deal_opening_hands().unwrap();
+
+        // 没有基础宝可梦，需要重抽
+        let status = game.setup_status(alice_id).unwrap();
+        assert!(!status.has_active);
+        assert!(status.needs_mulligan);
+
+        let bob = game.get_player_mut(bob_id).unwrap();
+        bob.active_pokemon = Some(uuid::Uuid::new_v4());
+        bob.bench = (0..5).map(|_| uuid::Uuid::new_v4()).collect();
+        assert_eq!(
+            game.setup_status(bob_id).unwrap(),
+            SetupStatus {
+                has_active: true,
+                bench_count: 5,
+                bench_full: true,
+                needs_mulligan: false,
+            }
+        );
+        assert!(game.setup_status(uuid::Uuid::new_v4()).is_err());
+    }
+
+    #[test]
+    fn test_compensation_uses_opponent_mulligan_count() {
+        let (mut game, alice_id, bob_id) = setup_game_without_basics();
+        game.deal_opening_hands().unwrap();
+
+        for _ in 0..3 {
+            game.perform_mulligan_and_check_basic_pokemon(alice_id).unwrap();
+        }
+        game.perform_mulligan_and_check_basic_pokemon(bob_id).unwrap();
+
+        assert_eq!(game.get_mulligan_count(alice_id), 3);
+        assert_eq!(game.get_mulligan_count(bob_id), 1);
+        assert_eq!(game.get_mulligan_compensation_limit(bob_id).unwrap(), 3);
+        assert_eq!(game.get_mulligan_compensation_limit(alice_id).unwrap(), 1);
+
+        // 补偿不能超过对手的重抽次数
+        assert!(game.mulligan_compensation(alice_id, 2).is_err());
+        assert_eq!(game.mulligan_compensation(bob_id, 3).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_compensation_when_only_one_player_mulligans() {
+        let (mut game, alice_id, bob_id) = setup_game_without_basics();
+        game.deal_opening_hands().unwrap();
+
+        game.declare_and_perform_mulligan(alice_id).unwrap();
+        game.declare_and_perform_mulligan(alice_id).unwrap();
+
+        assert_eq!(game.get_mulligan_compensation_limit(bob_id).unwrap(), 2);
+        assert_eq!(game.get_mulligan_compensation_limit(alice_id).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_both_players_pending_mulligans() {
+        let (mut game, alice_id, bob_id) = setup_game_without_basics();
+        game.deal_opening_hands().unwrap();
+
+        game.mark_player_for_mulligan(alice_id).unwrap();
+        game.mark_player_for_mulligan(bob_id).unwrap();
+        game.mark_player_for_mulligan(alice_id).unwrap();
+        assert_eq!(game.players_waiting_for_mulligan.len(), 2);
+
+        game.perform_pending_mulligans().unwrap();
+
+        assert!(game.players_waiting_for_mulligan.is_empty());
+        assert_eq!(game.get_mulligan_count(alice_id), 1);
+        assert_eq!(game.get_mulligan_count(bob_id), 1);
+        assert_eq!(game.get_player(alice_id).unwrap().hand.len(), 7);
+    }
+}
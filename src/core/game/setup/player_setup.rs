@@ -2,19 +2,20 @@
 
 use crate::core::{
     deck::Deck,
+    game::error::GameError,
     game::state::{Game, GameState},
     player::{Player, PlayerId},
 };
 
 impl Game {
     /// Add a player to the game
-    pub fn add_player(&mut self, mut player: Player) -> Result<(), String> {
+    pub fn add_player(&mut self, mut player: Player) -> Result<(), GameError> {
         if self.state != GameState::Setup {
-            return Err("Cannot add players after game has started".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
 
         if self.players.len() >= 2 {
-            return Err("Maximum of 2 players allowed".to_string());
+            return Err(GameError::InvalidAction("Maximum of 2 players allowed".to_string()));
         }
 
         // Set prize cards according to game rules
@@ -26,10 +27,10 @@ impl Game {
         Ok(())
     }
 
-    /// Set a player's deck
-    pub fn set_player_deck(&mut self, player_id: PlayerId, deck: Deck) -> Result<(), String> {
+    /// Set a player's deck, shuffled with the game RNG
+    pub fn set_player_deck(&mut self, player_id: PlayerId, deck: Deck) -> Result<(), GameError> {
         if self.state != GameState::Setup {
-            return Err("Cannot set deck after game has started".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
 
         // Add deck cards to the game's card database
@@ -38,12 +39,15 @@ impl Game {
             // For now, we'll assume the cards are already in the database
         }
 
+        if !self.players.contains_key(&player_id) {
+            return Err(GameError::PlayerNotFound);
+        }
+        let shuffled_cards = deck.shuffle_with(&mut self.rng);
         if let Some(player) = self.players.get_mut(&player_id) {
-            let shuffled_cards = deck.shuffle();
             player.set_deck(shuffled_cards);
             Ok(())
         } else {
-            Err("Player not found".to_string())
+            Err(GameError::PlayerNotFound)
         }
     }
 }
\ No newline at end of file
@@ -1,23 +1,104 @@
 //! Turn setup functionality
 
-use crate::core::game::state::{Game, GameState};
+use crate::core::game::error::GameError;
+use crate::core::game::state::{Game, GameState, SetupPhase};
+use crate::core::player::PlayerId;
+use rand::Rng;
 
 impl Game {
-    /// 阶段1: 通过猜拳决定先后手顺序
-    pub fn determine_turn_order(&mut self) -> Result<(), String> {
+    /// 阶段1: 通过抛硬币决定先后手顺序
+    pub fn determine_turn_order(&mut self) -> Result<(), GameError> {
         // 检查当前是否处于设置阶段
         if self.state != GameState::Setup {
-            return Err("Can only determine turn order during setup phase".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
+        self.require_setup_phase(&[SetupPhase::DeterminingTurnOrder], "determine turn order")?;
 
-        // 在实际实现中，这里应该有一个随机化过程来决定先后手
-        // 简单起见，我们保持当前顺序，但在真实游戏中应该通过抛硬币等方式决定
-        for &player_id in self.players.keys() {
-            self.turn_order.push(player_id);
+        if self.players.len() < 2 {
+            return Err(GameError::InvalidAction(
+                "Need at least 2 players to determine turn order".to_string(),
+            ));
         }
 
-        self.turn_order.swap(0, 1); // 示例：交换两名玩家的顺序
+        // 按ID排序，使顺序不依赖于HashMap的迭代顺序
+        let mut player_ids: Vec<PlayerId> = self.players.keys().copied().collect();
+        player_ids.sort();
+
+        // 抛硬币（多于两名玩家时随机选择）决定谁先手，其余玩家按顺序轮流
+        let first = self.rng.gen_range(0..player_ids.len());
+        player_ids.rotate_left(first);
+
+        self.turn_order = player_ids;
+        self.current_player_index = 0;
+        self.advance_setup_phase(SetupPhase::DealingOpeningHands);
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game::rng::GameRng;
+    use crate::core::player::Player;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_turn_order_is_randomized() {
+        let alice = Player::new("Alice".to_string());
+        let bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+
+        let mut alice_first = false;
+        let mut bob_first = false;
+        for seed in 0..32 {
+            let mut game = Game::new();
+            game.rng = GameRng::seed_from_u64(seed);
+            game.add_player(alice.clone()).unwrap();
+            game.add_player(bob.clone()).unwrap();
+            game.setup_phase = Some(SetupPhase::DeterminingTurnOrder);
+
+            game.determine_turn_order().unwrap();
+            assert_eq!(game.turn_order.len(), 2);
+
+            match game.turn_order[0] {
+                id if id == alice_id => alice_first = true,
+                id if id == bob_id => bob_first = true,
+                _ => unreachable!(),
+            }
+        }
+
+        assert!(alice_first && bob_first);
+    }
+
+    #[test]
+    fn test_turn_order_requires_two_players() {
+        let mut game = Game::new();
+        assert!(game.determine_turn_order().is_err());
+
+        game.add_player(Player::new("Alice".to_string())).unwrap();
+        assert!(game.determine_turn_order().is_err());
+        assert!(game.turn_order.is_empty());
+    }
+
+    #[test]
+    fn test_setup_methods_reject_started_game() {
+        let mut game = Game::new();
+        game.add_player(Player::new("Alice".to_string())).unwrap();
+        game.add_player(Player::new("Bob".to_string())).unwrap();
+        game.state = GameState::InProgress;
+
+        let wrong_phase = Err(GameError::WrongPhase(GameState::InProgress));
+        assert_eq!(game.determine_turn_order(), wrong_phase);
+        assert_eq!(game.start_setup(), wrong_phase);
+        assert_eq!(game.add_player(Player::new("Carol".to_string())), wrong_phase);
+        assert!(matches!(
+            game.check_for_basic_pokemon(),
+            Err(GameError::WrongPhase(GameState::InProgress))
+        ));
+
+        let error: crate::Error = game.deal_opening_hands().unwrap_err().into();
+        assert!(matches!(error, crate::Error::GameError(GameError::WrongPhase(_))));
+        assert_eq!(error.to_string(), "游戏错误: Not allowed while the game is InProgress");
+    }
+}
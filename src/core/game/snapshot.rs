@@ -0,0 +1,63 @@
+//! 游戏状态的紧凑二进制快照（需要 `bincode` 特性）
+
+use crate::Error;
+use crate::core::game::state::Game;
+
+impl Game {
+    /// 将游戏状态序列化为紧凑的二进制格式
+    ///
+    /// 与 serde 的其他格式一样，不可序列化的运行时字段（`effects`）会被跳过。
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        Ok(bincode::serialize(self)?)
+    }
+
+    /// 从 `to_bytes` 生成的二进制数据恢复游戏状态
+    ///
+    /// 恢复后的 `effects` 为空，需要重新注册卡牌效果。
+    pub fn from_bytes(bytes: &[u8]) -> Result<Game, Error> {
+        Ok(bincode::deserialize(bytes)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::card::EnergyType;
+    use crate::core::game::state::GameState;
+    use crate::core::player::Player;
+    use crate::test_fixtures::basic_energy;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_mid_game_round_trip() {
+        let mut game = Game::new();
+        let mut alice = Player::new("小智".to_string());
+        let mut bob = Player::new("小茂".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+        let energy = basic_energy(EnergyType::Fire);
+        for player in [&mut alice, &mut bob] {
+            let active = Uuid::new_v4();
+            player.active_pokemon = Some(active);
+            player.set_deck((0..8).map(|_| Uuid::new_v4()).collect());
+            player.draw_cards(3);
+            player.add_damage(active, 40);
+        }
+        alice.attached_energy.insert(alice.active_pokemon.unwrap(), vec![energy.id]);
+        game.add_card_to_database(energy);
+        game.turn_order = vec![alice_id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.start().unwrap();
+        game.end_turn().unwrap();
+
+        let bytes = game.to_bytes().unwrap();
+        let restored = Game::from_bytes(&bytes).unwrap();
+
+        assert_eq!(restored.players, game.players);
+        assert_eq!(restored.card_database, game.card_database);
+        assert_eq!(restored.history, game.history);
+        assert_eq!(restored.state, GameState::InProgress);
+        assert_eq!(restored.state_hash(), game.state_hash());
+        assert!(Game::from_bytes(&bytes[..bytes.len() / 2]).is_err());
+    }
+}
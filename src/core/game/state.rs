@@ -4,17 +4,27 @@
 
 use crate::core::{
     card::{Card, CardId},
-    player::{Player, PlayerId},
+    deck::CopyLimitMode,
+    effects::{EffectManager, TrainerEffectRegistry},
+    game::{
+        actions::{DamageModifier, RetreatCostModifier},
+        choices::PendingChoice,
+        error::GameError,
+        rng::GameRng,
+    },
+    player::{Player, PlayerId, SpecialCondition},
 };
+use rand::SeedableRng;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 use uuid::Uuid;
 
 /// Unique identifier for a game
 pub type GameId = Uuid;
 
 /// Represents the current phase of a turn
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum GamePhase {
     /// Beginning of turn (draw card, flip coins for special conditions)
     BeginningOfTurn,
@@ -26,8 +36,23 @@ pub enum GamePhase {
     EndOfTurn,
 }
 
+/// Steps of the pre-game setup, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SetupPhase {
+    /// Waiting for the coin flip that decides the turn order
+    DeterminingTurnOrder,
+    /// Waiting for the opening hands to be dealt
+    DealingOpeningHands,
+    /// Players without a Basic Pokemon mulligan
+    Mulligans,
+    /// Players choose their active and benched Pokemon
+    SelectingActivePokemon,
+    /// Prize cards are placed; the game can start
+    Complete,
+}
+
 /// Represents the overall state of the game
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum GameState {
     /// Game is being set up
     Setup,
@@ -39,6 +64,21 @@ pub enum GameState {
     Cancelled,
 }
 
+/// How a game is resolved when both players meet a win condition at once
+///
+/// This happens when one batch of knockouts leaves both players with no
+/// prize cards left, or with no Pokemon in play.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TieBreak {
+    /// The player whose turn it is wins
+    #[default]
+    TurnPlayerWins,
+    /// The game ends without a winner and a one-prize sudden death game decides it
+    SuddenDeath,
+    /// The game ends in a draw
+    Draw,
+}
+
 /// Game rules and settings
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct GameRules {
@@ -46,12 +86,38 @@ pub struct GameRules {
     pub format: String,
     /// Number of prize cards each player starts with
     pub prize_cards: u32,
+    /// Number of cards in each opening hand (and mulligan redraw)
+    pub starting_hand_size: usize,
     /// Maximum hand size (usually unlimited in PTCG)
     pub max_hand_size: Option<u32>,
+    /// Hand size a player must discard down to before ending their turn
+    pub end_turn_hand_limit: Option<usize>,
     /// Time limit per turn (in seconds)
     pub turn_time_limit: Option<u32>,
+    /// Thinking time each player has for the whole game (in seconds)
+    pub time_bank: Option<u32>,
     /// Whether to use automatic deck shuffling
     pub auto_shuffle: bool,
+    /// Maximum number of Pokemon on the bench
+    pub max_bench_size: usize,
+    /// Number of energy cards a player may attach from hand each turn
+    pub energy_per_turn: u32,
+    /// Maximum copies of a card (other than basic energy) allowed in a deck
+    pub max_copies_per_card: u32,
+    /// Whether the copy limit counts each printing or each card name
+    pub copy_limit_by: CopyLimitMode,
+    /// Printings, as (set name, set number), exempt from the copy limit
+    pub unlimited_copies: HashSet<(String, String)>,
+    /// Whether the first player may attack during their restricted first turns
+    pub first_player_can_attack: bool,
+    /// Whether the first player may play a Supporter during their restricted first turns
+    pub first_player_can_supporter: bool,
+    /// Last turn number on which the first player's restrictions apply
+    pub first_turn_number_threshold: u32,
+    /// Whether special conditions are cured when a Pokemon moves from the active spot to the bench
+    pub cure_conditions_on_bench: bool,
+    /// How a game where both players win at once is resolved
+    pub tiebreak: TieBreak,
 }
 
 /// Main game structure
@@ -63,6 +129,8 @@ pub struct Game {
     pub state: GameState,
     /// Current phase of the turn
     pub phase: GamePhase,
+    /// Current setup step, once `start_setup` has been called
+    pub setup_phase: Option<SetupPhase>,
     /// All players in the game
     pub players: HashMap<PlayerId, Player>,
     /// Player turn order
@@ -71,16 +139,36 @@ pub struct Game {
     pub current_player_index: usize,
     /// All cards used in this game
     pub card_database: HashMap<CardId, Card>,
-    /// Turn counter
+    /// Number of the current player turn, counting every player's turns from 1
     pub turn_number: u32,
     /// Game rules and settings
     pub rules: GameRules,
     /// Game history/log
     pub history: Vec<GameEvent>,
-    /// Player waiting for mulligan after opponent completes setup (only one player can wait at a time)
-    pub player_waiting_for_mulligan: Option<PlayerId>,
-    /// Count of mulligans performed (used for prize card compensation)
-    pub mulligan_count: usize,
+    /// Players waiting for mulligan after their opponent completes setup
+    pub players_waiting_for_mulligan: Vec<PlayerId>,
+    /// Mulligans performed by each player (used for opponent's card compensation)
+    pub mulligan_counts: HashMap<PlayerId, usize>,
+    /// Retreat cost modifiers keyed by the card providing them (tool, stadium or Pokemon)
+    pub retreat_modifiers: HashMap<CardId, RetreatCostModifier>,
+    /// Damage modifiers keyed by the card providing them (tool or Pokemon)
+    pub damage_modifiers: HashMap<CardId, DamageModifier>,
+    /// Random number generator driving coin flips, shuffles and random choices
+    pub rng: GameRng,
+    /// Time (in seconds, caller-supplied clock) at which the current turn's timer started
+    pub turn_started_at: Option<u64>,
+    /// Thinking time left for each player who has spent some, under `rules.time_bank`
+    pub time_banks: HashMap<PlayerId, Duration>,
+    /// Players who must promote a new active Pokemon after a knockout
+    pub pending_promotions: Vec<PlayerId>,
+    /// Player decisions waiting to be answered, oldest first
+    pub pending_choices: Vec<PendingChoice>,
+    /// Card effects dispatched when game events are emitted (not serialized)
+    #[serde(skip)]
+    pub effects: EffectManager,
+    /// Trainer card effects keyed by card name, resolved by `play_trainer` (not serialized)
+    #[serde(skip)]
+    pub trainer_effects: TrainerEffectRegistry,
 }
 
 /// Events that can occur during a game
@@ -114,6 +202,36 @@ pub enum GameEvent {
         energy_id: CardId,
         pokemon_id: CardId,
     },
+    /// Pokemon in play was evolved by an evolution card from hand
+    PokemonEvolved {
+        player_id: PlayerId,
+        pokemon_id: CardId,
+        evolution_id: CardId,
+    },
+    /// Active Pokemon retreated, paying its cost with the discarded energy
+    PokemonRetreated {
+        player_id: PlayerId,
+        pokemon_id: CardId,
+        replacement_id: CardId,
+        discarded_energy: Vec<CardId>,
+    },
+    /// Stadium was played, replacing any stadium already in play
+    StadiumPlayed {
+        player_id: PlayerId,
+        card_id: CardId,
+    },
+    /// Pokemon Tool was attached
+    ToolAttached {
+        player_id: PlayerId,
+        tool_id: CardId,
+        pokemon_id: CardId,
+    },
+    /// Special condition was applied to a Pokemon
+    SpecialConditionApplied {
+        player_id: PlayerId,
+        pokemon_id: CardId,
+        condition: SpecialCondition,
+    },
     /// Attack was used
     AttackUsed {
         player_id: PlayerId,
@@ -138,7 +256,10 @@ pub enum GameEvent {
     /// Turn ended
     TurnEnded { player_id: PlayerId },
     /// Game ended
-    GameEnded { winner: Option<PlayerId> },
+    GameEnded {
+        winner: Option<PlayerId>,
+        reason: String,
+    },
 }
 
 impl Default for GameRules {
@@ -146,10 +267,234 @@ impl Default for GameRules {
         Self {
             format: "Standard".to_string(),
             prize_cards: 6,
+            starting_hand_size: 7,
             max_hand_size: None,
+            end_turn_hand_limit: None,
             turn_time_limit: None,
+            time_bank: None,
             auto_shuffle: true,
+            max_bench_size: 5,
+            energy_per_turn: 1,
+            max_copies_per_card: 4,
+            copy_limit_by: CopyLimitMode::ById,
+            unlimited_copies: HashSet::new(),
+            first_player_can_attack: true,
+            first_player_can_supporter: true,
+            first_turn_number_threshold: 1,
+            cure_conditions_on_bench: true,
+            tiebreak: TieBreak::TurnPlayerWins,
+        }
+    }
+}
+
+impl GameRules {
+    /// Rules for the Standard format
+    pub fn standard() -> Self {
+        Self {
+            format: "Standard".to_string(),
+            copy_limit_by: CopyLimitMode::ByName,
+            ..Self::first_turn_restricted()
+        }
+    }
+
+    /// Rules for the Expanded format
+    pub fn expanded() -> Self {
+        Self {
+            format: "Expanded".to_string(),
+            copy_limit_by: CopyLimitMode::ByName,
+            ..Self::first_turn_restricted()
+        }
+    }
+
+    /// Rules for the Gym Leader Challenge format (singleton decks)
+    pub fn glc() -> Self {
+        Self {
+            format: "GLC".to_string(),
+            max_copies_per_card: 1,
+            copy_limit_by: CopyLimitMode::ByName,
+            ..Self::first_turn_restricted()
+        }
+    }
+
+    /// Default rules with the modern turn-1 restrictions for the first player
+    pub fn first_turn_restricted() -> Self {
+        Self {
+            first_player_can_attack: false,
+            first_player_can_supporter: false,
+            ..Self::default()
+        }
+    }
+
+    /// Cards dealt for an opening hand and for each mulligan redraw
+    ///
+    /// Reads `starting_hand_size`, so a format only sets that field.
+    pub fn opening_hand_size(&self) -> usize {
+        self.starting_hand_size
+    }
+
+    /// Start building a custom ruleset from the default rules
+    pub fn builder() -> GameRulesBuilder {
+        GameRulesBuilder::new()
+    }
+}
+
+/// Fluent builder for custom `GameRules`
+#[derive(Debug, Clone, Default)]
+pub struct GameRulesBuilder {
+    rules: GameRules,
+}
+
+impl GameRulesBuilder {
+    /// Create a builder starting from `GameRules::default()`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a builder starting from an existing ruleset (e.g. a format preset)
+    pub fn from_rules(rules: GameRules) -> Self {
+        Self { rules }
+    }
+
+    /// Set the format name
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.rules.format = format.into();
+        self
+    }
+
+    /// Set the number of prize cards each player starts with
+    pub fn prize_cards(mut self, prize_cards: u32) -> Self {
+        self.rules.prize_cards = prize_cards;
+        self
+    }
+
+    /// Set the number of cards in each opening hand
+    pub fn starting_hand_size(mut self, starting_hand_size: usize) -> Self {
+        self.rules.starting_hand_size = starting_hand_size;
+        self
+    }
+
+    /// Set the maximum hand size
+    pub fn max_hand_size(mut self, max_hand_size: Option<u32>) -> Self {
+        self.rules.max_hand_size = max_hand_size;
+        self
+    }
+
+    /// Set the hand size to discard down to at the end of each turn
+    pub fn end_turn_hand_limit(mut self, end_turn_hand_limit: Option<usize>) -> Self {
+        self.rules.end_turn_hand_limit = end_turn_hand_limit;
+        self
+    }
+
+    /// Set the time limit per turn (in seconds)
+    pub fn turn_time_limit(mut self, turn_time_limit: Option<u32>) -> Self {
+        self.rules.turn_time_limit = turn_time_limit;
+        self
+    }
+
+    /// Set each player's thinking time for the whole game (in seconds)
+    pub fn time_bank(mut self, time_bank: Option<u32>) -> Self {
+        self.rules.time_bank = time_bank;
+        self
+    }
+
+    /// Set whether decks are shuffled automatically
+    pub fn auto_shuffle(mut self, auto_shuffle: bool) -> Self {
+        self.rules.auto_shuffle = auto_shuffle;
+        self
+    }
+
+    /// Set the maximum number of Pokemon on the bench
+    pub fn max_bench_size(mut self, max_bench_size: usize) -> Self {
+        self.rules.max_bench_size = max_bench_size;
+        self
+    }
+
+    /// Set the number of energy attachments allowed per turn
+    pub fn energy_per_turn(mut self, energy_per_turn: u32) -> Self {
+        self.rules.energy_per_turn = energy_per_turn;
+        self
+    }
+
+    /// Set the maximum copies of a card allowed in a deck
+    pub fn max_copies_per_card(mut self, max_copies_per_card: u32) -> Self {
+        self.rules.max_copies_per_card = max_copies_per_card;
+        self
+    }
+
+    /// Set whether the copy limit counts printings or card names
+    pub fn copy_limit_by(mut self, copy_limit_by: CopyLimitMode) -> Self {
+        self.rules.copy_limit_by = copy_limit_by;
+        self
+    }
+
+    /// Exempt a printing from the copy limit
+    pub fn unlimited_copies(mut self, set_name: &str, set_number: &str) -> Self {
+        self.rules
+            .unlimited_copies
+            .insert((set_name.to_string(), set_number.to_string()));
+        self
+    }
+
+    /// Set whether the first player may attack during their restricted first turns
+    pub fn first_player_can_attack(mut self, first_player_can_attack: bool) -> Self {
+        self.rules.first_player_can_attack = first_player_can_attack;
+        self
+    }
+
+    /// Set whether the first player may play a Supporter during their restricted first turns
+    pub fn first_player_can_supporter(mut self, first_player_can_supporter: bool) -> Self {
+        self.rules.first_player_can_supporter = first_player_can_supporter;
+        self
+    }
+
+    /// Set the last turn number on which the first player's restrictions apply
+    pub fn first_turn_number_threshold(mut self, first_turn_number_threshold: u32) -> Self {
+        self.rules.first_turn_number_threshold = first_turn_number_threshold;
+        self
+    }
+
+    /// Set whether special conditions are cured when a Pokemon is moved to the bench
+    pub fn cure_conditions_on_bench(mut self, cure_conditions_on_bench: bool) -> Self {
+        self.rules.cure_conditions_on_bench = cure_conditions_on_bench;
+        self
+    }
+
+    /// Set how a game where both players win at once is resolved
+    pub fn tiebreak(mut self, tiebreak: TieBreak) -> Self {
+        self.rules.tiebreak = tiebreak;
+        self
+    }
+
+    /// Validate and build the rules
+    pub fn build(self) -> Result<GameRules, String> {
+        let rules = self.rules;
+
+        if rules.format.trim().is_empty() {
+            return Err("Format name cannot be empty".to_string());
         }
+        if rules.prize_cards == 0 {
+            return Err("Prize cards must be greater than 0".to_string());
+        }
+        if rules.starting_hand_size == 0 {
+            return Err("Starting hand size must be greater than 0".to_string());
+        }
+        if rules.max_bench_size < 1 {
+            return Err("Bench size must be at least 1".to_string());
+        }
+        if rules.max_copies_per_card == 0 {
+            return Err("Max copies per card must be greater than 0".to_string());
+        }
+        if rules.max_hand_size == Some(0) {
+            return Err("Max hand size must be greater than 0".to_string());
+        }
+        if rules.turn_time_limit == Some(0) {
+            return Err("Turn time limit must be greater than 0".to_string());
+        }
+        if rules.time_bank == Some(0) {
+            return Err("Time bank must be greater than 0".to_string());
+        }
+
+        Ok(rules)
     }
 }
 
@@ -160,6 +505,7 @@ impl Game {
             id: Uuid::new_v4(),
             state: GameState::Setup,
             phase: GamePhase::BeginningOfTurn,
+            setup_phase: None,
             players: HashMap::new(),
             turn_order: Vec::new(),
             current_player_index: 0,
@@ -167,11 +513,31 @@ impl Game {
             turn_number: 1,
             rules: GameRules::default(),
             history: Vec::new(),
-            player_waiting_for_mulligan: None,
-            mulligan_count: 0,
+            players_waiting_for_mulligan: Vec::new(),
+            mulligan_counts: HashMap::new(),
+            retreat_modifiers: HashMap::new(),
+            damage_modifiers: HashMap::new(),
+            rng: GameRng::from_entropy(),
+            turn_started_at: None,
+            time_banks: HashMap::new(),
+            pending_promotions: Vec::new(),
+            pending_choices: Vec::new(),
+            effects: EffectManager::new(),
+            trainer_effects: TrainerEffectRegistry::new(),
         }
     }
 
+    /// Create a new game whose randomness all comes from `seed`
+    ///
+    /// Deck shuffles, the turn-order flip, coin flips and random targets all
+    /// draw from the game RNG, so two games with the same seed and the same
+    /// inputs play out identically.
+    pub fn new_with_seed(seed: u64) -> Self {
+        let mut game = Self::new();
+        game.rng = GameRng::seed_from_u64(seed);
+        game
+    }
+
     /// Create a new game with custom rules
     pub fn with_rules(rules: GameRules) -> Self {
         let mut game = Self::new();
@@ -190,8 +556,12 @@ impl Game {
     }
 
     /// Add an event to the game history
+    ///
+    /// The event is recorded in the history and then dispatched to any card
+    /// effects whose trigger matches it.
     pub fn add_event(&mut self, event: GameEvent) {
-        self.history.push(event);
+        self.history.push(event.clone());
+        self.dispatch_triggers_for_event(&event);
     }
 
     /// Get the game history
@@ -222,33 +592,93 @@ impl Game {
     }
 
     /// Get the current player's ID
-    pub fn get_current_player_id(&self) -> Result<PlayerId, String> {
+    pub fn get_current_player_id(&self) -> Result<PlayerId, GameError> {
         self.turn_order
             .get(self.current_player_index)
             .copied()
-            .ok_or_else(|| "No current player".to_string())
+            .ok_or_else(|| GameError::InvalidAction("No current player".to_string()))
     }
 
     /// Get the current player
-    pub fn get_current_player(&self) -> Result<&Player, String> {
+    pub fn get_current_player(&self) -> Result<&Player, GameError> {
         let player_id = self.get_current_player_id()?;
         self.players
             .get(&player_id)
-            .ok_or_else(|| "Current player not found".to_string())
+            .ok_or(GameError::PlayerNotFound)
     }
 
     /// Get a mutable reference to the current player
-    pub fn get_current_player_mut(&mut self) -> Result<&mut Player, String> {
+    pub fn get_current_player_mut(&mut self) -> Result<&mut Player, GameError> {
         let player_id = self.get_current_player_id()?;
         self.players
             .get_mut(&player_id)
-            .ok_or_else(|| "Current player not found".to_string())
+            .ok_or(GameError::PlayerNotFound)
+    }
+
+    /// Get the opponent of a player in a two-player game
+    pub fn get_opponent_id(&self, player_id: PlayerId) -> Result<PlayerId, GameError> {
+        let mut others: Vec<PlayerId> = self
+            .players
+            .keys()
+            .copied()
+            .filter(|&id| id != player_id)
+            .collect();
+        others.sort();
+        others
+            .first()
+            .copied()
+            .ok_or_else(|| GameError::InvalidAction("Opponent not found".to_string()))
+    }
+
+    /// Check that setup is at one of the `allowed` steps
+    ///
+    /// A `setup_phase` of `None` means `start_setup` has not been called, so
+    /// every setup step is rejected.
+    pub(crate) fn require_setup_phase(&self, allowed: &[SetupPhase], action: &str) -> Result<(), GameError> {
+        match self.setup_phase {
+            Some(phase) if allowed.contains(&phase) => Ok(()),
+            Some(phase) => {
+                Err(GameError::InvalidAction(format!("Cannot {} during setup phase {:?}", action, phase)))
+            }
+            None => Err(GameError::InvalidAction(format!("Cannot {} before setup has started", action))),
+        }
+    }
+
+    /// Move setup on to `next`
+    pub(crate) fn advance_setup_phase(&mut self, next: SetupPhase) {
+        self.setup_phase = Some(next);
     }
 
     /// Force end the game
     pub fn end_game(&mut self, winner: Option<PlayerId>) {
         self.state = GameState::Finished { winner };
-        self.add_event(GameEvent::GameEnded { winner });
+        self.add_event(GameEvent::GameEnded {
+            winner,
+            reason: "Game ended early".to_string(),
+        });
+    }
+
+    /// Concede the game on behalf of `player_id`; their opponent wins
+    ///
+    /// Allowed at any point while the game is being set up or played,
+    /// regardless of whose turn it is.
+    pub fn concede(&mut self, player_id: PlayerId) -> Result<(), GameError> {
+        if !matches!(self.state, GameState::Setup | GameState::InProgress) {
+            return Err(GameError::WrongPhase(self.state.clone()));
+        }
+        let name = self
+            .get_player(player_id)
+            .ok_or(GameError::PlayerNotFound)?
+            .name
+            .clone();
+        let winner = self.get_opponent_id(player_id)?;
+
+        self.state = GameState::Finished { winner: Some(winner) };
+        self.add_event(GameEvent::GameEnded {
+            winner: Some(winner),
+            reason: format!("{} conceded", name),
+        });
+        Ok(())
     }
 
     /// Cancel the game
@@ -275,6 +705,41 @@ mod tests {
         assert_eq!(game.turn_number, 1);
     }
 
+    #[test]
+    fn test_same_seed_gives_same_setup() {
+        use crate::core::deck::Deck;
+
+        let mut deck = Deck::new("Test".to_string(), "Standard".to_string());
+        for _ in 0..15 {
+            deck.add_card(Uuid::new_v4(), 4);
+        }
+        let players = [Player::new("Alice".to_string()), Player::new("Bob".to_string())];
+
+        let set_up = |seed| {
+            let mut game = Game::new_with_seed(seed);
+            for player in &players {
+                game.add_player(player.clone()).unwrap();
+                game.set_player_deck(player.id, deck.clone()).unwrap();
+            }
+            game.start_setup().unwrap();
+            game.determine_turn_order().unwrap();
+            game.deal_opening_hands().unwrap();
+            game
+        };
+
+        let (first, second) = (set_up(7), set_up(7));
+        assert_eq!(first.turn_order, second.turn_order);
+        for player in &players {
+            let (a, b) = (&first.players[&player.id], &second.players[&player.id]);
+            assert_eq!(a.deck, b.deck);
+            assert_eq!(a.hand, b.hand);
+        }
+
+        // A different seed shuffles differently
+        let other = set_up(8);
+        assert_ne!(first.players[&players[0].id].deck, other.players[&players[0].id].deck);
+    }
+
     #[test]
     fn test_game_with_rules() {
         let rules = GameRules {
@@ -283,6 +748,7 @@ mod tests {
             max_hand_size: Some(7),
             turn_time_limit: Some(50),
             auto_shuffle: false,
+            ..GameRules::default()
         };
 
         let game = Game::with_rules(rules.clone());
@@ -310,10 +776,50 @@ mod tests {
 
         assert!(game.add_player(player1).is_ok());
         assert!(game.add_player(player2).is_ok());
+        game.setup_phase = Some(SetupPhase::DeterminingTurnOrder);
 
         assert!(game.determine_turn_order().is_ok());
 
         assert_eq!(game.turn_order.len(), 2);
         assert_eq!(game.current_player_index, 0);
     }
+
+    #[test]
+    fn test_rules_presets() {
+        let standard = GameRules::standard();
+        assert_eq!(standard.format, "Standard");
+        assert_eq!(standard.prize_cards, 6);
+        assert!(!standard.first_player_can_attack);
+        assert!(!standard.first_player_can_supporter);
+
+        assert_eq!(GameRules::expanded().format, "Expanded");
+
+        let glc = GameRules::glc();
+        assert_eq!(glc.format, "GLC");
+        assert_eq!(glc.max_copies_per_card, 1);
+
+        assert!(GameRules::default().first_player_can_attack);
+        assert!(!GameRules::first_turn_restricted().first_player_can_attack);
+    }
+
+    #[test]
+    fn test_rules_builder() {
+        let rules = GameRules::builder()
+            .format("Expanded")
+            .prize_cards(4)
+            .max_bench_size(8)
+            .first_player_can_attack(false)
+            .build()
+            .unwrap();
+
+        assert_eq!(rules.format, "Expanded");
+        assert_eq!(rules.prize_cards, 4);
+        assert_eq!(rules.max_bench_size, 8);
+        assert!(!rules.first_player_can_attack);
+        assert!(rules.first_player_can_supporter);
+        assert_eq!(rules.energy_per_turn, GameRules::default().energy_per_turn);
+
+        assert!(GameRules::builder().prize_cards(0).build().is_err());
+        assert!(GameRules::builder().max_bench_size(0).build().is_err());
+    }
 }
@@ -0,0 +1,179 @@
+//! Deterministic game-state hashing for desync detection
+//!
+//! Clients and server can compare `Game::state_hash` after each action to
+//! verify they hold identical state. The hash does not depend on `HashMap`
+//! iteration order, process-specific hasher keys or platform endianness.
+
+use crate::core::card::CardId;
+use crate::core::game::state::Game;
+use crate::core::player::{Player, PlayerId};
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+
+/// 64-bit FNV-1a hasher with fixed parameters and little-endian integer encoding
+struct StableHasher(u64);
+
+impl StableHasher {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Self(Self::OFFSET_BASIS)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(Self::PRIME);
+        }
+    }
+
+    fn write_u16(&mut self, i: u16) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, i: u32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+
+    fn write_i32(&mut self, i: i32) {
+        self.write(&i.to_le_bytes());
+    }
+
+    fn write_isize(&mut self, i: isize) {
+        self.write(&(i as i64).to_le_bytes());
+    }
+}
+
+/// Hash map entries in key order
+fn hash_sorted<K: Ord + Hash, V, H: Hasher>(
+    map: &HashMap<K, V>,
+    state: &mut H,
+    mut hash_value: impl FnMut(&V, &mut H),
+) {
+    let mut entries: Vec<(&K, &V)> = map.iter().collect();
+    entries.sort_by(|a, b| a.0.cmp(b.0));
+
+    entries.len().hash(state);
+    for (key, value) in entries {
+        key.hash(state);
+        hash_value(value, state);
+    }
+}
+
+fn hash_player<H: Hasher>(player: &Player, state: &mut H) {
+    player.id.hash(state);
+    player.name.hash(state);
+    player.prize_cards.hash(state);
+    player.prize_pile.hash(state);
+    player.hand.hash(state);
+    player.active_pokemon.hash(state);
+    player.bench.hash(state);
+    player.discard_pile.hash(state);
+    player.lost_zone.hash(state);
+    let mut revealed: Vec<&CardId> = player.revealed.iter().collect();
+    revealed.sort();
+    revealed.hash(state);
+    player.deck.hash(state);
+    hash_sorted(&player.attached_energy, state, |energy, state| energy.hash(state));
+    hash_sorted(&player.attached_tools, state, |tool: &CardId, state| tool.hash(state));
+    hash_sorted(&player.evolution_stacks, state, |stack, state| stack.hash(state));
+    hash_sorted(&player.damage_counters, state, |damage, state| damage.hash(state));
+    player.has_attacked.hash(state);
+    player.can_play_trainer.hash(state);
+    player.has_retreated.hash(state);
+    player.supporter_played_this_turn.hash(state);
+    player.energy_attached_this_turn.hash(state);
+    player.stadium.hash(state);
+    hash_sorted(&player.protected_until_turn, state, |turn, state| turn.hash(state));
+    hash_sorted(&player.markers, state, |markers, state| {
+        hash_sorted(markers, state, |value, state| value.hash(state));
+    });
+    hash_sorted(&player.special_conditions, state, |conditions, state| {
+        conditions.len().hash(state);
+        for instance in conditions {
+            instance.condition.hash(state);
+            instance.duration.hash(state);
+            instance.applied_turn.hash(state);
+            hash_sorted(&instance.data, state, |value, state| value.hash(state));
+        }
+    });
+}
+
+impl Game {
+    /// Compute a stable hash of the game's public and private state
+    ///
+    /// Covers the game state, phase, turn, turn order, RNG state, pending
+    /// setup/promotion/choice bookkeeping and every player's zones, attachments,
+    /// damage and special conditions. The card database and event history
+    /// are not included.
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = StableHasher::new();
+
+        self.state.hash(&mut hasher);
+        self.phase.hash(&mut hasher);
+        self.setup_phase.hash(&mut hasher);
+        self.turn_number.hash(&mut hasher);
+        self.current_player_index.hash(&mut hasher);
+        self.turn_order.hash(&mut hasher);
+        self.rng.hash(&mut hasher);
+        self.players_waiting_for_mulligan.hash(&mut hasher);
+        hash_sorted(&self.mulligan_counts, &mut hasher, |count, state| count.hash(state));
+        self.pending_promotions.hash(&mut hasher);
+        self.pending_choices.hash(&mut hasher);
+
+        let mut player_ids: Vec<&PlayerId> = self.players.keys().collect();
+        player_ids.sort();
+        player_ids.len().hash(&mut hasher);
+        for player_id in player_ids {
+            hash_player(&self.players[player_id], &mut hasher);
+        }
+
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game::rng::GameRng;
+    use rand::SeedableRng;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_identical_games_hash_equal() {
+        let mut game = Game::new();
+        game.rng = GameRng::seed_from_u64(1);
+        let mut alice = Player::new("Alice".to_string());
+        let alice_id = alice.id;
+        let pokemon_id = Uuid::new_v4();
+        alice.active_pokemon = Some(pokemon_id);
+        alice.set_deck((0..5).map(|_| Uuid::new_v4()).collect());
+        game.add_player(alice).unwrap();
+        game.add_player(Player::new("Bob".to_string())).unwrap();
+
+        // Rebuild the player map so its iteration order differs
+        let mut copy = game.clone();
+        let mut players: Vec<(PlayerId, Player)> = game.players.clone().into_iter().collect();
+        players.reverse();
+        copy.players = players.into_iter().collect();
+        assert_eq!(game.state_hash(), copy.state_hash());
+
+        copy.get_player_mut(alice_id).unwrap().add_damage(pokemon_id, 10);
+        assert_ne!(game.state_hash(), copy.state_hash());
+    }
+}
@@ -0,0 +1,233 @@
+//! Resolution of effect targets that the engine picks itself
+
+use crate::core::card::CardId;
+use crate::core::effects::TargetRequirement;
+use crate::core::game::error::GameError;
+use crate::core::game::state::Game;
+use crate::core::player::{Player, PlayerId};
+use rand::seq::SliceRandom;
+
+impl Game {
+    /// Cards matching a random-target filter, relative to `controller`
+    ///
+    /// Filters are `own_` or `opponent_` followed by a zone: `active`,
+    /// `bench`, `pokemon` (active and bench), `hand` or `discard`. Pokemon
+    /// are listed active first, then in bench order.
+    pub fn random_target_candidates(
+        &self,
+        filter: &str,
+        controller: PlayerId,
+    ) -> Result<Vec<CardId>, GameError> {
+        let (owner_id, zone) = if let Some(zone) = filter.strip_prefix("own_") {
+            (controller, zone)
+        } else if let Some(zone) = filter.strip_prefix("opponent_") {
+            (self.get_opponent_id(controller)?, zone)
+        } else {
+            return Err(GameError::InvalidTarget(format!("Unknown target filter: {}", filter)));
+        };
+        let owner = self
+            .get_player(owner_id)
+            .ok_or(GameError::PlayerNotFound)?;
+
+        Ok(match zone {
+            "active" => owner.active_pokemon.into_iter().collect(),
+            "bench" => owner.bench.clone(),
+            "pokemon" => owner.pokemon_in_play(),
+            "hand" => owner.hand.clone(),
+            "discard" => owner.discard_pile.clone(),
+            _ => return Err(GameError::InvalidTarget(format!("Unknown target filter: {}", filter))),
+        })
+    }
+
+    /// Pick a random card for `EffectTarget::Random { filter }` with the game RNG
+    ///
+    /// Returns `None` if the filter is unknown or matches no cards.
+    pub fn resolve_random_target(&mut self, filter: &str, controller: PlayerId) -> Option<CardId> {
+        let candidates = self.random_target_candidates(filter, controller).ok()?;
+        candidates.choose(&mut self.rng).copied()
+    }
+
+    /// Whether a card satisfies an effect's target requirement
+    ///
+    /// Card types are read from the card database and zones from the player
+    /// who holds the card. `MinHP` compares remaining HP (printed HP minus
+    /// damage). `Custom` requirements have no generic meaning and never match.
+    pub fn meets_requirement(&self, card_id: CardId, req: &TargetRequirement) -> bool {
+        let card = self.get_card(card_id);
+        let owner = self.card_owner(card_id);
+        let damage = || {
+            owner
+                .and_then(|owner| owner.damage_counters.get(&card_id).copied())
+                .unwrap_or(0)
+        };
+
+        match req {
+            TargetRequirement::Pokemon => card.is_some_and(|card| card.is_pokemon()),
+            TargetRequirement::Energy => card.is_some_and(|card| card.is_energy()),
+            TargetRequirement::Trainer => card.is_some_and(|card| card.is_trainer()),
+            TargetRequirement::InPlay => owner.is_some_and(|owner| owner.pokemon_in_play().contains(&card_id)),
+            TargetRequirement::InHand => owner.is_some_and(|owner| owner.hand.contains(&card_id)),
+            TargetRequirement::InDiscard => owner.is_some_and(|owner| owner.discard_pile.contains(&card_id)),
+            TargetRequirement::OwnedBy(player_id) => owner.is_some_and(|owner| owner.id == *player_id),
+            TargetRequirement::HasEnergyType(energy_type) => owner.is_some_and(|owner| {
+                owner
+                    .get_attached_energy_types(card_id, &self.card_database)
+                    .contains(energy_type)
+            }),
+            TargetRequirement::MinHP(min_hp) => card
+                .and_then(|card| card.get_hp())
+                .is_some_and(|hp| hp.saturating_sub(damage()) >= *min_hp),
+            TargetRequirement::MinDamage(min_damage) => damage() >= *min_damage,
+            TargetRequirement::Custom(_) => false,
+        }
+    }
+
+    /// Every card, across both players, that meets all of the requirements
+    ///
+    /// Used by UIs to highlight legal targets. Cards are listed per player in
+    /// sorted player order, each in `Player::owned_card_ids` order.
+    pub fn valid_targets_for(&self, requirements: &[TargetRequirement]) -> Vec<CardId> {
+        self.players_in_order()
+            .flat_map(Player::owned_card_ids)
+            .filter(|&card_id| requirements.iter().all(|req| self.meets_requirement(card_id, req)))
+            .collect()
+    }
+
+    /// The player holding a card in any zone, checked in sorted player order
+    fn card_owner(&self, card_id: CardId) -> Option<&Player> {
+        self.players_in_order()
+            .find(|player| player.owned_card_ids().contains(&card_id))
+    }
+
+    fn players_in_order(&self) -> impl Iterator<Item = &Player> {
+        let mut player_ids: Vec<&PlayerId> = self.players.keys().collect();
+        player_ids.sort();
+        player_ids.into_iter().filter_map(|player_id| self.players.get(player_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game::rng::GameRng;
+    use crate::core::player::Player;
+    use rand::SeedableRng;
+    use uuid::Uuid;
+
+    fn game_with_bench(seed: u64) -> (Game, PlayerId, Vec<CardId>) {
+        let mut game = Game::new();
+        game.rng = GameRng::seed_from_u64(seed);
+        let alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let alice_id = alice.id;
+        let bench: Vec<CardId> = (0..4).map(|n| Uuid::from_u128(n + 1)).collect();
+        bob.active_pokemon = Some(Uuid::from_u128(100));
+        bob.bench = bench.clone();
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        (game, alice_id, bench)
+    }
+
+    #[test]
+    fn test_random_bench_target_is_reproducible() {
+        let (mut game, alice_id, bench) = game_with_bench(42);
+        let picks: Vec<CardId> = (0..5)
+            .map(|_| game.resolve_random_target("opponent_bench", alice_id).unwrap())
+            .collect();
+        assert!(picks.iter().all(|pick| bench.contains(pick)));
+
+        let (mut replay, alice_id, _) = game_with_bench(42);
+        let replayed: Vec<CardId> = (0..5)
+            .map(|_| replay.resolve_random_target("opponent_bench", alice_id).unwrap())
+            .collect();
+        assert_eq!(picks, replayed);
+    }
+
+    #[test]
+    fn test_random_target_filters() {
+        let (mut game, alice_id, bench) = game_with_bench(7);
+
+        let pokemon = game.random_target_candidates("opponent_pokemon", alice_id).unwrap();
+        assert_eq!(pokemon.len(), 5);
+        assert_eq!(pokemon[1..], bench[..]);
+        assert_eq!(game.resolve_random_target("own_hand", alice_id), None);
+        assert_eq!(game.resolve_random_target("everything", alice_id), None);
+        assert!(game.random_target_candidates("own_deck", alice_id).is_err());
+    }
+
+    #[test]
+    fn test_target_requirements_on_board() {
+        use crate::core::card::EnergyType;
+        use crate::test_fixtures::{basic_energy, pokemon};
+
+        let (mut game, alice_id, bench) = game_with_bench(1);
+        let bob_id = game.get_opponent_id(alice_id).unwrap();
+        let pikachu = pokemon("Pikachu", 60);
+        let energy = basic_energy(EnergyType::Lightning);
+        let (pikachu_id, energy_id, discarded) = (pikachu.id, energy.id, Uuid::new_v4());
+        game.add_card_to_database(pikachu);
+        game.add_card_to_database(energy);
+        let bob = game.get_player_mut(bob_id).unwrap();
+        bob.bench[0] = pikachu_id;
+        bob.attached_energy.insert(pikachu_id, vec![energy_id]);
+        bob.add_damage(pikachu_id, 30);
+        game.get_player_mut(alice_id).unwrap().discard_pile.push(discarded);
+
+        let meets = |card_id, req| game.meets_requirement(card_id, &req);
+        assert!(meets(pikachu_id, TargetRequirement::Pokemon));
+        assert!(!meets(pikachu_id, TargetRequirement::Energy));
+        assert!(meets(energy_id, TargetRequirement::Energy));
+        assert!(meets(pikachu_id, TargetRequirement::InPlay));
+        assert!(!meets(energy_id, TargetRequirement::InPlay));
+        assert!(meets(pikachu_id, TargetRequirement::OwnedBy(bob_id)));
+        assert!(!meets(pikachu_id, TargetRequirement::OwnedBy(alice_id)));
+        assert!(meets(discarded, TargetRequirement::InDiscard));
+        assert!(meets(discarded, TargetRequirement::OwnedBy(alice_id)));
+        assert!(!meets(discarded, TargetRequirement::InHand));
+        assert!(meets(pikachu_id, TargetRequirement::HasEnergyType(EnergyType::Lightning)));
+        assert!(!meets(bench[1], TargetRequirement::HasEnergyType(EnergyType::Lightning)));
+        assert!(meets(pikachu_id, TargetRequirement::MinHP(30)));
+        assert!(!meets(pikachu_id, TargetRequirement::MinHP(40)));
+        assert!(meets(pikachu_id, TargetRequirement::MinDamage(30)));
+        assert!(!meets(bench[1], TargetRequirement::MinDamage(10)));
+        assert!(!meets(pikachu_id, TargetRequirement::Custom("anything".to_string())));
+    }
+
+    #[test]
+    fn test_valid_targets_are_damaged_pokemon_in_play() {
+        use crate::core::card::Card;
+        use crate::test_fixtures::pokemon;
+
+        let (mut game, alice_id, _) = game_with_bench(3);
+        let bob_id = game.get_opponent_id(alice_id).unwrap();
+        let pokemon: Vec<Card> = (0..4).map(|_| pokemon("Rattata", 40)).collect();
+        let ids: Vec<CardId> = pokemon.iter().map(|card| card.id).collect();
+        for card in pokemon {
+            game.add_card_to_database(card);
+        }
+
+        // Bob: two damaged benched Pokemon and one undamaged; Alice: a damaged
+        // Pokemon in the discard pile (not in play)
+        let bob = game.get_player_mut(bob_id).unwrap();
+        bob.bench = vec![ids[0], ids[1], ids[2]];
+        bob.add_damage(ids[0], 10);
+        bob.add_damage(ids[2], 20);
+        let alice = game.get_player_mut(alice_id).unwrap();
+        alice.discard_pile.push(ids[3]);
+        alice.add_damage(ids[3], 10);
+
+        let requirements = [
+            TargetRequirement::Pokemon,
+            TargetRequirement::InPlay,
+            TargetRequirement::MinDamage(10),
+        ];
+        let mut targets = game.valid_targets_for(&requirements);
+        targets.sort();
+        let mut expected = vec![ids[0], ids[2]];
+        expected.sort();
+        assert_eq!(targets, expected);
+
+        let pokemon_in_play = game.valid_targets_for(&requirements[..2]);
+        assert_eq!(pokemon_in_play.len(), 3);
+    }
+}
@@ -0,0 +1,194 @@
+//! Dispatch of card effect triggers from game events
+
+use crate::core::effects::{EffectContext, EffectResult, EffectTarget, EffectTrigger};
+use crate::core::game::state::{Game, GameEvent};
+use std::collections::HashMap;
+
+/// Map a game event to the effect trigger it fires, if any
+pub fn trigger_for_event(event: &GameEvent) -> Option<EffectTrigger> {
+    match event {
+        GameEvent::TurnStarted { .. } => Some(EffectTrigger::OnTurnStart),
+        GameEvent::TurnEnded { .. } => Some(EffectTrigger::OnTurnEnd),
+        GameEvent::CardDrawn { .. } => Some(EffectTrigger::OnCardDraw),
+        GameEvent::CardPlayed { .. }
+        | GameEvent::PokemonEvolved { .. }
+        | GameEvent::StadiumPlayed { .. }
+        | GameEvent::ToolAttached { .. } => Some(EffectTrigger::OnPlay),
+        GameEvent::PokemonBenched { .. } => Some(EffectTrigger::OnEnterPlay),
+        GameEvent::EnergyAttached { .. } => Some(EffectTrigger::OnEnergyAttach),
+        GameEvent::AttackUsed { .. } => Some(EffectTrigger::OnAttack),
+        GameEvent::DamageDealt { .. } => Some(EffectTrigger::OnTakeDamage),
+        GameEvent::PokemonKnockedOut { .. } => Some(EffectTrigger::OnKnockOut),
+        GameEvent::GameStarted
+        | GameEvent::PrizeTaken { .. }
+        | GameEvent::DeckShuffled { .. }
+        | GameEvent::PokemonRetreated { .. }
+        | GameEvent::SpecialConditionApplied { .. }
+        | GameEvent::GameEnded { .. } => None,
+    }
+}
+
+/// Build the effect context for an event: the card or player it concerns
+fn context_for_event(event: &GameEvent, trigger: EffectTrigger) -> Option<EffectContext> {
+    let (controller, target) = match *event {
+        GameEvent::TurnStarted { player_id, .. }
+        | GameEvent::TurnEnded { player_id }
+        | GameEvent::CardDrawn { player_id, .. } => (player_id, EffectTarget::Player(player_id)),
+        GameEvent::CardPlayed { player_id, card_id }
+        | GameEvent::PokemonBenched { player_id, card_id }
+        | GameEvent::StadiumPlayed { player_id, card_id }
+        | GameEvent::PokemonEvolved {
+            player_id,
+            evolution_id: card_id,
+            ..
+        }
+        | GameEvent::ToolAttached {
+            player_id,
+            tool_id: card_id,
+            ..
+        } => (player_id, EffectTarget::Card(card_id)),
+        GameEvent::EnergyAttached {
+            player_id,
+            pokemon_id,
+            ..
+        }
+        | GameEvent::AttackUsed {
+            player_id,
+            pokemon_id,
+            ..
+        }
+        | GameEvent::DamageDealt {
+            player_id,
+            pokemon_id,
+            ..
+        }
+        | GameEvent::PokemonKnockedOut {
+            player_id,
+            pokemon_id,
+        } => (player_id, EffectTarget::Card(pokemon_id)),
+        _ => return None,
+    };
+
+    Some(EffectContext {
+        // Replaced with the card carrying each triggered effect
+        source_card: uuid::Uuid::nil(),
+        controller,
+        target: Some(target),
+        parameters: HashMap::new(),
+        trigger: Some(trigger),
+    })
+}
+
+impl Game {
+    /// Run every registered effect whose trigger matches the event
+    ///
+    /// Effects are applied to this game with a context whose target is the
+    /// card (or player) the event concerns. Events emitted while effects are
+    /// resolving do not dispatch further triggers. Returns each effect's result.
+    pub fn dispatch_triggers_for_event(&mut self, event: &GameEvent) -> Vec<EffectResult> {
+        let Some(trigger) = trigger_for_event(event) else {
+            return Vec::new();
+        };
+        let Some(context) = context_for_event(event, trigger.clone()) else {
+            return Vec::new();
+        };
+
+        // Detach the manager while effects run so they can mutate the game
+        let mut effects = std::mem::take(&mut self.effects);
+        let results = effects.trigger_effects(self, trigger, context);
+        self.effects = effects;
+
+        results
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::card::CardId;
+    use crate::core::effects::{BaseEffect, Effect, EffectId, EffectOutcome, TargetRequirement};
+    use crate::core::game::state::{GameRules, GameState};
+    use crate::core::player::Player;
+    use crate::test_fixtures::pokemon;
+    use uuid::Uuid;
+
+    /// When the Pokemon carrying this effect is knocked out, its owner draws a card
+    #[derive(Clone)]
+    struct DrawOnKnockOut {
+        base: BaseEffect,
+    }
+
+    impl Effect for DrawOnKnockOut {
+        fn id(&self) -> EffectId {
+            self.base.id
+        }
+
+        fn name(&self) -> &str {
+            &self.base.name
+        }
+
+        fn description(&self) -> &str {
+            &self.base.description
+        }
+
+        fn can_apply(&self, _game: &Game, context: &EffectContext) -> bool {
+            context.target == Some(EffectTarget::Card(context.source_card))
+        }
+
+        fn apply(&self, game: &mut Game, context: &EffectContext) -> EffectResult {
+            let drawn = game
+                .get_player_mut(context.controller)
+                .and_then(|player| player.draw_card())
+                .is_some();
+            Ok(vec![EffectOutcome::CardsDrawn {
+                player: context.controller,
+                count: drawn as u32,
+            }])
+        }
+
+        fn triggers(&self) -> Vec<EffectTrigger> {
+            vec![EffectTrigger::OnKnockOut]
+        }
+
+        fn target_requirements(&self) -> Vec<TargetRequirement> {
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_knockout_runs_on_knockout_effect() {
+        let mut game = Game::with_rules(GameRules::default());
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let bob_id = bob.id;
+
+        let alice_active = pokemon("Magikarp", 30);
+        let bob_active = pokemon("Magikarp", 30);
+        let bob_benched = pokemon("Magikarp", 30);
+        let bob_active_id: CardId = bob_active.id;
+        alice.active_pokemon = Some(alice_active.id);
+        bob.active_pokemon = Some(bob_active_id);
+        bob.bench.push(bob_benched.id);
+        bob.set_deck(vec![Uuid::new_v4()]);
+        bob.add_damage(bob_active_id, 30);
+
+        for card in [alice_active, bob_active, bob_benched] {
+            game.add_card_to_database(card);
+        }
+        game.turn_order = vec![alice.id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.state = GameState::InProgress;
+
+        let effect_id = game.effects.register_effect(DrawOnKnockOut {
+            base: BaseEffect::new("Parting Gift".to_string(), "Draw a card".to_string()),
+        });
+        game.effects.attach_effect(bob_active_id, effect_id).unwrap();
+
+        game.process_knockouts().unwrap();
+
+        let bob = game.get_player(bob_id).unwrap();
+        assert_eq!(bob.hand.len(), 1);
+        assert!(bob.deck.is_empty());
+    }
+}
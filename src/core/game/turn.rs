@@ -3,25 +3,63 @@
 //! This module contains functions for managing game turns, including:
 //! - Starting and ending turns
 //! - Phase advancement
+//! - Turn timers
+//! - Between-turns effects (special conditions)
 //! - Win condition checking
 
-use crate::core::game::state::{Game, GameEvent, GamePhase, GameState};
+use crate::core::card::CardId;
+use crate::core::game::error::GameError;
+use crate::core::game::state::{Game, GameEvent, GamePhase, GameState, SetupPhase, TieBreak};
+use crate::core::player::{ConditionEffect, PlayerId, SpecialCondition};
+use crate::core::rules::GameAction;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Public view of one player's board for a turn summary
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerSummary {
+    pub player_id: PlayerId,
+    pub name: String,
+    pub hand_size: usize,
+    pub prize_cards: u32,
+    pub active_pokemon: Option<CardId>,
+    pub bench_size: usize,
+}
+
+/// Snapshot of the current turn for frontends and debugging
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TurnSummary {
+    pub current_player_id: PlayerId,
+    pub current_player_name: String,
+    pub turn_number: u32,
+    pub phase: GamePhase,
+    /// Energy attached from hand by the current player this turn
+    pub energy_attached_this_turn: u32,
+    /// Whether the current player has attacked this turn
+    pub has_attacked: bool,
+    /// Every player in turn order
+    pub players: Vec<PlayerSummary>,
+}
 
 impl Game {
     /// Start the game
-    pub fn start(&mut self) -> Result<(), String> {
+    pub fn start(&mut self) -> Result<(), GameError> {
         if self.state != GameState::Setup {
-            return Err("Game is not in setup state".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
+        }
+
+        if self.setup_phase.is_some_and(|phase| phase != SetupPhase::Complete) {
+            return Err(GameError::InvalidAction("Setup is not complete".to_string()));
         }
 
         if self.players.len() < 2 {
-            return Err("Need at least 2 players to start".to_string());
+            return Err(GameError::InvalidAction("Need at least 2 players to start".to_string()));
         }
 
         // Validate all players have decks
         for player in self.players.values() {
             if player.deck.is_empty() {
-                return Err("All players must have decks".to_string());
+                return Err(GameError::InvalidAction("All players must have decks".to_string()));
             }
         }
 
@@ -35,9 +73,9 @@ impl Game {
     }
 
     /// Start a new turn
-    pub fn start_turn(&mut self) -> Result<(), String> {
+    pub fn start_turn(&mut self) -> Result<(), GameError> {
         if self.state != GameState::InProgress {
-            return Err("Game is not in progress".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
 
         let current_player_id = self.get_current_player_id()?;
@@ -48,6 +86,8 @@ impl Game {
         }
 
         self.phase = GamePhase::BeginningOfTurn;
+        // The timer starts on the first clock reading of the new turn
+        self.turn_started_at = None;
         self.add_event(GameEvent::TurnStarted {
             player_id: current_player_id,
             turn_number: self.turn_number,
@@ -62,13 +102,20 @@ impl Game {
     }
 
     /// End the current turn and move to the next player
-    pub fn end_turn(&mut self) -> Result<(), String> {
+    pub fn end_turn(&mut self) -> Result<(), GameError> {
         if self.state != GameState::InProgress {
-            return Err("Game is not in progress".to_string());
+            return Err(GameError::WrongPhase(self.state.clone()));
         }
 
         let current_player_id = self.get_current_player_id()?;
 
+        let excess = self.cards_over_hand_limit(current_player_id);
+        if excess > 0 {
+            return Err(GameError::InvalidAction(
+                format!("Must discard {} card(s) before ending the turn", excess),
+            ));
+        }
+
         if let Some(player) = self.players.get_mut(&current_player_id) {
             player.end_turn();
         }
@@ -82,21 +129,84 @@ impl Game {
             return Ok(());
         }
 
-        // Move to next player
+        self.advance_to_next_player();
+        self.start_turn()?;
+
+        Ok(())
+    }
+
+    /// Pass the turn to the next player in `turn_order`
+    ///
+    /// `turn_number` counts player turns, not rounds: it goes up by one on
+    /// every transition, and this is the only place it changes during play.
+    pub(crate) fn advance_to_next_player(&mut self) {
         self.current_player_index = (self.current_player_index + 1) % self.turn_order.len();
+        self.turn_number += 1;
+    }
 
-        // Increment turn number when we complete a full round
-        if self.current_player_index == 0 {
-            self.turn_number += 1;
+    /// Number of turns `player_id` has started, including the current one
+    ///
+    /// Derived from `turn_number` and the player's seat in `turn_order`.
+    /// Zero during setup and for players outside the turn order.
+    pub fn whose_turn_count(&self, player_id: PlayerId) -> u32 {
+        if self.state == GameState::Setup {
+            return 0;
         }
+        let Some(seat) = self.turn_order.iter().position(|&id| id == player_id) else {
+            return 0;
+        };
+        let (seat, seats) = (seat as u32, self.turn_order.len() as u32);
+        if self.turn_number <= seat {
+            0
+        } else {
+            (self.turn_number - 1 - seat) / seats + 1
+        }
+    }
 
-        self.start_turn()?;
+    /// Number of cards a player must discard to meet `rules.end_turn_hand_limit`
+    pub fn cards_over_hand_limit(&self, player_id: PlayerId) -> usize {
+        match (self.rules.end_turn_hand_limit, self.players.get(&player_id)) {
+            (Some(limit), Some(player)) => player.hand.len().saturating_sub(limit),
+            _ => 0,
+        }
+    }
+
+    /// Discard the chosen cards to bring a hand down to the end-of-turn limit
+    ///
+    /// The turn cannot end while the current player holds more cards than
+    /// `rules.end_turn_hand_limit`. The player picks exactly as many distinct
+    /// cards from their hand as they are over the limit.
+    pub fn enforce_hand_limit(&mut self, player_id: PlayerId, to_discard: &[CardId]) -> Result<(), GameError> {
+        let required = self.cards_over_hand_limit(player_id);
+        let player = self
+            .players
+            .get_mut(&player_id)
+            .ok_or(GameError::PlayerNotFound)?;
 
+        if to_discard.len() != required {
+            return Err(GameError::InvalidAction(format!(
+                "Must discard exactly {} card(s), got {}",
+                required,
+                to_discard.len()
+            )));
+        }
+        for (i, card_id) in to_discard.iter().enumerate() {
+            if !player.hand.contains(card_id) {
+                return Err(GameError::InvalidAction("Card is not in hand".to_string()));
+            }
+            if to_discard[..i].contains(card_id) {
+                return Err(GameError::InvalidAction("A card was selected more than once".to_string()));
+            }
+        }
+
+        for &card_id in to_discard {
+            player.discard_from_hand(card_id);
+        }
         Ok(())
     }
 
     /// Advance to the next phase
-    pub fn next_phase(&mut self) -> Result<(), String> {
+    pub fn next_phase(&mut self) -> Result<(), GameError> {
         self.phase = match self.phase {
             GamePhase::BeginningOfTurn => GamePhase::Main,
             GamePhase::Main => GamePhase::Attack,
@@ -109,14 +219,163 @@ impl Game {
         Ok(())
     }
 
+    /// Apply between-turns effects of special conditions for every player
+    ///
+    /// Poison and burn place their damage, and the coin flips for waking up
+    /// and healing burns are made with the game RNG.
+    pub fn process_between_turns(&mut self) {
+        let mut player_ids: Vec<PlayerId> = self.players.keys().copied().collect();
+        player_ids.sort();
+
+        for player_id in player_ids {
+            let turn_number = self.turn_number;
+            let effects = match self.players.get_mut(&player_id) {
+                Some(player) => player.update_special_conditions(turn_number),
+                None => continue,
+            };
+
+            for effect in effects {
+                match effect {
+                    ConditionEffect::Damage {
+                        pokemon_id, amount, ..
+                    } => {
+                        if let Some(player) = self.players.get_mut(&player_id) {
+                            player.add_damage(pokemon_id, amount);
+                        }
+                        self.add_event(GameEvent::DamageDealt {
+                            player_id,
+                            pokemon_id,
+                            damage: amount,
+                        });
+                    }
+                    ConditionEffect::CoinFlip {
+                        pokemon_id,
+                        condition,
+                        ..
+                    } => {
+                        let removed = match condition.as_str() {
+                            "Burn removal" => SpecialCondition::Burned { damage_per_turn: 0 },
+                            "Wake up" => SpecialCondition::Asleep,
+                            _ => continue,
+                        };
+                        if self.rng.flip_coin()
+                            && let Some(player) = self.players.get_mut(&player_id)
+                        {
+                            player.remove_special_condition_type(pokemon_id, &removed);
+                        }
+                    }
+                    ConditionEffect::ConditionRemoved { .. }
+                    | ConditionEffect::PreventAction { .. } => {}
+                }
+            }
+        }
+    }
+
+    /// Start the current turn's timer at the given time (in seconds)
+    pub fn start_turn_timer(&mut self, now: u64) {
+        self.turn_started_at = Some(now);
+    }
+
+    /// Check whether the current turn has exceeded `rules.turn_time_limit`
+    ///
+    /// The clock is supplied by the caller so the check stays deterministic.
+    /// If the timer has not been started this turn, it starts at `now`.
+    /// Returns the `EndTurn` action to apply on behalf of the current player
+    /// once the limit is reached, or `None` otherwise. A player whose time
+    /// bank is empty has lost on time: the result is then `Concede`, which
+    /// ends the game instead of passing every later turn.
+    pub fn check_turn_timeout(&mut self, now: u64) -> Option<GameAction> {
+        if self.state != GameState::InProgress {
+            return None;
+        }
+        let player_id = self.get_current_player_id().ok()?;
+        if self.time_remaining(player_id) == Some(Duration::ZERO) {
+            return Some(GameAction::Concede { player_id });
+        }
+
+        let limit = self.rules.turn_time_limit?;
+        let started_at = *self.turn_started_at.get_or_insert(now);
+        if now.saturating_sub(started_at) < limit as u64 {
+            return None;
+        }
+
+        Some(GameAction::EndTurn { player_id })
+    }
+
+    /// Thinking time a player has left, or `None` without `rules.time_bank`
+    ///
+    /// Players who have not spent any time yet have the full bank.
+    pub fn time_remaining(&self, player_id: PlayerId) -> Option<Duration> {
+        let bank = Duration::from_secs(self.rules.time_bank?.into());
+        Some(self.time_banks.get(&player_id).copied().unwrap_or(bank))
+    }
+
+    /// Deduct time a player spent during their turn from their time bank
+    ///
+    /// Called by the turn driver with the time elapsed since its last clock
+    /// reading. Returns whether the bank is now empty, in which case
+    /// `check_turn_timeout` has the player concede. Does nothing without
+    /// `rules.time_bank`.
+    pub fn consume_time(&mut self, player_id: PlayerId, elapsed: Duration) -> bool {
+        let Some(remaining) = self.time_remaining(player_id) else {
+            return false;
+        };
+        let remaining = remaining.saturating_sub(elapsed);
+        self.time_banks.insert(player_id, remaining);
+        remaining.is_zero()
+    }
+
+    /// Summarize the current turn
+    pub fn current_turn_summary(&self) -> Result<TurnSummary, GameError> {
+        let current = self.get_current_player()?;
+        let players = self
+            .turn_order
+            .iter()
+            .filter_map(|player_id| self.players.get(player_id))
+            .map(|player| PlayerSummary {
+                player_id: player.id,
+                name: player.name.clone(),
+                hand_size: player.hand.len(),
+                prize_cards: player.prize_cards,
+                active_pokemon: player.active_pokemon,
+                bench_size: player.bench.len(),
+            })
+            .collect();
+
+        Ok(TurnSummary {
+            current_player_id: current.id,
+            current_player_name: current.name.clone(),
+            turn_number: self.turn_number,
+            phase: self.phase.clone(),
+            energy_attached_this_turn: current.energy_attached_this_turn,
+            has_attacked: current.has_attacked,
+            players,
+        })
+    }
+
+    /// Whether the first player's opening-turn restrictions apply right now
+    ///
+    /// They cover the first player's turns up to
+    /// `rules.first_turn_number_threshold`.
+    pub fn first_player_restricted(&self) -> bool {
+        self.current_player_index == 0 && self.turn_number <= self.rules.first_turn_number_threshold
+    }
+
     /// Check for win conditions
-    pub fn check_win_conditions(&mut self) -> Result<bool, String> {
-        let mut winner = None;
+    ///
+    /// If more than one player meets a win condition at once, the game is
+    /// resolved by `rules.tiebreak`. The `GameEnded` event records why the
+    /// game ended.
+    pub fn check_win_conditions(&mut self) -> Result<bool, GameError> {
+        let mut player_ids: Vec<PlayerId> = self.players.keys().copied().collect();
+        player_ids.sort();
 
-        for (&player_id, player) in &self.players {
+        let mut winners = Vec::new();
+        for &player_id in &player_ids {
+            let player = &self.players[&player_id];
             if player.has_won() {
-                winner = Some(player_id);
-                break;
+                winners.push((player_id, "All prize cards taken"));
+                continue;
             }
 
             // Check if opponent has lost
@@ -126,21 +385,326 @@ impl Game {
                 .any(|p| p.id != player_id && p.has_lost());
 
             if opponent_lost {
-                winner = Some(player_id);
-                break;
+                winners.push((player_id, "Opponent has no Pokemon in play"));
             }
         }
 
-        if let Some(winner_id) = winner {
-            self.state = GameState::Finished {
-                winner: Some(winner_id),
-            };
-            self.add_event(GameEvent::GameEnded {
-                winner: Some(winner_id),
-            });
-            return Ok(true);
+        let (winner, reason) = match winners.as_slice() {
+            [] => return Ok(false),
+            [(winner_id, reason)] => (Some(*winner_id), reason.to_string()),
+            _ => self.resolve_tie(&winners),
+        };
+
+        self.state = GameState::Finished { winner };
+        self.add_event(GameEvent::GameEnded { winner, reason });
+        Ok(true)
+    }
+
+    /// Restart a game that ended without a winner as a one-prize sudden death game
+    ///
+    /// Each player's cards go back into their deck, which is shuffled, and
+    /// `rules.prize_cards` drops to 1. The game returns to `GameState::Setup`,
+    /// so the usual setup steps run again. Cards still in the prize pile go
+    /// back into the deck too, so each player restarts with their full deck.
+    pub fn start_sudden_death(&mut self) -> Result<(), GameError> {
+        if self.state != (GameState::Finished { winner: None }) {
+            return Err(GameError::InvalidAction(
+                "Sudden death needs a game that ended without a winner".to_string(),
+            ));
+        }
+
+        self.rules.prize_cards = 1;
+        let mut player_ids: Vec<PlayerId> = self.players.keys().copied().collect();
+        player_ids.sort();
+        for player_id in player_ids {
+            if let Some(player) = self.players.get_mut(&player_id) {
+                player.return_all_cards_to_deck();
+                player.prize_cards = self.rules.prize_cards;
+                player.shuffle_deck_with(&mut self.rng);
+            }
+            self.add_event(GameEvent::DeckShuffled { player_id });
+        }
+
+        self.state = GameState::Setup;
+        self.setup_phase = Some(SetupPhase::DeterminingTurnOrder);
+        self.phase = GamePhase::BeginningOfTurn;
+        self.turn_number = 1;
+        self.current_player_index = 0;
+        self.turn_started_at = None;
+        self.time_banks.clear();
+        self.pending_promotions.clear();
+        self.pending_choices.clear();
+        self.players_waiting_for_mulligan.clear();
+        self.mulligan_counts.clear();
+        Ok(())
+    }
+
+    /// Pick the winner when several players meet a win condition at once
+    fn resolve_tie(&self, winners: &[(PlayerId, &str)]) -> (Option<PlayerId>, String) {
+        match self.rules.tiebreak {
+            TieBreak::TurnPlayerWins => {
+                let turn_player = self
+                    .get_current_player_id()
+                    .ok()
+                    .filter(|id| winners.iter().any(|(winner_id, _)| winner_id == id));
+                match turn_player {
+                    Some(player_id) => (
+                        Some(player_id),
+                        "Both players won at once; the turn player wins".to_string(),
+                    ),
+                    None => (None, "Both players won at once; draw".to_string()),
+                }
+            }
+            TieBreak::SuddenDeath => (None, "Both players won at once; sudden death".to_string()),
+            TieBreak::Draw => (None, "Both players won at once; draw".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game::state::GameRules;
+    use crate::core::player::Player;
+
+    #[test]
+    fn test_turn_timeout_returns_end_turn() {
+        let rules = GameRules::builder().turn_time_limit(Some(30)).build().unwrap();
+        let mut game = Game::with_rules(rules);
+        let alice = Player::new("Alice".to_string());
+        let bob = Player::new("Bob".to_string());
+        let alice_id = alice.id;
+        game.turn_order = vec![alice_id, bob.id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.state = GameState::InProgress;
+
+        game.start_turn_timer(1_000);
+        assert_eq!(game.check_turn_timeout(1_010), None);
+        assert_eq!(game.check_turn_timeout(1_029), None);
+        assert_eq!(
+            game.check_turn_timeout(1_031),
+            Some(GameAction::EndTurn { player_id: alice_id })
+        );
+    }
+
+    #[test]
+    fn test_time_bank_runs_out() {
+        let rules = GameRules::builder().time_bank(Some(60)).build().unwrap();
+        let mut game = Game::with_rules(rules);
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+        for player in [&mut alice, &mut bob] {
+            player.active_pokemon = Some(uuid::Uuid::new_v4());
+            player.set_deck((0..5).map(|_| uuid::Uuid::new_v4()).collect());
         }
+        game.turn_order = vec![alice_id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.state = GameState::InProgress;
+
+        assert_eq!(game.time_remaining(alice_id), Some(Duration::from_secs(60)));
+        assert!(!game.consume_time(alice_id, Duration::from_secs(45)));
+        assert_eq!(game.time_remaining(alice_id), Some(Duration::from_secs(15)));
+        assert_eq!(game.time_remaining(bob_id), Some(Duration::from_secs(60)));
+        assert_eq!(game.check_turn_timeout(0), None);
+
+        assert!(game.consume_time(alice_id, Duration::from_secs(20)));
+        assert_eq!(game.time_remaining(alice_id), Some(Duration::ZERO));
+        assert_eq!(
+            game.check_turn_timeout(0),
+            Some(GameAction::Concede { player_id: alice_id })
+        );
+
+        // Bob still has time; on Alice's next turn her empty bank still loses
+        game.end_turn().unwrap();
+        assert_eq!(game.check_turn_timeout(0), None);
+        game.end_turn().unwrap();
+        assert_eq!(game.get_current_player_id(), Ok(alice_id));
+        assert_eq!(
+            game.check_turn_timeout(0),
+            Some(GameAction::Concede { player_id: alice_id })
+        );
+
+        let untimed = Game::new();
+        assert_eq!(untimed.time_remaining(alice_id), None);
+    }
+
+    #[test]
+    fn test_turn_number_counts_player_turns() {
+        let mut game = Game::new();
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+        for player in [&mut alice, &mut bob] {
+            player.active_pokemon = Some(uuid::Uuid::new_v4());
+            player.set_deck((0..10).map(|_| uuid::Uuid::new_v4()).collect());
+        }
+        game.turn_order = vec![alice_id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        assert_eq!(game.whose_turn_count(alice_id), 0);
+
+        game.start().unwrap();
+        assert_eq!(game.turn_number, 1);
+        assert_eq!((game.whose_turn_count(alice_id), game.whose_turn_count(bob_id)), (1, 0));
+
+        // Both ways of ending a turn advance the counter by one
+        let engine = crate::core::rules::StandardRules::create_engine();
+        game.end_turn().unwrap();
+        assert_eq!(game.turn_number, 2);
+        game.execute_action(&engine, &GameAction::EndTurn { player_id: bob_id })
+            .unwrap();
+        assert_eq!(game.turn_number, 3);
+        game.end_turn().unwrap();
+        game.end_turn().unwrap();
+        assert_eq!(game.turn_number, 5);
+        assert_eq!(game.get_current_player_id(), Ok(alice_id));
+        assert_eq!((game.whose_turn_count(alice_id), game.whose_turn_count(bob_id)), (3, 2));
+
+        let started: Vec<u32> = game
+            .get_history()
+            .iter()
+            .filter_map(|event| match event {
+                GameEvent::TurnStarted { turn_number, .. } => Some(*turn_number),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(started, vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_poison_damage_between_turns() {
+        let mut game = Game::new();
+        let mut alice = Player::new("Alice".to_string());
+        let alice_id = alice.id;
+        let pokemon_id = uuid::Uuid::new_v4();
+        alice.active_pokemon = Some(pokemon_id);
+        alice.add_special_condition(pokemon_id, SpecialCondition::Poisoned { damage_per_turn: 10 }, 0, 0);
+        game.add_player(alice).unwrap();
+
+        game.process_between_turns();
+        game.process_between_turns();
+
+        let alice = game.get_player(alice_id).unwrap();
+        assert_eq!(alice.damage_counters.get(&pokemon_id), Some(&20));
+    }
+
+    #[test]
+    fn test_end_turn_hand_limit() {
+        let rules = GameRules::builder().end_turn_hand_limit(Some(7)).build().unwrap();
+        let mut game = Game::with_rules(rules);
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let alice_id = alice.id;
+        let hand: Vec<CardId> = (0..9).map(|_| uuid::Uuid::new_v4()).collect();
+        alice.hand = hand.clone();
+        alice.active_pokemon = Some(uuid::Uuid::new_v4());
+        bob.active_pokemon = Some(uuid::Uuid::new_v4());
+        game.turn_order = vec![alice_id, bob.id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.state = GameState::InProgress;
+
+        assert_eq!(game.cards_over_hand_limit(alice_id), 2);
+        assert!(game.end_turn().is_err());
+        assert!(game.enforce_hand_limit(alice_id, &hand[..1]).is_err());
+        assert!(game.enforce_hand_limit(alice_id, &hand[..3]).is_err());
+        assert!(game.enforce_hand_limit(alice_id, &[hand[0], hand[0]]).is_err());
+        assert_eq!(game.get_player(alice_id).unwrap().hand.len(), 9);
+
+        game.enforce_hand_limit(alice_id, &hand[..2]).unwrap();
+        let alice = game.get_player(alice_id).unwrap();
+        assert_eq!(alice.hand, hand[2..]);
+        assert_eq!(alice.discard_pile, hand[..2]);
+        assert!(game.end_turn().is_ok());
+    }
+
+    #[test]
+    fn test_current_turn_summary() {
+        let mut game = Game::new();
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+        let bob_active = uuid::Uuid::new_v4();
+        alice.hand = vec![uuid::Uuid::new_v4(); 3];
+        bob.active_pokemon = Some(bob_active);
+        game.turn_order = vec![bob_id, alice_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        assert!(Game::new().current_turn_summary().is_err());
+
+        game.current_player_index = 1;
+        game.phase = GamePhase::Main;
+        game.get_player_mut(alice_id).unwrap().energy_attached_this_turn = 1;
+        game.get_player_mut(bob_id).unwrap().prize_cards = 4;
+
+        let summary = game.current_turn_summary().unwrap();
+        assert_eq!(summary.current_player_id, alice_id);
+        assert_eq!(summary.current_player_name, "Alice");
+        assert_eq!(summary.phase, GamePhase::Main);
+        assert_eq!(summary.energy_attached_this_turn, 1);
+        assert!(!summary.has_attacked);
+        assert_eq!(summary.players.len(), 2);
+        assert_eq!(summary.players[0].player_id, bob_id);
+        assert_eq!(summary.players[0].prize_cards, 4);
+        assert_eq!(summary.players[0].active_pokemon, Some(bob_active));
+        assert_eq!(summary.players[1].hand_size, 3);
+    }
+
+    #[test]
+    fn test_simultaneous_knockout_leads_to_sudden_death() {
+        let rules = GameRules::builder().tiebreak(TieBreak::SuddenDeath).build().unwrap();
+        let mut game = Game::with_rules(rules);
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+        for player in [&mut alice, &mut bob] {
+            player.set_deck((0..10).map(|_| uuid::Uuid::new_v4()).collect());
+            player.draw_cards(3);
+            player.draw_prize_cards(2);
+            player.prize_cards = 2;
+        }
+        // Alice took one prize; Bob's prizes are untouched
+        assert!(alice.take_prize_card());
+        alice.discard_pile.push(uuid::Uuid::new_v4());
+        game.turn_order = vec![alice_id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.state = GameState::InProgress;
+
+        // Both players' last Pokemon were knocked out
+        assert!(game.start_sudden_death().is_err());
+        assert!(game.check_win_conditions().unwrap());
+        assert_eq!(game.state, GameState::Finished { winner: None });
+
+        game.start_sudden_death().unwrap();
+        assert_eq!(game.state, GameState::Setup);
+        assert_eq!(game.rules.prize_cards, 1);
+        // Both decks are back to their original size, prizes included
+        assert_eq!(game.get_player(alice_id).unwrap().deck.len(), 11);
+        assert_eq!(game.get_player(bob_id).unwrap().deck.len(), 10);
+        for player_id in [alice_id, bob_id] {
+            let player = game.get_player(player_id).unwrap();
+            assert!(player.hand.is_empty() && player.discard_pile.is_empty());
+            assert!(player.prize_pile.is_empty());
+        }
+        assert_eq!(game.setup_phase, Some(SetupPhase::DeterminingTurnOrder));
+
+        game.setup_phase = Some(SetupPhase::SelectingActivePokemon);
+        game.place_prize_cards().unwrap();
+        for player_id in [alice_id, bob_id] {
+            assert_eq!(game.get_player(player_id).unwrap().prize_cards, 1);
+        }
+    }
+
+    #[test]
+    fn test_no_timeout_without_limit() {
+        let mut game = Game::new();
+        game.state = GameState::InProgress;
+        game.start_turn_timer(0);
 
-        Ok(false)
+        assert_eq!(game.check_turn_timeout(u64::MAX), None);
     }
 }
@@ -5,10 +5,12 @@
 pub mod state;
 pub mod conditions;
 pub mod actions;
+pub mod view;
 
 // 重新导出常用类型
 pub use state::*;
 pub use conditions::*;
+pub use view::*;
 
 #[cfg(test)]
 mod tests {
@@ -41,7 +41,7 @@ pub enum ConditionEffect {
     PreventAction { pokemon_id: CardId, action: String },
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum SpecialCondition {
     /// Pokemon is poisoned (takes damage between turns)
     Poisoned {
@@ -75,4 +75,5 @@ pub enum CardLocation {
     Bench(usize), // Index on the bench
     Prizes,
     AttachedEnergy(CardId), // Attached to the specified Pokemon
+    LostZone,
 }
\ No newline at end of file
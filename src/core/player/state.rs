@@ -1,14 +1,31 @@
 //! Player state management
 
 use crate::core::card::{CardId, Card, EnergyType};
+use crate::core::effects::EffectOutcome;
 use crate::core::player::{SpecialConditionInstance, CardLocation};
+use rand::Rng;
+use rand::seq::SliceRandom;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use uuid::Uuid;
 
 /// Unique identifier for a player
 pub type PlayerId = Uuid;
 
+/// Remove the entries whose Pokemon is not in play, returning their values in key order
+fn take_orphaned<V>(map: &mut HashMap<CardId, V>, in_play: &HashSet<CardId>) -> Vec<V> {
+    let mut orphaned: Vec<CardId> = map.keys().filter(|id| !in_play.contains(id)).copied().collect();
+    orphaned.sort();
+    orphaned.into_iter().filter_map(|id| map.remove(&id)).collect()
+}
+
+/// Prefix of marker names that are cleared when their owner's next turn starts
+///
+/// Marker names should be `"<card or effect name>:<marker>"` so effects do
+/// not collide, with this prefix in front for turn-scoped markers, e.g.
+/// `"turn:Crabominable:used_attack"`.
+pub const TURN_MARKER_PREFIX: &str = "turn:";
+
 /// Represents a player in the game
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Player {
@@ -18,6 +35,8 @@ pub struct Player {
     pub name: String,
     /// Player's current life/prize cards remaining
     pub prize_cards: u32,
+    /// Cards set aside face down as prize cards
+    pub prize_pile: Vec<CardId>,
     /// Cards currently in hand
     pub hand: Vec<CardId>,
     /// Active Pokemon on the field
@@ -26,20 +45,38 @@ pub struct Player {
     pub bench: Vec<CardId>,
     /// Cards in the discard pile
     pub discard_pile: Vec<CardId>,
+    /// Cards removed from the game for good (the Lost Zone)
+    pub lost_zone: Vec<CardId>,
     /// Cards in the deck
     pub deck: Vec<CardId>,
     /// Energy cards attached to Pokemon
     pub attached_energy: HashMap<CardId, Vec<CardId>>,
+    /// Pokemon Tool attached to each Pokemon
+    pub attached_tools: HashMap<CardId, CardId>,
+    /// Cards underneath each evolved Pokemon in play, Basic first
+    pub evolution_stacks: HashMap<CardId, Vec<CardId>>,
     /// Damage counters on Pokemon
     pub damage_counters: HashMap<CardId, u32>,
     /// Player's current turn status
     pub has_attacked: bool,
     /// Whether the player can still play trainer cards this turn
     pub can_play_trainer: bool,
+    /// Whether the player has retreated this turn
+    pub has_retreated: bool,
+    /// Whether the player has played a Supporter this turn
+    pub supporter_played_this_turn: bool,
+    /// Energy cards attached from hand this turn
+    pub energy_attached_this_turn: u32,
     /// Stadium card in play (if any)
     pub stadium: Option<CardId>,
     /// Special conditions affecting Pokemon
     pub special_conditions: HashMap<CardId, Vec<SpecialConditionInstance>>,
+    /// Cards in hidden zones that have been shown to every player
+    pub revealed: HashSet<CardId>,
+    /// Last turn number on which each Pokemon is shielded from the effects of attacks
+    pub protected_until_turn: HashMap<CardId, u32>,
+    /// Named counters and flags that card effects keep on each Pokemon
+    pub markers: HashMap<CardId, HashMap<String, i64>>,
 }
 
 impl Player {
@@ -49,17 +86,27 @@ impl Player {
             id: Uuid::new_v4(),
             name,
             prize_cards: 6, // Standard game starts with 6 prize cards
+            prize_pile: Vec::new(),
             hand: Vec::new(),
             active_pokemon: None,
             bench: Vec::new(),
             discard_pile: Vec::new(),
+            lost_zone: Vec::new(),
             deck: Vec::new(),
             attached_energy: HashMap::new(),
+            attached_tools: HashMap::new(),
+            evolution_stacks: HashMap::new(),
             damage_counters: HashMap::new(),
             has_attacked: false,
             can_play_trainer: true,
+            has_retreated: false,
+            supporter_played_this_turn: false,
+            energy_attached_this_turn: 0,
             stadium: None,
             special_conditions: HashMap::new(),
+            revealed: HashSet::new(),
+            protected_until_turn: HashMap::new(),
+            markers: HashMap::new(),
         }
     }
 
@@ -68,6 +115,56 @@ impl Player {
         self.deck = deck;
     }
 
+    /// Make a card in a hidden zone (such as the hand) public knowledge
+    pub fn reveal_card(&mut self, card_id: CardId) {
+        self.revealed.insert(card_id);
+    }
+
+    /// Whether a card has been revealed to every player
+    pub fn is_revealed(&self, card_id: CardId) -> bool {
+        self.revealed.contains(&card_id)
+    }
+
+    /// Shield a Pokemon from the effects of attacks through `until_turn`
+    pub fn protect_pokemon(&mut self, pokemon_id: CardId, until_turn: u32) {
+        self.protected_until_turn.insert(pokemon_id, until_turn);
+    }
+
+    /// Whether a Pokemon is shielded from the effects of attacks on `current_turn`
+    pub fn is_protected(&self, pokemon_id: CardId, current_turn: u32) -> bool {
+        self.protected_until_turn
+            .get(&pokemon_id)
+            .is_some_and(|&until_turn| current_turn <= until_turn)
+    }
+
+    /// Set a marker on a Pokemon, replacing any previous value
+    ///
+    /// See `TURN_MARKER_PREFIX` for how to name markers.
+    pub fn set_marker(&mut self, pokemon_id: CardId, name: &str, value: i64) {
+        self.markers
+            .entry(pokemon_id)
+            .or_default()
+            .insert(name.to_string(), value);
+    }
+
+    /// Value of a marker on a Pokemon, if set
+    pub fn get_marker(&self, pokemon_id: CardId, name: &str) -> Option<i64> {
+        self.markers.get(&pokemon_id)?.get(name).copied()
+    }
+
+    /// Remove every marker on a Pokemon
+    pub fn clear_markers_for_pokemon(&mut self, pokemon_id: CardId) {
+        self.markers.remove(&pokemon_id);
+    }
+
+    /// Remove the turn-scoped markers (named with `TURN_MARKER_PREFIX`)
+    pub fn clear_turn_markers(&mut self) {
+        for markers in self.markers.values_mut() {
+            markers.retain(|name, _| !name.starts_with(TURN_MARKER_PREFIX));
+        }
+        self.markers.retain(|_, markers| !markers.is_empty());
+    }
+
     /// Draw a card from the deck to hand
     pub fn draw_card(&mut self) -> Option<CardId> {
         if let Some(card_id) = self.deck.pop() {
@@ -91,23 +188,89 @@ impl Player {
         drawn
     }
 
-    /// Shuffle the player's deck
-    pub fn shuffle_deck(&mut self) {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
+    /// Shuffle the player's deck using the given random number generator
+    ///
+    /// Pass the game's `GameRng` so shuffles are reproducible from its seed.
+    pub fn shuffle_deck_with<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.deck.shuffle(rng);
+    }
+
+    /// Shuffle the whole discard pile into the deck
+    pub fn shuffle_discard_into_deck<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        self.deck.append(&mut self.discard_pile);
+        self.shuffle_deck_with(rng);
+    }
 
-        // Simple shuffle algorithm (in a real implementation, you'd use a proper RNG)
-        let mut hasher = DefaultHasher::new();
-        self.id.hash(&mut hasher);
-        let seed = hasher.finish();
+    /// Shuffle the whole hand into the deck, returning how many cards went back
+    ///
+    /// Trainer effects that refresh the hand follow this with `draw_cards`.
+    pub fn shuffle_hand_into_deck<R: Rng + ?Sized>(&mut self, rng: &mut R) -> usize {
+        let count = self.hand.len();
+        self.deck.append(&mut self.hand);
+        self.shuffle_deck_with(rng);
+        count
+    }
 
-        // Fisher-Yates shuffle with simple PRNG
-        for i in (1..self.deck.len()).rev() {
-            let j = (seed.wrapping_mul(i as u64 + 1)) % (i as u64 + 1);
-            self.deck.swap(i, j as usize);
+    /// Discard the whole hand, returning how many cards were discarded
+    pub fn discard_hand(&mut self) -> usize {
+        let count = self.hand.len();
+        self.discard_pile.append(&mut self.hand);
+        count
+    }
+
+    /// Put a specific card from the discard pile into hand
+    pub fn recover_from_discard(&mut self, card_id: CardId) -> bool {
+        if let Some(pos) = self.discard_pile.iter().position(|&id| id == card_id) {
+            self.discard_pile.remove(pos);
+            self.hand.push(card_id);
+            true
+        } else {
+            false
         }
     }
 
+    /// Send a card to the Lost Zone from the given location
+    ///
+    /// A Pokemon sent from play leaves its attached energy and tool in the
+    /// discard pile. Cards in the Lost Zone cannot be recovered. Returns
+    /// false if the card is not at `from`.
+    pub fn send_to_lost_zone(&mut self, card_id: CardId, from: CardLocation) -> bool {
+        let remove = |zone: &mut Vec<CardId>| {
+            zone.iter()
+                .position(|&id| id == card_id)
+                .map(|pos| zone.remove(pos))
+                .is_some()
+        };
+
+        let removed = match from {
+            CardLocation::Hand => remove(&mut self.hand),
+            CardLocation::Deck => remove(&mut self.deck),
+            CardLocation::DiscardPile => remove(&mut self.discard_pile),
+            CardLocation::Active | CardLocation::Bench(_) => {
+                let in_place = match from {
+                    CardLocation::Active => self.active_pokemon == Some(card_id),
+                    _ => self.bench.contains(&card_id),
+                };
+                in_place && self.discard_from_field(card_id) && remove(&mut self.discard_pile)
+            }
+            CardLocation::AttachedEnergy(pokemon_id) => self
+                .attached_energy
+                .get_mut(&pokemon_id)
+                .is_some_and(remove),
+            CardLocation::Prizes | CardLocation::LostZone => false,
+        };
+
+        if removed {
+            self.lost_zone.push(card_id);
+        }
+        removed
+    }
+
+    /// Number of cards in the Lost Zone
+    pub fn lost_zone_count(&self) -> usize {
+        self.lost_zone.len()
+    }
+
     /// Move a card from hand to discard pile
     pub fn discard_from_hand(&mut self, card_id: CardId) -> bool {
         if let Some(pos) = self.hand.iter().position(|&id| id == card_id) {
@@ -119,6 +282,247 @@ impl Player {
         }
     }
 
+    /// Discard up to `count` energy cards attached to a Pokemon
+    ///
+    /// Energy is discarded in attachment order. Returns the discarded card IDs.
+    pub fn discard_attached_energy(&mut self, pokemon_id: CardId, count: usize) -> Vec<CardId> {
+        let mut discarded = Vec::new();
+
+        if let Some(energy) = self.attached_energy.get_mut(&pokemon_id) {
+            let take = count.min(energy.len());
+            discarded.extend(energy.drain(..take));
+            if energy.is_empty() {
+                self.attached_energy.remove(&pokemon_id);
+            }
+        }
+
+        self.discard_pile.extend(discarded.iter().copied());
+        discarded
+    }
+
+    /// Pay an energy cost by discarding the chosen energy from a Pokemon
+    ///
+    /// Every chosen card must be attached to `pokemon_id` and chosen once.
+    /// Nothing is discarded if any choice is invalid.
+    pub fn pay_energy_cost(&mut self, pokemon_id: CardId, to_discard: &[CardId]) -> Result<(), String> {
+        let attached = self.attached_energy.get(&pokemon_id).map(Vec::as_slice).unwrap_or_default();
+        for (i, energy_id) in to_discard.iter().enumerate() {
+            if !attached.contains(energy_id) {
+                return Err(format!("Energy {} is not attached to this Pokemon", energy_id));
+            }
+            if to_discard[..i].contains(energy_id) {
+                return Err(format!("Energy {} was chosen more than once", energy_id));
+            }
+        }
+
+        if let Some(energy) = self.attached_energy.get_mut(&pokemon_id) {
+            energy.retain(|id| !to_discard.contains(id));
+            if energy.is_empty() {
+                self.attached_energy.remove(&pokemon_id);
+            }
+        }
+        self.discard_pile.extend_from_slice(to_discard);
+        Ok(())
+    }
+
+    /// Put an evolution card from hand on top of a Pokemon in play
+    ///
+    /// The evolved Pokemon takes the place of `pokemon_id` and keeps its
+    /// energy, tool and damage; `pokemon_id` joins the evolution stack.
+    /// Evolving removes special conditions, markers and protection from attacks.
+    /// Returns false if the card is not in hand or the Pokemon is not in play.
+    pub fn evolve_pokemon(&mut self, pokemon_id: CardId, evolution_id: CardId) -> bool {
+        let Some(hand_pos) = self.hand.iter().position(|&id| id == evolution_id) else {
+            return false;
+        };
+        if self.active_pokemon == Some(pokemon_id) {
+            self.active_pokemon = Some(evolution_id);
+        } else if let Some(slot) = self.bench.iter_mut().find(|id| **id == pokemon_id) {
+            *slot = evolution_id;
+        } else {
+            return false;
+        }
+        self.hand.remove(hand_pos);
+
+        let mut stack = self.evolution_stacks.remove(&pokemon_id).unwrap_or_default();
+        stack.push(pokemon_id);
+        self.evolution_stacks.insert(evolution_id, stack);
+        if let Some(energy) = self.attached_energy.remove(&pokemon_id) {
+            self.attached_energy.insert(evolution_id, energy);
+        }
+        if let Some(tool) = self.attached_tools.remove(&pokemon_id) {
+            self.attached_tools.insert(evolution_id, tool);
+        }
+        if let Some(damage) = self.damage_counters.remove(&pokemon_id) {
+            self.damage_counters.insert(evolution_id, damage);
+        }
+        self.special_conditions.remove(&pokemon_id);
+        self.protected_until_turn.remove(&pokemon_id);
+        self.clear_markers_for_pokemon(pokemon_id);
+        true
+    }
+
+    /// The cards a Pokemon in play evolved from, Basic first
+    ///
+    /// Empty for a Pokemon that has not evolved or is not in play.
+    pub fn evolution_stack(&self, top: CardId) -> &[CardId] {
+        self.evolution_stacks.get(&top).map_or(&[], Vec::as_slice)
+    }
+
+    /// Move a card in play to the discard pile
+    ///
+    /// Works for the active Pokemon, benched Pokemon and attached energy. When a
+    /// Pokemon is discarded, the cards it evolved from, its attached energy,
+    /// tool, damage and special conditions go with it.
+    pub fn discard_from_field(&mut self, card_id: CardId) -> bool {
+        let was_active = self.active_pokemon == Some(card_id);
+        let bench_pos = self.bench.iter().position(|&id| id == card_id);
+
+        if was_active || bench_pos.is_some() {
+            if was_active {
+                self.active_pokemon = None;
+            } else if let Some(pos) = bench_pos {
+                self.bench.remove(pos);
+            }
+
+            if let Some(stack) = self.evolution_stacks.remove(&card_id) {
+                self.discard_pile.extend(stack);
+            }
+            if let Some(energy) = self.attached_energy.remove(&card_id) {
+                self.discard_pile.extend(energy);
+            }
+            if let Some(tool) = self.attached_tools.remove(&card_id) {
+                self.discard_pile.push(tool);
+            }
+            self.damage_counters.remove(&card_id);
+            self.special_conditions.remove(&card_id);
+            self.protected_until_turn.remove(&card_id);
+            self.clear_markers_for_pokemon(card_id);
+            self.discard_pile.push(card_id);
+            return true;
+        }
+
+        // Check if it's attached energy
+        let holder = self
+            .attached_energy
+            .iter()
+            .find(|(_, energy)| energy.contains(&card_id))
+            .map(|(&pokemon_id, _)| pokemon_id);
+
+        if let Some(pokemon_id) = holder
+            && let Some(energy) = self.attached_energy.get_mut(&pokemon_id)
+        {
+            energy.retain(|&id| id != card_id);
+            if energy.is_empty() {
+                self.attached_energy.remove(&pokemon_id);
+            }
+            self.discard_pile.push(card_id);
+            return true;
+        }
+
+        false
+    }
+
+    /// Find all cards in the deck matching a predicate
+    pub fn search_deck<F: Fn(&Card) -> bool>(
+        &self,
+        card_database: &HashMap<CardId, Card>,
+        predicate: F,
+    ) -> Vec<CardId> {
+        self.deck
+            .iter()
+            .filter(|&card_id| card_database.get(card_id).is_some_and(&predicate))
+            .copied()
+            .collect()
+    }
+
+    /// Move a specific card from the deck to hand
+    ///
+    /// The deck should be shuffled afterwards; see `Game::tutor`.
+    pub fn move_from_deck_to_hand(&mut self, card_id: CardId) -> bool {
+        if let Some(pos) = self.deck.iter().position(|&id| id == card_id) {
+            self.deck.remove(pos);
+            self.hand.push(card_id);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Discard cards from the top of the deck (mill)
+    ///
+    /// Returns the discarded cards, top card first. Stops early if the deck
+    /// runs out.
+    pub fn discard_top_of_deck(&mut self, n: usize) -> Vec<CardId> {
+        let mut milled = Vec::new();
+        for _ in 0..n {
+            if let Some(card_id) = self.deck.pop() {
+                self.discard_pile.push(card_id);
+                milled.push(card_id);
+            } else {
+                break;
+            }
+        }
+        milled
+    }
+
+    /// Look at the top `n` cards of the deck without moving them
+    ///
+    /// Returns the cards top card first; fewer if the deck is smaller.
+    pub fn peek_top(&self, n: usize) -> Vec<CardId> {
+        self.deck.iter().rev().take(n).copied().collect()
+    }
+
+    /// Put the top cards of the deck back in a new order
+    ///
+    /// `new_order` lists the cards top card first and must be a permutation of
+    /// `peek_top(new_order.len())`.
+    pub fn reorder_top(&mut self, new_order: Vec<CardId>) -> Result<(), String> {
+        let mut current = self.peek_top(new_order.len());
+        if current.len() != new_order.len() {
+            return Err("Not enough cards in deck".to_string());
+        }
+
+        let mut proposed = new_order.clone();
+        current.sort();
+        proposed.sort();
+        if current != proposed {
+            return Err("New order is not a permutation of the top cards".to_string());
+        }
+
+        let start = self.deck.len() - new_order.len();
+        self.deck.truncate(start);
+        self.deck.extend(new_order.into_iter().rev());
+        Ok(())
+    }
+
+    /// Search the deck for up to `max` cards matching a predicate and put them
+    /// into hand, then shuffle the deck with `rng`
+    ///
+    /// Only the cards taken are returned, so the rest of the deck's contents
+    /// stay hidden from the caller. During a game use `Game::search_deck_to_hand`,
+    /// which shuffles with the game RNG and records the shuffle.
+    pub fn search_deck_to_hand<R: Rng + ?Sized>(
+        &mut self,
+        predicate: impl Fn(&Card) -> bool,
+        card_database: &HashMap<CardId, Card>,
+        max: usize,
+        rng: &mut R,
+    ) -> Vec<CardId> {
+        let taken: Vec<CardId> = self
+            .search_deck(card_database, predicate)
+            .into_iter()
+            .take(max)
+            .collect();
+
+        for &card_id in &taken {
+            self.move_from_deck_to_hand(card_id);
+        }
+        self.shuffle_deck_with(rng);
+
+        taken
+    }
+
     /// Set the active Pokemon
     pub fn set_active_pokemon(&mut self, card_id: CardId) -> bool {
         if self.hand.contains(&card_id) || self.bench.contains(&card_id) {
@@ -152,24 +556,56 @@ impl Player {
         }
     }
 
-    /// Attach energy to a Pokemon
+    /// Attach energy from hand to a Pokemon
     pub fn attach_energy(&mut self, energy_id: CardId, pokemon_id: CardId) -> bool {
-        if self.hand.contains(&energy_id)
-            && (Some(pokemon_id) == self.active_pokemon || self.bench.contains(&pokemon_id))
-        {
-            // Remove energy from hand
-            if let Some(pos) = self.hand.iter().position(|&id| id == energy_id) {
-                self.hand.remove(pos);
+        self.attach_energy_from(energy_id, pokemon_id, CardLocation::Hand).is_ok()
+    }
 
-                // Attach to Pokemon
-                self.attached_energy
-                    .entry(pokemon_id)
-                    .or_default()
-                    .push(energy_id);
-                true
-            } else {
-                false
-            }
+    /// Attach an energy card from hand, deck or discard pile to a Pokemon in play
+    ///
+    /// Used by energy-acceleration effects. When attaching from the deck,
+    /// the caller is responsible for shuffling it afterwards.
+    pub fn attach_energy_from(
+        &mut self,
+        energy_id: CardId,
+        pokemon_id: CardId,
+        source: CardLocation,
+    ) -> Result<(), String> {
+        if Some(pokemon_id) != self.active_pokemon && !self.bench.contains(&pokemon_id) {
+            return Err("Target Pokemon is not in play".to_string());
+        }
+
+        let zone = match source {
+            CardLocation::Hand => &mut self.hand,
+            CardLocation::Deck => &mut self.deck,
+            CardLocation::DiscardPile => &mut self.discard_pile,
+            other => return Err(format!("Cannot attach energy from {:?}", other)),
+        };
+        let pos = zone
+            .iter()
+            .position(|&id| id == energy_id)
+            .ok_or_else(|| format!("Energy card not found in {:?}", source))?;
+        zone.remove(pos);
+
+        self.attached_energy
+            .entry(pokemon_id)
+            .or_default()
+            .push(energy_id);
+
+        Ok(())
+    }
+
+    /// Attach a Pokemon Tool from hand to a Pokemon (one tool per Pokemon)
+    pub fn attach_tool(&mut self, tool_id: CardId, pokemon_id: CardId) -> bool {
+        let in_play = Some(pokemon_id) == self.active_pokemon || self.bench.contains(&pokemon_id);
+        if !in_play || self.attached_tools.contains_key(&pokemon_id) {
+            return false;
+        }
+
+        if let Some(pos) = self.hand.iter().position(|&id| id == tool_id) {
+            self.hand.remove(pos);
+            self.attached_tools.insert(pokemon_id, tool_id);
+            true
         } else {
             false
         }
@@ -179,7 +615,18 @@ impl Player {
     pub fn add_damage(&mut self, pokemon_id: CardId, damage: u32) {
         let current_damage = self.damage_counters.get(&pokemon_id).unwrap_or(&0);
         self.damage_counters
-            .insert(pokemon_id, current_damage + damage);
+            .insert(pokemon_id, current_damage.saturating_add(damage));
+    }
+
+    /// Place damage counters (10 damage each) on a Pokemon
+    ///
+    /// Unlike attack damage, placed counters ignore weakness and resistance.
+    pub fn place_damage_counters(&mut self, pokemon_id: CardId, counters: u32) -> EffectOutcome {
+        self.add_damage(pokemon_id, counters.saturating_mul(10));
+        EffectOutcome::CountersPlaced {
+            target: pokemon_id,
+            counters,
+        }
     }
 
     /// Heal damage from a Pokemon
@@ -211,20 +658,83 @@ impl Player {
     }
 
     /// Take a prize card
+    ///
+    /// The top card of the prize pile goes into the hand. Games that only
+    /// set `prize_cards` without placing prizes just count down.
     pub fn take_prize_card(&mut self) -> bool {
         if self.prize_cards > 0 {
             self.prize_cards -= 1;
-            // In a real implementation, you'd move a specific card from prizes to hand
+            if let Some(card_id) = self.prize_pile.pop() {
+                self.hand.push(card_id);
+            }
             true
         } else {
             false
         }
     }
 
-    /// Reset turn-based flags
+    /// Drop per-Pokemon state left behind by Pokemon that are no longer in play
+    ///
+    /// Moves made through `discard_from_field` clean up after themselves; this
+    /// catches entries left by code that edits the zones directly. Energy,
+    /// tools and evolution cards still recorded on a Pokemon that left play go
+    /// to the discard pile. Returns the number of entries removed.
+    pub fn prune_orphaned_state(&mut self) -> usize {
+        let in_play: HashSet<CardId> = self.pokemon_in_play().into_iter().collect();
+        let mut pruned = 0;
+
+        for stack in take_orphaned(&mut self.evolution_stacks, &in_play) {
+            self.discard_pile.extend(stack);
+            pruned += 1;
+        }
+        for energy in take_orphaned(&mut self.attached_energy, &in_play) {
+            self.discard_pile.extend(energy);
+            pruned += 1;
+        }
+        for tool in take_orphaned(&mut self.attached_tools, &in_play) {
+            self.discard_pile.push(tool);
+            pruned += 1;
+        }
+        pruned += take_orphaned(&mut self.damage_counters, &in_play).len();
+        pruned += take_orphaned(&mut self.special_conditions, &in_play).len();
+        pruned += take_orphaned(&mut self.protected_until_turn, &in_play).len();
+        pruned += take_orphaned(&mut self.markers, &in_play).len();
+        pruned
+    }
+
+    /// Put every card the player has back into their deck and clear the board
+    ///
+    /// Used to restart a game with the same cards. The deck is not shuffled
+    /// and `prize_cards` is reset to zero.
+    pub fn return_all_cards_to_deck(&mut self) {
+        self.deck = self.owned_card_ids();
+        self.hand.clear();
+        self.discard_pile.clear();
+        self.lost_zone.clear();
+        self.prize_pile.clear();
+        self.active_pokemon = None;
+        self.bench.clear();
+        self.stadium = None;
+        self.attached_energy.clear();
+        self.attached_tools.clear();
+        self.evolution_stacks.clear();
+        self.damage_counters.clear();
+        self.special_conditions.clear();
+        self.revealed.clear();
+        self.protected_until_turn.clear();
+        self.markers.clear();
+        self.prize_cards = 0;
+        self.start_turn();
+    }
+
+    /// Reset turn-based flags and clear turn-scoped markers
     pub fn start_turn(&mut self) {
         self.has_attacked = false;
         self.can_play_trainer = true;
+        self.has_retreated = false;
+        self.supporter_played_this_turn = false;
+        self.energy_attached_this_turn = 0;
+        self.clear_turn_markers();
     }
 
     /// End turn
@@ -242,6 +752,113 @@ impl Player {
         self.prize_cards == 0
     }
 
+    /// Active Pokemon followed by the bench, in bench order
+    pub fn pokemon_in_play(&self) -> Vec<CardId> {
+        let mut pokemon = Vec::with_capacity(1 + self.bench.len());
+        pokemon.extend(self.active_pokemon);
+        pokemon.extend_from_slice(&self.bench);
+        pokemon
+    }
+
+    /// Index of a Pokemon on the bench, as used by `CardLocation::Bench`
+    ///
+    /// The bench has no empty slots: when a benched Pokemon leaves play the
+    /// Pokemon after it move up one position.
+    pub fn bench_position(&self, card_id: CardId) -> Option<usize> {
+        self.bench.iter().position(|&id| id == card_id)
+    }
+
+    /// Swap the Pokemon in two bench positions
+    ///
+    /// Returns false (and changes nothing) if either position is empty.
+    pub fn swap_bench_positions(&mut self, a: usize, b: usize) -> bool {
+        if a >= self.bench.len() || b >= self.bench.len() {
+            return false;
+        }
+        self.bench.swap(a, b);
+        true
+    }
+
+    /// The cards in one zone
+    ///
+    /// `Bench(index)` yields the Pokemon at that bench position and
+    /// `AttachedEnergy(pokemon)` the energy attached to that Pokemon.
+    pub fn cards_in_zone(&self, location: &CardLocation) -> Vec<CardId> {
+        match location {
+            CardLocation::Hand => self.hand.clone(),
+            CardLocation::Deck => self.deck.clone(),
+            CardLocation::DiscardPile => self.discard_pile.clone(),
+            CardLocation::LostZone => self.lost_zone.clone(),
+            CardLocation::Active => self.active_pokemon.into_iter().collect(),
+            CardLocation::Bench(index) => self.bench.get(*index).copied().into_iter().collect(),
+            CardLocation::AttachedEnergy(pokemon_id) => {
+                self.attached_energy.get(pokemon_id).cloned().unwrap_or_default()
+            }
+            CardLocation::Prizes => self.prize_pile.clone(),
+        }
+    }
+
+    /// Every card the player owns that is tracked in a zone
+    ///
+    /// Covers hand, deck, discard pile, Lost Zone, prize pile, stadium and the
+    /// Pokemon in play together with their evolution stacks, energy and tools.
+    /// Each card appears once.
+    pub fn owned_card_ids(&self) -> Vec<CardId> {
+        let in_play = self.pokemon_in_play();
+        let mut cards = Vec::with_capacity(
+            self.hand.len()
+                + self.deck.len()
+                + self.discard_pile.len()
+                + self.lost_zone.len()
+                + self.prize_pile.len()
+                + in_play.len() * 2,
+        );
+        cards.extend_from_slice(&self.hand);
+        cards.extend_from_slice(&self.deck);
+        cards.extend_from_slice(&self.discard_pile);
+        cards.extend_from_slice(&self.lost_zone);
+        cards.extend_from_slice(&self.prize_pile);
+        cards.extend(self.stadium);
+        for pokemon_id in &in_play {
+            if let Some(stack) = self.evolution_stacks.get(pokemon_id) {
+                cards.extend_from_slice(stack);
+            }
+            cards.push(*pokemon_id);
+            if let Some(energy) = self.attached_energy.get(pokemon_id) {
+                cards.extend_from_slice(energy);
+            }
+            cards.extend(self.attached_tools.get(pokemon_id));
+        }
+        cards
+    }
+
+    /// Every card in a zone that `CardLocation` can name, with its location
+    ///
+    /// Yields hand, deck, discard pile, Lost Zone and prize pile in order, then
+    /// the active Pokemon, the bench and the energy attached to each Pokemon in
+    /// play. Tools, the stadium and evolution stacks have no `CardLocation`; use
+    /// `owned_card_ids` when those are needed too.
+    pub fn all_cards(&self) -> impl Iterator<Item = (CardId, CardLocation)> + '_ {
+        let attached = self.pokemon_in_play().into_iter().flat_map(move |pokemon_id| {
+            self.attached_energy
+                .get(&pokemon_id)
+                .into_iter()
+                .flatten()
+                .map(move |&energy_id| (energy_id, CardLocation::AttachedEnergy(pokemon_id)))
+        });
+
+        self.hand
+            .iter()
+            .map(|&id| (id, CardLocation::Hand))
+            .chain(self.deck.iter().map(|&id| (id, CardLocation::Deck)))
+            .chain(self.discard_pile.iter().map(|&id| (id, CardLocation::DiscardPile)))
+            .chain(self.lost_zone.iter().map(|&id| (id, CardLocation::LostZone)))
+            .chain(self.prize_pile.iter().map(|&id| (id, CardLocation::Prizes)))
+            .chain(self.active_pokemon.map(|id| (id, CardLocation::Active)))
+            .chain(self.bench.iter().enumerate().map(|(index, &id)| (id, CardLocation::Bench(index))))
+            .chain(attached)
+    }
+
     /// Get the location of a specific card
     pub fn find_card_location(&self, card_id: CardId) -> Option<CardLocation> {
         if self.hand.contains(&card_id) {
@@ -250,6 +867,10 @@ impl Player {
             Some(CardLocation::Deck)
         } else if self.discard_pile.contains(&card_id) {
             Some(CardLocation::DiscardPile)
+        } else if self.lost_zone.contains(&card_id) {
+            Some(CardLocation::LostZone)
+        } else if self.prize_pile.contains(&card_id) {
+            Some(CardLocation::Prizes)
         } else if Some(card_id) == self.active_pokemon {
             Some(CardLocation::Active)
         } else if let Some(index) = self.bench.iter().position(|&id| id == card_id) {
@@ -270,22 +891,15 @@ impl Player {
         let mut basic_pokemon = Vec::new();
 
         for &card_id in &self.hand {
-            if let Some(card) = card_database.get(&card_id) {
-                // 检查是否是宝可梦卡并且是基础阶段
-                if let crate::core::card::CardType::Pokemon {
-                    stage: crate::core::card::EvolutionStage::Basic,
-                    ..
-                } = card.card_type
-                {
-                    basic_pokemon.push(card_id);
-                }
+            if card_database.get(&card_id).is_some_and(Card::is_basic_pokemon) {
+                basic_pokemon.push(card_id);
             }
         }
 
         basic_pokemon
     }
 
-    /// 从牌库顶部抽取指定数量的卡牌作为奖赏卡
+    /// 从牌库顶部抽取指定数量的卡牌作为奖赏卡，放入奖赏卡区
     pub fn draw_prize_cards(&mut self, count: usize) -> Vec<CardId> {
         let mut prize_cards = Vec::new();
 
@@ -297,6 +911,7 @@ impl Player {
             }
         }
 
+        self.prize_pile.extend_from_slice(&prize_cards);
         prize_cards
     }
 
@@ -326,4 +941,505 @@ impl Player {
 
         energy_types
     }
-}
\ No newline at end of file
+
+    /// Units of one energy type attached to a Pokemon
+    ///
+    /// Uses `Card::energy_provides`, so a special energy providing several
+    /// units counts each matching one.
+    pub fn count_energy_of_type(
+        &self,
+        pokemon_id: CardId,
+        energy_type: &EnergyType,
+        card_database: &HashMap<CardId, Card>,
+    ) -> usize {
+        self.attached_energy
+            .get(&pokemon_id)
+            .into_iter()
+            .flatten()
+            .filter_map(|energy_id| card_database.get(energy_id))
+            .flat_map(Card::energy_provides)
+            .filter(|provided| provided == energy_type)
+            .count()
+    }
+
+    /// Attached energy cards on a Pokemon, counted by card energy type
+    ///
+    /// Built on `get_attached_energy_types`, so each energy card counts once
+    /// under its own type. Use `count_energy_of_type` for the units provided.
+    pub fn energy_type_counts(
+        &self,
+        pokemon_id: CardId,
+        card_database: &HashMap<CardId, Card>,
+    ) -> HashMap<EnergyType, usize> {
+        let mut counts = HashMap::new();
+        for energy_type in self.get_attached_energy_types(pokemon_id, card_database) {
+            *counts.entry(energy_type).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Whether an energy card of the given type is attached to a Pokemon
+    pub fn has_energy_type(
+        &self,
+        pokemon_id: CardId,
+        energy_type: &EnergyType,
+        card_database: &HashMap<CardId, Card>,
+    ) -> bool {
+        self.get_attached_energy_types(pokemon_id, card_database)
+            .contains(energy_type)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_discard_attached_energy() {
+        let mut player = Player::new("Alice".to_string());
+        let pokemon_id = Uuid::new_v4();
+        let energy: Vec<CardId> = (0..3).map(|_| Uuid::new_v4()).collect();
+        player.active_pokemon = Some(pokemon_id);
+        player.attached_energy.insert(pokemon_id, energy.clone());
+
+        let discarded = player.discard_attached_energy(pokemon_id, 2);
+
+        assert_eq!(discarded, vec![energy[0], energy[1]]);
+        assert_eq!(player.get_attached_energy_count(pokemon_id), 1);
+        assert_eq!(player.discard_pile, vec![energy[0], energy[1]]);
+    }
+
+    #[test]
+    fn test_discard_benched_pokemon_from_field() {
+        let mut player = Player::new("Alice".to_string());
+        let benched = Uuid::new_v4();
+        let energy = Uuid::new_v4();
+        player.bench.push(benched);
+        player.attached_energy.insert(benched, vec![energy]);
+        player.add_damage(benched, 30);
+
+        assert!(player.discard_from_field(benched));
+
+        assert!(player.bench.is_empty());
+        assert!(player.discard_pile.contains(&benched));
+        assert!(player.discard_pile.contains(&energy));
+        assert!(!player.attached_energy.contains_key(&benched));
+        assert!(!player.damage_counters.contains_key(&benched));
+        assert!(!player.discard_from_field(Uuid::new_v4()));
+    }
+
+    #[test]
+    fn test_peek_and_reorder_top() {
+        let mut player = Player::new("Alice".to_string());
+        let deck: Vec<CardId> = (0..5).map(|_| Uuid::new_v4()).collect();
+        player.set_deck(deck.clone());
+
+        let top = player.peek_top(3);
+        assert_eq!(top, vec![deck[4], deck[3], deck[2]]);
+        assert_eq!(player.deck, deck);
+
+        player.reorder_top(vec![deck[2], deck[4], deck[3]]).unwrap();
+        assert_eq!(player.peek_top(3), vec![deck[2], deck[4], deck[3]]);
+        assert_eq!(player.draw_card(), Some(deck[2]));
+
+        assert!(player.reorder_top(vec![deck[4], deck[0]]).is_err());
+        assert!(player.reorder_top(vec![deck[4], deck[4]]).is_err());
+        assert!(player.reorder_top(vec![Uuid::new_v4(); 5]).is_err());
+        assert_eq!(player.peek_top(2), vec![deck[4], deck[3]]);
+    }
+
+    #[test]
+    fn test_discard_top_of_deck() {
+        let mut player = Player::new("Alice".to_string());
+        let deck: Vec<CardId> = (0..5).map(|_| Uuid::new_v4()).collect();
+        player.set_deck(deck.clone());
+
+        let milled = player.discard_top_of_deck(3);
+
+        assert_eq!(milled, vec![deck[4], deck[3], deck[2]]);
+        assert_eq!(player.deck, vec![deck[0], deck[1]]);
+        assert_eq!(player.discard_pile, milled);
+        assert_eq!(player.discard_top_of_deck(5).len(), 2);
+    }
+
+    #[test]
+    fn test_recover_and_shuffle_discard() {
+        use crate::core::game::rng::GameRng;
+        use rand::SeedableRng;
+
+        let mut player = Player::new("Alice".to_string());
+        let deck: Vec<CardId> = (0..10).map(|_| Uuid::new_v4()).collect();
+        player.set_deck(deck);
+        let milled = player.discard_top_of_deck(4);
+
+        assert!(player.recover_from_discard(milled[1]));
+        assert!(!player.recover_from_discard(milled[1]));
+        assert_eq!(player.hand, vec![milled[1]]);
+        assert_eq!(player.discard_pile.len(), 3);
+
+        let mut rng = GameRng::seed_from_u64(7);
+        player.shuffle_discard_into_deck(&mut rng);
+
+        assert!(player.discard_pile.is_empty());
+        assert_eq!(player.deck.len(), 9);
+        for card_id in [milled[0], milled[2], milled[3]] {
+            assert!(player.deck.contains(&card_id));
+        }
+    }
+
+    #[test]
+    fn test_shuffle_hand_into_deck_then_draw() {
+        use crate::core::game::rng::GameRng;
+        use rand::SeedableRng;
+
+        let mut player = Player::new("Alice".to_string());
+        let deck: Vec<CardId> = (0..10).map(|_| Uuid::new_v4()).collect();
+        player.set_deck(deck);
+        let hand = player.draw_cards(5);
+
+        let mut rng = GameRng::seed_from_u64(3);
+        assert_eq!(player.shuffle_hand_into_deck(&mut rng), 5);
+        assert!(player.hand.is_empty());
+        assert_eq!(player.deck.len(), 10);
+        assert!(hand.iter().all(|card_id| player.deck.contains(card_id)));
+
+        assert_eq!(player.draw_cards(4).len(), 4);
+        assert_eq!(player.deck.len(), 6);
+        assert_eq!(player.discard_hand(), 4);
+        assert!(player.hand.is_empty());
+        assert_eq!(player.discard_pile.len(), 4);
+        assert_eq!(player.discard_hand(), 0);
+    }
+
+    #[test]
+    fn test_attach_energy_from_discard_and_deck() {
+        use crate::core::game::rng::GameRng;
+        use rand::SeedableRng;
+
+        let mut player = Player::new("Alice".to_string());
+        let pokemon_id = Uuid::new_v4();
+        player.active_pokemon = Some(pokemon_id);
+        let from_discard = Uuid::new_v4();
+        let from_deck = Uuid::new_v4();
+        player.discard_pile.push(from_discard);
+        player.set_deck(vec![Uuid::new_v4(), from_deck, Uuid::new_v4()]);
+
+        player
+            .attach_energy_from(from_discard, pokemon_id, CardLocation::DiscardPile)
+            .unwrap();
+        assert!(player.discard_pile.is_empty());
+
+        player
+            .attach_energy_from(from_deck, pokemon_id, CardLocation::Deck)
+            .unwrap();
+        player.shuffle_deck_with(&mut GameRng::seed_from_u64(3));
+        assert_eq!(player.deck.len(), 2);
+        assert!(!player.deck.contains(&from_deck));
+
+        assert_eq!(player.attached_energy[&pokemon_id], vec![from_discard, from_deck]);
+        assert!(player
+            .attach_energy_from(from_deck, pokemon_id, CardLocation::Deck)
+            .is_err());
+        assert!(player
+            .attach_energy_from(Uuid::new_v4(), pokemon_id, CardLocation::Prizes)
+            .is_err());
+    }
+
+    #[test]
+    fn test_attach_energy_from_discard_to_bench() {
+        let mut player = Player::new("Alice".to_string());
+        let benched = Uuid::new_v4();
+        let (from_discard, from_hand) = (Uuid::new_v4(), Uuid::new_v4());
+        player.active_pokemon = Some(Uuid::new_v4());
+        player.bench.push(benched);
+        player.discard_pile = vec![Uuid::new_v4(), from_discard];
+        player.hand.push(from_hand);
+
+        player
+            .attach_energy_from(from_discard, benched, CardLocation::DiscardPile)
+            .unwrap();
+        assert_eq!(player.discard_pile.len(), 1);
+        assert_eq!(player.get_attached_energy_count(benched), 1);
+
+        assert!(!player.attach_energy(from_discard, benched));
+        assert!(player.attach_energy(from_hand, benched));
+        assert!(player.hand.is_empty());
+        assert_eq!(player.attached_energy[&benched], vec![from_discard, from_hand]);
+    }
+
+    #[test]
+    fn test_count_energy_of_type() {
+        use crate::test_fixtures::{basic_energy, special_energy};
+
+        let mut double_colorless = special_energy("Double Colorless Energy", EnergyType::Colorless);
+        double_colorless.add_metadata("provides".to_string(), "Colorless,Colorless".to_string());
+        let cards = [
+            basic_energy(EnergyType::Lightning),
+            basic_energy(EnergyType::Lightning),
+            basic_energy(EnergyType::Colorless),
+            double_colorless,
+        ];
+
+        let mut player = Player::new("Alice".to_string());
+        let pokemon_id = Uuid::new_v4();
+        player.active_pokemon = Some(pokemon_id);
+        player
+            .attached_energy
+            .insert(pokemon_id, cards.iter().map(|card| card.id).collect());
+        let database: HashMap<CardId, Card> = cards.into_iter().map(|card| (card.id, card)).collect();
+
+        assert_eq!(player.count_energy_of_type(pokemon_id, &EnergyType::Lightning, &database), 2);
+        assert_eq!(player.count_energy_of_type(pokemon_id, &EnergyType::Colorless, &database), 3);
+        assert_eq!(player.count_energy_of_type(pokemon_id, &EnergyType::Fire, &database), 0);
+        assert_eq!(player.count_energy_of_type(Uuid::new_v4(), &EnergyType::Lightning, &database), 0);
+    }
+
+    #[test]
+    fn test_energy_type_counts() {
+        use crate::test_fixtures::basic_energy;
+
+        let cards: Vec<Card> = [EnergyType::Lightning, EnergyType::Lightning, EnergyType::Colorless]
+            .into_iter()
+            .map(basic_energy)
+            .collect();
+
+        let mut player = Player::new("Alice".to_string());
+        let pokemon_id = Uuid::new_v4();
+        player.active_pokemon = Some(pokemon_id);
+        player
+            .attached_energy
+            .insert(pokemon_id, cards.iter().map(|card| card.id).collect());
+        let database: HashMap<CardId, Card> = cards.into_iter().map(|card| (card.id, card)).collect();
+
+        let expected = HashMap::from([(EnergyType::Lightning, 2), (EnergyType::Colorless, 1)]);
+        assert_eq!(player.energy_type_counts(pokemon_id, &database), expected);
+        assert!(player.has_energy_type(pokemon_id, &EnergyType::Lightning, &database));
+        assert!(!player.has_energy_type(pokemon_id, &EnergyType::Fire, &database));
+        assert!(player.energy_type_counts(Uuid::new_v4(), &database).is_empty());
+    }
+
+    #[test]
+    fn test_add_damage_saturates() {
+        let mut player = Player::new("Alice".to_string());
+        let pokemon_id = Uuid::new_v4();
+
+        player.add_damage(pokemon_id, u32::MAX - 5);
+        player.add_damage(pokemon_id, 100);
+        assert_eq!(player.damage_counters[&pokemon_id], u32::MAX);
+    }
+
+    #[test]
+    fn test_zone_accessors() {
+        let mut player = Player::new("Alice".to_string());
+        let [active, benched, energy, in_hand, discarded] = [(); 5].map(|_| Uuid::new_v4());
+        player.active_pokemon = Some(active);
+        player.bench.push(benched);
+        player.attached_energy.insert(active, vec![energy]);
+        player.hand.push(in_hand);
+        player.discard_pile.push(discarded);
+
+        assert_eq!(player.pokemon_in_play(), vec![active, benched]);
+        assert_eq!(player.cards_in_zone(&CardLocation::Hand), vec![in_hand]);
+        assert_eq!(player.cards_in_zone(&CardLocation::DiscardPile), vec![discarded]);
+        assert_eq!(player.cards_in_zone(&CardLocation::Active), vec![active]);
+        assert_eq!(player.cards_in_zone(&CardLocation::Bench(0)), vec![benched]);
+        assert!(player.cards_in_zone(&CardLocation::Bench(1)).is_empty());
+        assert_eq!(player.cards_in_zone(&CardLocation::AttachedEnergy(active)), vec![energy]);
+
+        let mut owned = player.owned_card_ids();
+        let mut expected = vec![active, benched, energy, in_hand, discarded];
+        owned.sort();
+        expected.sort();
+        assert_eq!(owned, expected);
+
+        let located: Vec<(CardId, CardLocation)> = player.all_cards().collect();
+        assert_eq!(
+            located,
+            vec![
+                (in_hand, CardLocation::Hand),
+                (discarded, CardLocation::DiscardPile),
+                (active, CardLocation::Active),
+                (benched, CardLocation::Bench(0)),
+                (energy, CardLocation::AttachedEnergy(active)),
+            ]
+        );
+        assert!(
+            located
+                .iter()
+                .all(|(card_id, location)| player.find_card_location(*card_id).as_ref() == Some(location))
+        );
+    }
+
+    #[test]
+    fn test_bench_positions() {
+        let mut player = Player::new("Alice".to_string());
+        let bench: Vec<CardId> = (0..3).map(|_| Uuid::new_v4()).collect();
+        player.bench = bench.clone();
+
+        assert_eq!(player.bench_position(bench[2]), Some(2));
+        assert_eq!(player.bench_position(Uuid::new_v4()), None);
+
+        assert!(player.swap_bench_positions(0, 2));
+        assert_eq!(player.bench, vec![bench[2], bench[1], bench[0]]);
+        assert_eq!(player.find_card_location(bench[0]), Some(CardLocation::Bench(2)));
+        assert!(!player.swap_bench_positions(1, 3));
+
+        // Knocked-out Pokemon leave no gap
+        assert!(player.discard_from_field(bench[1]));
+        assert_eq!(player.bench_position(bench[0]), Some(1));
+    }
+
+    #[test]
+    fn test_pokemon_in_play_lists_active_then_bench() {
+        let mut player = Player::new("Alice".to_string());
+        let bench: Vec<CardId> = (0..3).map(|_| Uuid::new_v4()).collect();
+        player.bench = bench.clone();
+        assert_eq!(player.pokemon_in_play(), bench);
+
+        let active = Uuid::new_v4();
+        player.active_pokemon = Some(active);
+        let in_play = player.pokemon_in_play();
+        assert_eq!(in_play[0], active);
+        assert_eq!(in_play[1..], bench[..]);
+    }
+
+    #[test]
+    fn test_evolution_stack_survives_two_evolutions() {
+        let mut player = Player::new("Alice".to_string());
+        let (basic, stage1, stage2) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        player.active_pokemon = Some(basic);
+        player.hand = vec![stage1, stage2];
+
+        assert!(player.evolve_pokemon(basic, stage1));
+        assert_eq!(player.evolution_stack(stage1), &[basic]);
+        assert!(player.evolve_pokemon(stage1, stage2));
+        assert_eq!(player.evolution_stack(stage2), &[basic, stage1]);
+        assert!(player.evolution_stack(stage1).is_empty());
+        assert_eq!(player.active_pokemon, Some(stage2));
+
+        assert!(player.discard_from_field(stage2));
+        assert_eq!(player.discard_pile, vec![basic, stage1, stage2]);
+        assert!(player.evolution_stack(stage2).is_empty());
+    }
+
+    #[test]
+    fn test_markers() {
+        let mut player = Player::new("Alice".to_string());
+        let (active, benched) = (Uuid::new_v4(), Uuid::new_v4());
+        player.active_pokemon = Some(active);
+        player.bench.push(benched);
+
+        player.set_marker(active, "Crabominable:counters", 2);
+        player.set_marker(active, "turn:Crabominable:used_attack", 1);
+        player.set_marker(benched, "Crabominable:counters", 5);
+        assert_eq!(player.get_marker(active, "Crabominable:counters"), Some(2));
+        assert_eq!(player.get_marker(active, "Missing:marker"), None);
+
+        player.start_turn();
+        assert_eq!(player.get_marker(active, "turn:Crabominable:used_attack"), None);
+        assert_eq!(player.get_marker(active, "Crabominable:counters"), Some(2));
+
+        // Markers leave play with the Pokemon
+        assert!(player.discard_from_field(benched));
+        assert!(!player.markers.contains_key(&benched));
+        player.clear_markers_for_pokemon(active);
+        assert!(player.markers.is_empty());
+    }
+
+    #[test]
+    fn test_prune_orphaned_state() {
+        use crate::core::player::SpecialCondition;
+
+        let mut player = Player::new("Alice".to_string());
+        let (active, benched, energy) = (Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4());
+        player.active_pokemon = Some(active);
+        player.bench.push(benched);
+        for pokemon_id in [active, benched] {
+            player.add_damage(pokemon_id, 20);
+            player.add_special_condition(pokemon_id, SpecialCondition::Confused, -1, 1);
+        }
+        player.attached_energy.insert(benched, vec![energy]);
+        assert_eq!(player.prune_orphaned_state(), 0);
+
+        // The benched Pokemon leaves play without going through discard_from_field
+        player.bench.clear();
+        player.discard_pile.push(benched);
+        assert_eq!(player.prune_orphaned_state(), 3);
+
+        assert_eq!(player.discard_pile, vec![benched, energy]);
+        assert!(!player.attached_energy.contains_key(&benched));
+        assert!(!player.damage_counters.contains_key(&benched));
+        assert!(!player.special_conditions.contains_key(&benched));
+        assert_eq!(player.damage_counters.get(&active), Some(&20));
+    }
+
+    #[test]
+    fn test_send_to_lost_zone() {
+        use crate::core::game::rng::GameRng;
+        use rand::SeedableRng;
+
+        let mut player = Player::new("Alice".to_string());
+        let card_id = Uuid::new_v4();
+        player.discard_pile.push(card_id);
+
+        assert!(!player.send_to_lost_zone(card_id, CardLocation::Hand));
+        assert!(player.send_to_lost_zone(card_id, CardLocation::DiscardPile));
+        assert_eq!(player.find_card_location(card_id), Some(CardLocation::LostZone));
+        assert_eq!(player.lost_zone_count(), 1);
+
+        assert!(!player.recover_from_discard(card_id));
+        player.shuffle_discard_into_deck(&mut GameRng::seed_from_u64(1));
+        assert!(player.deck.is_empty());
+        assert_eq!(player.lost_zone, vec![card_id]);
+
+        // A Pokemon leaves its attachments in the discard pile
+        let pokemon_id = Uuid::new_v4();
+        let energy_id = Uuid::new_v4();
+        player.bench.push(pokemon_id);
+        player.attached_energy.insert(pokemon_id, vec![energy_id]);
+        assert!(player.send_to_lost_zone(pokemon_id, CardLocation::Bench(0)));
+        assert!(player.bench.is_empty());
+        assert_eq!(player.discard_pile, vec![energy_id]);
+        assert_eq!(player.lost_zone, vec![card_id, pokemon_id]);
+    }
+
+    #[test]
+    fn test_search_deck_to_hand() {
+        use crate::core::card::{CardType, EvolutionStage};
+        use crate::core::game::rng::GameRng;
+        use crate::test_fixtures::{pokemon, pokemon_at_stage};
+        use rand::SeedableRng;
+
+        let cards = [
+            pokemon("Pikachu", 60),
+            pokemon_at_stage("Raichu", 90, EvolutionStage::Stage1),
+            pokemon("Eevee", 50),
+            pokemon("Snorlax", 100),
+        ];
+        let card_database: HashMap<CardId, Card> =
+            cards.iter().map(|card| (card.id, card.clone())).collect();
+
+        let mut player = Player::new("Alice".to_string());
+        player.set_deck(cards.iter().map(|card| card.id).collect());
+
+        let taken = player.search_deck_to_hand(
+            |card| {
+                matches!(
+                    card.card_type,
+                    CardType::Pokemon { stage: EvolutionStage::Basic, .. }
+                )
+            },
+            &card_database,
+            2,
+            &mut GameRng::seed_from_u64(5),
+        );
+
+        assert_eq!(taken.len(), 2);
+        assert_eq!(player.hand, taken);
+        assert_eq!(player.deck.len(), 2);
+        assert!(player.deck.contains(&cards[1].id));
+        for card_id in &taken {
+            assert!(!player.deck.contains(card_id));
+        }
+    }
+}
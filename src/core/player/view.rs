@@ -0,0 +1,82 @@
+//! What one player can see of another player's board
+
+use crate::core::card::CardId;
+use crate::core::player::{Player, PlayerId};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A player's board as seen by a particular viewer
+///
+/// Cards in play, the discard pile and the Lost Zone are public. Hand cards
+/// are only shown to their owner, or to anyone once they have been revealed;
+/// the deck is reduced to its size.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerView {
+    pub player_id: PlayerId,
+    pub name: String,
+    pub prize_cards: u32,
+    /// Hand in order, with `None` for cards the viewer cannot see
+    pub hand: Vec<Option<CardId>>,
+    pub deck_size: usize,
+    pub active_pokemon: Option<CardId>,
+    pub bench: Vec<CardId>,
+    pub discard_pile: Vec<CardId>,
+    pub lost_zone: Vec<CardId>,
+    pub attached_energy: HashMap<CardId, Vec<CardId>>,
+    pub attached_tools: HashMap<CardId, CardId>,
+    pub damage_counters: HashMap<CardId, u32>,
+    pub stadium: Option<CardId>,
+}
+
+impl Player {
+    /// This player's board as `viewer` is allowed to see it
+    pub fn player_view(&self, viewer: PlayerId) -> PlayerView {
+        let is_owner = viewer == self.id;
+        let hand = self
+            .hand
+            .iter()
+            .map(|&card_id| (is_owner || self.is_revealed(card_id)).then_some(card_id))
+            .collect();
+
+        PlayerView {
+            player_id: self.id,
+            name: self.name.clone(),
+            prize_cards: self.prize_cards,
+            hand,
+            deck_size: self.deck.len(),
+            active_pokemon: self.active_pokemon,
+            bench: self.bench.clone(),
+            discard_pile: self.discard_pile.clone(),
+            lost_zone: self.lost_zone.clone(),
+            attached_energy: self.attached_energy.clone(),
+            attached_tools: self.attached_tools.clone(),
+            damage_counters: self.damage_counters.clone(),
+            stadium: self.stadium,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_revealed_hand_card_is_visible_to_opponent() {
+        let mut alice = Player::new("Alice".to_string());
+        let bob = Player::new("Bob".to_string());
+        alice.hand = (0..3).map(|_| Uuid::new_v4()).collect();
+        alice.set_deck((0..4).map(|_| Uuid::new_v4()).collect());
+        let revealed = alice.hand[1];
+        alice.reveal_card(revealed);
+
+        let view = alice.player_view(bob.id);
+        assert_eq!(view.hand, vec![None, Some(revealed), None]);
+        assert_eq!(view.deck_size, 4);
+
+        let own_view = alice.player_view(alice.id);
+        assert!(own_view.hand.iter().all(Option::is_some));
+        assert!(alice.is_revealed(revealed));
+        assert!(!alice.is_revealed(alice.hand[0]));
+    }
+}
@@ -5,6 +5,7 @@ use crate::core::game::state::Game;
 use crate::core::player::PlayerId;
 use dyn_clone::DynClone;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Trait for defining game rules
 pub trait Rule: DynClone + Send + Sync {
@@ -67,16 +68,31 @@ pub enum GameAction {
         player_id: PlayerId,
         pokemon_id: CardId,
         attack_index: usize,
+        /// Target Pokemon for attacks that choose one (`Choose`/`Bench` target types)
+        target: Option<CardId>,
     },
-    /// Retreat a Pokemon
+    /// Retreat the active Pokemon, promoting `pokemon_id` from the bench
     Retreat {
         player_id: PlayerId,
         pokemon_id: CardId,
     },
+    /// Evolve a Pokemon in play with an evolution card from hand
+    Evolve {
+        player_id: PlayerId,
+        card_id: CardId,
+        pokemon_id: CardId,
+    },
+    /// Promote a benched Pokemon to active after the active was knocked out
+    PromoteActive {
+        player_id: PlayerId,
+        bench_index: usize,
+    },
     /// End turn
     EndTurn { player_id: PlayerId },
     /// Pass turn without action
     Pass { player_id: PlayerId },
+    /// Concede the game, making the opponent the winner
+    Concede { player_id: PlayerId },
 }
 
 /// Main rule engine that manages and applies all rules
@@ -99,6 +115,48 @@ pub struct RuleConfig {
     pub min_severity: ViolationSeverity,
 }
 
+/// Serializable description of a rule engine: its configuration and the
+/// names of its rules, in order
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleEngineDescriptor {
+    pub config: RuleConfig,
+    pub rule_names: Vec<String>,
+}
+
+/// Creates a fresh instance of a rule
+pub type RuleFactory = Box<dyn Fn() -> Box<dyn Rule> + Send + Sync>;
+
+/// Named rule factories used to rebuild an engine from a descriptor
+#[derive(Default)]
+pub struct RuleRegistry {
+    factories: HashMap<String, RuleFactory>,
+}
+
+impl RuleRegistry {
+    /// Create an empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a factory under a rule name, replacing any previous one
+    pub fn register<F>(&mut self, name: impl Into<String>, factory: F)
+    where
+        F: Fn() -> Box<dyn Rule> + Send + Sync + 'static,
+    {
+        self.factories.insert(name.into(), Box::new(factory));
+    }
+
+    /// Whether a rule name has a registered factory
+    pub fn contains(&self, name: &str) -> bool {
+        self.factories.contains_key(name)
+    }
+
+    /// Build the rule registered under a name
+    pub fn build(&self, name: &str) -> Option<Box<dyn Rule>> {
+        self.factories.get(name).map(|factory| factory())
+    }
+}
+
 impl Default for RuleConfig {
     fn default() -> Self {
         Self {
@@ -131,6 +189,15 @@ impl RuleEngine {
         self.rules.push(Box::new(rule));
     }
 
+    /// Add a rule built from the registry by name
+    pub fn add_rule_by_name(&mut self, name: &str, registry: &RuleRegistry) -> Result<(), String> {
+        let rule = registry
+            .build(name)
+            .ok_or_else(|| format!("Unknown rule: {}", name))?;
+        self.rules.push(rule);
+        Ok(())
+    }
+
     /// Remove a rule by name
     pub fn remove_rule(&mut self, rule_name: &str) {
         self.rules.retain(|rule| rule.name() != rule_name);
@@ -158,6 +225,25 @@ impl RuleEngine {
         violations
     }
 
+    /// The first violation that blocks an action, if any
+    ///
+    /// Only `Error` and `Fatal` violations at or above `min_severity` block.
+    /// Stops at the first one instead of collecting every violation.
+    pub fn first_violation(&self, game: &Game, action: &GameAction) -> Option<RuleViolation> {
+        self.rules
+            .iter()
+            .filter_map(|rule| rule.validate_action(game, action).err())
+            .find(|violation| {
+                violation.severity as u8 >= self.config.min_severity as u8
+                    && matches!(violation.severity, ViolationSeverity::Error | ViolationSeverity::Fatal)
+            })
+    }
+
+    /// Whether an action passes all rules, without collecting violations
+    pub fn is_action_legal(&self, game: &Game, action: &GameAction) -> bool {
+        self.first_violation(game, action).is_none()
+    }
+
     /// Apply an action if it passes all rule validations
     pub fn apply_action(
         &self,
@@ -203,6 +289,34 @@ impl RuleEngine {
     pub fn has_rule(&self, rule_name: &str) -> bool {
         self.rules.iter().any(|rule| rule.name() == rule_name)
     }
+
+    /// Describe this engine's configuration and rules in serializable form
+    pub fn describe(&self) -> RuleEngineDescriptor {
+        RuleEngineDescriptor {
+            config: self.config.clone(),
+            rule_names: self.get_rule_names(),
+        }
+    }
+
+    /// Rebuild an engine from a descriptor, creating each rule from the registry
+    ///
+    /// Fails if a rule name has no registered factory.
+    pub fn from_descriptor(descriptor: RuleEngineDescriptor, registry: &RuleRegistry) -> Result<Self, String> {
+        let rules = descriptor
+            .rule_names
+            .iter()
+            .map(|name| {
+                registry
+                    .build(name)
+                    .ok_or_else(|| format!("Unknown rule: {}", name))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            rules,
+            config: descriptor.config,
+        })
+    }
 }
 
 impl Default for RuleEngine {
@@ -1,7 +1,11 @@
 //! Standard PTCG rules implementation
 
-use crate::core::game::state::Game;
-use crate::core::rules::{Rule, RuleEngine, RuleResult, RuleViolation, ViolationSeverity, GameAction};
+use crate::core::card::{CardType, TrainerType};
+use crate::core::game::state::{Game, GameState};
+use crate::core::player::SpecialCondition;
+use crate::core::rules::{
+    GameAction, Rule, RuleEngine, RuleRegistry, RuleResult, RuleViolation, ViolationSeverity,
+};
 
 /// Standard PTCG rules implementation
 pub struct StandardRules;
@@ -14,9 +18,28 @@ impl StandardRules {
         engine.add_rule(TurnOrderRule);
         engine.add_rule(HandLimitRule);
         engine.add_rule(EnergyAttachmentRule);
+        engine.add_rule(SpecialConditionRule);
+        engine.add_rule(BenchRule);
+        engine.add_rule(EvolveRule);
+        engine.add_rule(FirstTurnRule);
 
         engine
     }
+
+    /// Registry of the standard rules, for rebuilding engines from descriptors
+    pub fn registry() -> RuleRegistry {
+        let mut registry = RuleRegistry::new();
+
+        registry.register("TurnOrder", || Box::new(TurnOrderRule));
+        registry.register("HandLimit", || Box::new(HandLimitRule));
+        registry.register("EnergyAttachment", || Box::new(EnergyAttachmentRule));
+        registry.register("SpecialCondition", || Box::new(SpecialConditionRule));
+        registry.register("Bench", || Box::new(BenchRule));
+        registry.register("Evolve", || Box::new(EvolveRule));
+        registry.register("FirstTurn", || Box::new(FirstTurnRule));
+
+        registry
+    }
 }
 
 /// Rule: Players must take actions only on their turn
@@ -30,11 +53,14 @@ impl Rule for TurnOrderRule {
 
     fn validate_action(&self, game: &Game, action: &GameAction) -> RuleResult {
         let action_player_id = match action {
+            // Promotions after a knockout and concessions may happen on the opponent's turn
+            GameAction::PromoteActive { .. } | GameAction::Concede { .. } => return Ok(()),
             GameAction::DrawCard { player_id, .. }
             | GameAction::PlayCard { player_id, .. }
             | GameAction::AttachEnergy { player_id, .. }
             | GameAction::UseAttack { player_id, .. }
             | GameAction::Retreat { player_id, .. }
+            | GameAction::Evolve { player_id, .. }
             | GameAction::EndTurn { player_id, .. }
             | GameAction::Pass { player_id, .. } => *player_id,
         };
@@ -56,6 +82,9 @@ impl Rule for TurnOrderRule {
 }
 
 /// Rule: Hand size limit (typically unlimited in PTCG, but can be configured)
+///
+/// Also blocks ending the turn while the player is over the end-of-turn
+/// hand limit; see `Game::enforce_hand_limit`.
 #[derive(Clone)]
 pub struct HandLimitRule;
 
@@ -76,6 +105,16 @@ impl Rule for HandLimitRule {
                 severity: ViolationSeverity::Error,
             });
         }
+        if let GameAction::EndTurn { player_id } = action {
+            let excess = game.cards_over_hand_limit(*player_id);
+            if excess > 0 {
+                return Err(RuleViolation {
+                    rule_name: self.name().to_string(),
+                    message: format!("Must discard {} card(s) before ending the turn", excess),
+                    severity: ViolationSeverity::Error,
+                });
+            }
+        }
         Ok(())
     }
 
@@ -119,6 +158,15 @@ impl Rule for EnergyAttachmentRule {
                 });
             }
 
+            // Check the per-turn attachment limit
+            if player.energy_attached_this_turn >= game.rules.energy_per_turn {
+                return Err(RuleViolation {
+                    rule_name: self.name().to_string(),
+                    message: "Energy already attached this turn".to_string(),
+                    severity: ViolationSeverity::Error,
+                });
+            }
+
             // Check if energy card is actually an energy
             if let Some(card) = game.get_card(*energy_id)
                 && !card.is_energy()
@@ -129,6 +177,125 @@ impl Rule for EnergyAttachmentRule {
                     severity: ViolationSeverity::Error,
                 });
             }
+
+            // Check the target is actually a Pokemon
+            if let Some(card) = game.get_card(*pokemon_id)
+                && !card.is_pokemon()
+            {
+                return Err(RuleViolation {
+                    rule_name: self.name().to_string(),
+                    message: "Target is not a Pokemon".to_string(),
+                    severity: ViolationSeverity::Error,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_effect(&self, _game: &mut Game, _action: &GameAction) -> RuleResult {
+        Ok(())
+    }
+}
+
+/// Rule: Special conditions restrict attacking and retreating
+///
+/// A paralyzed or asleep active Pokemon cannot attack, and a trapped one
+/// cannot retreat.
+#[derive(Clone)]
+pub struct SpecialConditionRule;
+
+impl SpecialConditionRule {
+    fn violation(&self, message: String) -> RuleViolation {
+        RuleViolation {
+            rule_name: self.name().to_string(),
+            message,
+            severity: ViolationSeverity::Error,
+        }
+    }
+}
+
+impl Rule for SpecialConditionRule {
+    fn name(&self) -> &str {
+        "SpecialCondition"
+    }
+
+    fn validate_action(&self, game: &Game, action: &GameAction) -> RuleResult {
+        match action {
+            GameAction::UseAttack {
+                player_id,
+                pokemon_id,
+                ..
+            } => {
+                let Some(player) = game.get_player(*player_id) else {
+                    return Ok(());
+                };
+                if !player.can_pokemon_attack(*pokemon_id) {
+                    let condition = player
+                        .special_conditions
+                        .get(pokemon_id)
+                        .into_iter()
+                        .flatten()
+                        .find_map(|instance| match instance.condition {
+                            SpecialCondition::Paralyzed => Some("paralyzed"),
+                            SpecialCondition::Asleep => Some("asleep"),
+                            _ => None,
+                        })
+                        .unwrap_or("unable to attack");
+                    return Err(self.violation(format!("Pokemon is {} and cannot attack", condition)));
+                }
+            }
+            GameAction::Retreat { player_id, .. } => {
+                if let Some(player) = game.get_player(*player_id)
+                    && let Some(active_id) = player.active_pokemon
+                    && !player.can_pokemon_retreat(active_id)
+                {
+                    return Err(self.violation("Pokemon is trapped and cannot retreat".to_string()));
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn apply_effect(&self, _game: &mut Game, _action: &GameAction) -> RuleResult {
+        Ok(())
+    }
+}
+
+/// Rule: Only Basic Pokemon may be played from hand to the bench
+#[derive(Clone)]
+pub struct BenchRule;
+
+impl Rule for BenchRule {
+    fn name(&self) -> &str {
+        "Bench"
+    }
+
+    fn validate_action(&self, game: &Game, action: &GameAction) -> RuleResult {
+        if let GameAction::PlayCard {
+            player_id, card_id, ..
+        } = action
+            && let Some(card) = game.get_card(*card_id)
+            && card.is_pokemon()
+        {
+            let message = if !card.is_basic_pokemon() {
+                Some("Only Basic Pokemon can be played to the bench".to_string())
+            } else if game
+                .get_player(*player_id)
+                .is_some_and(|player| player.bench.len() >= game.rules.max_bench_size)
+            {
+                Some("Bench is full".to_string())
+            } else {
+                None
+            };
+
+            if let Some(message) = message {
+                return Err(RuleViolation {
+                    rule_name: self.name().to_string(),
+                    message,
+                    severity: ViolationSeverity::Error,
+                });
+            }
         }
         Ok(())
     }
@@ -136,4 +303,282 @@ impl Rule for EnergyAttachmentRule {
     fn apply_effect(&self, _game: &mut Game, _action: &GameAction) -> RuleResult {
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Rule: Pokemon evolve only on their owner's turn, never during setup
+#[derive(Clone)]
+pub struct EvolveRule;
+
+impl Rule for EvolveRule {
+    fn name(&self) -> &str {
+        "Evolve"
+    }
+
+    fn validate_action(&self, game: &Game, action: &GameAction) -> RuleResult {
+        if let GameAction::Evolve { player_id, .. } = action {
+            let message = if game.state == GameState::Setup {
+                Some("Cannot evolve during setup")
+            } else if !game.is_player_turn(*player_id) {
+                Some("Can only evolve on your own turn")
+            } else {
+                None
+            };
+
+            if let Some(message) = message {
+                return Err(RuleViolation {
+                    rule_name: self.name().to_string(),
+                    message: message.to_string(),
+                    severity: ViolationSeverity::Error,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_effect(&self, _game: &mut Game, _action: &GameAction) -> RuleResult {
+        Ok(())
+    }
+}
+
+/// Rule: The first player may not attack or play a Supporter during their
+/// restricted opening turns
+///
+/// Each restriction applies only when `rules.first_player_can_attack` or
+/// `rules.first_player_can_supporter` is off, for turns up to
+/// `rules.first_turn_number_threshold`.
+#[derive(Clone)]
+pub struct FirstTurnRule;
+
+impl Rule for FirstTurnRule {
+    fn name(&self) -> &str {
+        "FirstTurn"
+    }
+
+    fn validate_action(&self, game: &Game, action: &GameAction) -> RuleResult {
+        if !game.first_player_restricted() {
+            return Ok(());
+        }
+
+        let forbidden = match action {
+            GameAction::UseAttack { .. } if !game.rules.first_player_can_attack => Some("attack"),
+            GameAction::PlayCard { card_id, .. } if !game.rules.first_player_can_supporter => game
+                .get_card(*card_id)
+                .filter(|card| {
+                    matches!(
+                        card.card_type,
+                        CardType::Trainer {
+                            trainer_type: TrainerType::Supporter
+                        }
+                    )
+                })
+                .map(|_| "play a Supporter"),
+            _ => None,
+        };
+
+        if let Some(forbidden) = forbidden {
+            return Err(RuleViolation {
+                rule_name: self.name().to_string(),
+                message: format!(
+                    "The first player cannot {} until after turn {}",
+                    forbidden, game.rules.first_turn_number_threshold
+                ),
+                severity: ViolationSeverity::Error,
+            });
+        }
+        Ok(())
+    }
+
+    fn apply_effect(&self, _game: &mut Game, _action: &GameAction) -> RuleResult {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::player::Player;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_rebuild_engine_from_descriptor() {
+        let engine = StandardRules::create_engine();
+        let descriptor = engine.describe();
+
+        let rebuilt = RuleEngine::from_descriptor(descriptor.clone(), &StandardRules::registry()).unwrap();
+        assert_eq!(rebuilt.get_rule_names(), engine.get_rule_names());
+        assert_eq!(rebuilt.describe(), descriptor);
+
+        let mut unknown = descriptor;
+        unknown.rule_names.push("Missing".to_string());
+        assert!(RuleEngine::from_descriptor(unknown, &StandardRules::registry()).is_err());
+    }
+
+    /// House rule: nobody may retreat
+    #[derive(Clone)]
+    struct NoRetreatRule;
+
+    impl Rule for NoRetreatRule {
+        fn name(&self) -> &str {
+            "NoRetreat"
+        }
+
+        fn validate_action(&self, _game: &Game, action: &GameAction) -> RuleResult {
+            if let GameAction::Retreat { .. } = action {
+                return Err(RuleViolation {
+                    rule_name: self.name().to_string(),
+                    message: "Retreating is disabled".to_string(),
+                    severity: ViolationSeverity::Error,
+                });
+            }
+            Ok(())
+        }
+
+        fn apply_effect(&self, _game: &mut Game, _action: &GameAction) -> RuleResult {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_registry_with_custom_rule() {
+        let mut registry = StandardRules::registry();
+        assert!(!registry.contains("NoRetreat"));
+        registry.register("NoRetreat", || Box::new(NoRetreatRule));
+        assert_eq!(registry.build("NoRetreat").unwrap().name(), "NoRetreat");
+        assert!(registry.build("Missing").is_none());
+
+        let mut engine = StandardRules::create_engine();
+        engine.add_rule_by_name("NoRetreat", &registry).unwrap();
+        assert!(engine.add_rule_by_name("Missing", &registry).is_err());
+        let rebuilt = RuleEngine::from_descriptor(engine.describe(), &registry).unwrap();
+        assert!(rebuilt.has_rule("NoRetreat"));
+
+        let game = Game::new();
+        let retreat = GameAction::Retreat {
+            player_id: Uuid::new_v4(),
+            pokemon_id: Uuid::new_v4(),
+        };
+        assert!(
+            rebuilt
+                .validate_action(&game, &retreat)
+                .iter()
+                .any(|violation| violation.rule_name == "NoRetreat")
+        );
+    }
+
+    #[test]
+    fn test_is_action_legal_for_draws() {
+        let mut game = Game::new();
+        let alice = Player::new("Alice".to_string());
+        let bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+        game.turn_order = vec![alice_id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.state = GameState::InProgress;
+        let engine = StandardRules::create_engine();
+
+        let alice_draw = GameAction::DrawCard { player_id: alice_id };
+        let bob_draw = GameAction::DrawCard { player_id: bob_id };
+        assert!(engine.is_action_legal(&game, &alice_draw));
+        assert_eq!(engine.first_violation(&game, &alice_draw), None);
+        assert!(!engine.is_action_legal(&game, &bob_draw));
+        assert_eq!(
+            engine.first_violation(&game, &bob_draw).map(|v| v.rule_name),
+            Some("TurnOrder".to_string())
+        );
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn test_descriptor_json_round_trip() {
+        let descriptor = StandardRules::create_engine().describe();
+
+        let json = serde_json::to_string(&descriptor).unwrap();
+        let parsed: crate::core::rules::RuleEngineDescriptor = serde_json::from_str(&json).unwrap();
+        let rebuilt = RuleEngine::from_descriptor(parsed, &StandardRules::registry()).unwrap();
+
+        assert_eq!(rebuilt.describe(), descriptor);
+    }
+
+    #[test]
+    fn test_energy_cannot_attach_to_non_pokemon() {
+        use crate::core::card::EnergyType;
+        use crate::test_fixtures::{basic_energy, trainer};
+
+        let mut game = Game::new();
+        let mut alice = Player::new("Alice".to_string());
+        let alice_id = alice.id;
+        let energy = basic_energy(EnergyType::Fire);
+        let trainer = trainer("Potion", TrainerType::Item);
+        let (energy_id, trainer_id) = (energy.id, trainer.id);
+        alice.hand.push(energy_id);
+        alice.active_pokemon = Some(trainer_id);
+        game.add_card_to_database(energy);
+        game.add_card_to_database(trainer);
+        game.turn_order = vec![alice_id];
+        game.add_player(alice).unwrap();
+        game.state = GameState::InProgress;
+
+        let attach = GameAction::AttachEnergy {
+            player_id: alice_id,
+            energy_id,
+            pokemon_id: trainer_id,
+        };
+        assert_eq!(
+            EnergyAttachmentRule.validate_action(&game, &attach),
+            Err(RuleViolation {
+                rule_name: "EnergyAttachment".to_string(),
+                message: "Target is not a Pokemon".to_string(),
+                severity: ViolationSeverity::Error,
+            })
+        );
+    }
+
+    #[test]
+    fn test_special_conditions_block_attack_and_retreat() {
+        let mut game = Game::new();
+        let mut alice = Player::new("Alice".to_string());
+        let alice_id = alice.id;
+        let (active_id, benched_id) = (Uuid::new_v4(), Uuid::new_v4());
+        alice.active_pokemon = Some(active_id);
+        alice.bench.push(benched_id);
+        game.turn_order = vec![alice_id];
+        game.add_player(alice).unwrap();
+        game.state = GameState::InProgress;
+        let engine = StandardRules::create_engine();
+
+        let attack = GameAction::UseAttack {
+            player_id: alice_id,
+            pokemon_id: active_id,
+            attack_index: 0,
+            target: None,
+        };
+        let retreat = GameAction::Retreat {
+            player_id: alice_id,
+            pokemon_id: benched_id,
+        };
+        let special_condition_violations = |game: &Game, action: &GameAction| {
+            engine
+                .validate_action(game, action)
+                .into_iter()
+                .filter(|v| v.rule_name == "SpecialCondition")
+                .map(|v| v.message)
+                .collect::<Vec<_>>()
+        };
+        assert!(special_condition_violations(&game, &attack).is_empty());
+        assert!(special_condition_violations(&game, &retreat).is_empty());
+
+        let alice = game.get_player_mut(alice_id).unwrap();
+        alice.add_special_condition(active_id, SpecialCondition::Paralyzed, 1, 1);
+        alice.add_special_condition(active_id, SpecialCondition::Trapped, -1, 1);
+
+        assert_eq!(
+            special_condition_violations(&game, &attack),
+            vec!["Pokemon is paralyzed and cannot attack".to_string()]
+        );
+        assert_eq!(
+            special_condition_violations(&game, &retreat),
+            vec!["Pokemon is trapped and cannot retreat".to_string()]
+        );
+    }
+}
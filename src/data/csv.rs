@@ -1,14 +1,55 @@
-//! CSV data import functionality
+//! CSV data import and export functionality
+//!
+//! Cards are stored one per row with a header line. The `attacks` column
+//! holds the card's attacks as a JSON array so that costs, damage modes and
+//! status effects survive a round trip.
 
 #[cfg(feature = "csv_import")]
-use crate::data::{DataImporter, ImportError, SourceInfo};
+use crate::data::{DataImporter, ExportError, ImportError, SourceInfo};
+
+#[cfg(feature = "csv_import")]
+use crate::core::card::{
+    Attack, CardId, CardRarity, CardType, EnergyType, EvolutionStage, TrainerType,
+};
 
 #[cfg(feature = "csv_import")]
 use crate::core::Card;
 
+#[cfg(feature = "csv_import")]
+use serde::{Deserialize, Serialize};
+
+#[cfg(feature = "csv_import")]
+use std::collections::HashMap;
+
 #[cfg(feature = "csv_import")]
 use std::path::Path;
 
+/// One CSV row describing a card
+#[cfg(feature = "csv_import")]
+#[derive(Debug, Serialize, Deserialize)]
+struct CardRecord {
+    name: String,
+    #[serde(rename = "type")]
+    card_type: String,
+    hp: Option<u32>,
+    stage: Option<String>,
+    energy_type: Option<String>,
+    set: String,
+    number: String,
+    rarity: String,
+    attacks: String,
+    species: Option<String>,
+    retreat_cost: Option<u32>,
+    weakness: Option<String>,
+    resistance: Option<String>,
+    evolves_from: Option<String>,
+    trainer_type: Option<String>,
+    basic: Option<bool>,
+    /// Localized names as a JSON object of locale to name
+    #[serde(default)]
+    names: Option<String>,
+}
+
 /// CSV importer for card data
 #[cfg(feature = "csv_import")]
 pub struct CsvImporter {
@@ -22,18 +63,33 @@ impl CsvImporter {
             file_path: file_path.as_ref().to_string_lossy().to_string(),
         }
     }
+
+    /// Parse cards from CSV text in the format written by `CsvExporter`
+    pub fn import_from_str(data: &str) -> Result<Vec<Card>, ImportError> {
+        let mut reader = csv::Reader::from_reader(data.as_bytes());
+        let mut cards = Vec::new();
+
+        for record in reader.deserialize() {
+            let record: CardRecord = record.map_err(|e| ImportError::Parse(e.to_string()))?;
+            cards.push(record_to_card(record)?);
+        }
+
+        Ok(cards)
+    }
 }
 
 #[cfg(feature = "csv_import")]
 impl DataImporter for CsvImporter {
     fn import_cards(&self) -> Result<Vec<Card>, ImportError> {
-        // Placeholder implementation
-        // In a real implementation, this would parse CSV and create Card objects
-        Ok(Vec::new())
+        let data = std::fs::read_to_string(&self.file_path)?;
+        Self::import_from_str(&data)
     }
 
-    fn import_card(&self, _identifier: &str) -> Result<Option<Card>, ImportError> {
-        Ok(None)
+    fn import_card(&self, identifier: &str) -> Result<Option<Card>, ImportError> {
+        Ok(self
+            .import_cards()?
+            .into_iter()
+            .find(|card| card.name == identifier))
     }
 
     fn source_info(&self) -> SourceInfo {
@@ -45,3 +101,289 @@ impl DataImporter for CsvImporter {
         }
     }
 }
+
+/// CSV exporter for card data, the inverse of `CsvImporter`
+#[cfg(feature = "csv_import")]
+pub struct CsvExporter;
+
+#[cfg(feature = "csv_import")]
+impl CsvExporter {
+    /// Export cards to CSV text, ordered by set, number and name
+    pub fn export_cards(cards: &HashMap<CardId, Card>) -> Result<String, ExportError> {
+        let mut sorted: Vec<&Card> = cards.values().collect();
+        sorted.sort_by(|a, b| {
+            (&a.set_name, &a.set_number, &a.name).cmp(&(&b.set_name, &b.set_number, &b.name))
+        });
+
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        for card in sorted {
+            writer
+                .serialize(card_to_record(card)?)
+                .map_err(|e| ExportError::Serialization(e.to_string()))?;
+        }
+
+        let bytes = writer
+            .into_inner()
+            .map_err(|e| ExportError::Serialization(e.to_string()))?;
+        String::from_utf8(bytes).map_err(|e| ExportError::Serialization(e.to_string()))
+    }
+}
+
+#[cfg(feature = "csv_import")]
+fn card_to_record(card: &Card) -> Result<CardRecord, ExportError> {
+    let attacks = serde_json::to_string(&card.attacks)
+        .map_err(|e| ExportError::Serialization(e.to_string()))?;
+    let mut record = CardRecord {
+        name: card.name.clone(),
+        card_type: String::new(),
+        hp: None,
+        stage: None,
+        energy_type: None,
+        set: card.set_name.clone(),
+        number: card.set_number.clone(),
+        rarity: format!("{:?}", card.rarity),
+        attacks,
+        species: None,
+        retreat_cost: None,
+        weakness: None,
+        resistance: None,
+        evolves_from: None,
+        trainer_type: None,
+        basic: None,
+        names: None,
+    };
+    if !card.names.is_empty() {
+        // Sorted so exports are stable
+        let names: std::collections::BTreeMap<_, _> = card.names.iter().collect();
+        record.names =
+            Some(serde_json::to_string(&names).map_err(|e| ExportError::Serialization(e.to_string()))?);
+    }
+
+    match &card.card_type {
+        CardType::Pokemon {
+            species,
+            hp,
+            pokemon_type,
+            retreat_cost,
+            weakness,
+            resistance,
+            stage,
+            evolves_from,
+        } => {
+            record.card_type = "Pokemon".to_string();
+            record.hp = Some(*hp);
+            record.stage = Some(format!("{:?}", stage));
+            record.energy_type = pokemon_type.as_ref().map(|energy| energy.to_string());
+            record.species = Some(species.clone());
+            record.retreat_cost = Some(*retreat_cost);
+            record.weakness = weakness.as_ref().map(|energy| energy.to_string());
+            record.resistance = resistance.as_ref().map(|energy| energy.to_string());
+            record.evolves_from = evolves_from.clone();
+        }
+        CardType::Energy {
+            energy_type,
+            is_basic,
+        } => {
+            record.card_type = "Energy".to_string();
+            record.energy_type = Some(energy_type.to_string());
+            record.basic = Some(*is_basic);
+        }
+        CardType::Trainer { trainer_type } => {
+            record.card_type = "Trainer".to_string();
+            record.trainer_type = Some(format!("{:?}", trainer_type));
+        }
+    }
+
+    Ok(record)
+}
+
+#[cfg(feature = "csv_import")]
+fn record_to_card(record: CardRecord) -> Result<Card, ImportError> {
+    let energy = |value: Option<&String>| -> Result<Option<EnergyType>, ImportError> {
+        value
+            .map(|value| value.parse::<EnergyType>().map_err(ImportError::Parse))
+            .transpose()
+    };
+
+    let card_type = match record.card_type.as_str() {
+        "Pokemon" => CardType::Pokemon {
+            species: record.species.clone().unwrap_or_else(|| record.name.clone()),
+            hp: record.hp.ok_or_else(|| ImportError::MissingField("hp".to_string()))?,
+            pokemon_type: energy(record.energy_type.as_ref())?,
+            retreat_cost: record.retreat_cost.unwrap_or(0),
+            weakness: energy(record.weakness.as_ref())?,
+            resistance: energy(record.resistance.as_ref())?,
+            stage: parse_stage(record.stage.as_deref().unwrap_or("Basic"))?,
+            evolves_from: record.evolves_from.clone(),
+        },
+        "Energy" => CardType::Energy {
+            energy_type: energy(record.energy_type.as_ref())?
+                .ok_or_else(|| ImportError::MissingField("energy_type".to_string()))?,
+            is_basic: record.basic.unwrap_or(true),
+        },
+        "Trainer" => CardType::Trainer {
+            trainer_type: parse_trainer_type(
+                record
+                    .trainer_type
+                    .as_deref()
+                    .ok_or_else(|| ImportError::MissingField("trainer_type".to_string()))?,
+            )?,
+        },
+        other => {
+            return Err(ImportError::InvalidFormat(format!("Unknown card type: {}", other)));
+        }
+    };
+
+    let mut card = Card::with_id(
+        Card::printing_id(&record.set, &record.number),
+        record.name,
+        card_type,
+        record.set,
+        record.number,
+        parse_rarity(&record.rarity)?,
+    );
+    if let Some(names) = record.names.as_deref().filter(|names| !names.is_empty()) {
+        card.names = serde_json::from_str(names).map_err(|e| ImportError::Parse(e.to_string()))?;
+    }
+    if !record.attacks.is_empty() {
+        card.attacks = serde_json::from_str::<Vec<Attack>>(&record.attacks)
+            .map_err(|e| ImportError::Parse(e.to_string()))?;
+    }
+
+    Ok(card)
+}
+
+#[cfg(feature = "csv_import")]
+fn parse_stage(value: &str) -> Result<EvolutionStage, ImportError> {
+    Ok(match value {
+        "Basic" => EvolutionStage::Basic,
+        "Stage1" => EvolutionStage::Stage1,
+        "Stage2" => EvolutionStage::Stage2,
+        "Mega" => EvolutionStage::Mega,
+        "GX" => EvolutionStage::GX,
+        "EX" => EvolutionStage::EX,
+        "V" => EvolutionStage::V,
+        "VMax" => EvolutionStage::VMax,
+        other => return Err(ImportError::Parse(format!("Unknown stage: {}", other))),
+    })
+}
+
+#[cfg(feature = "csv_import")]
+fn parse_trainer_type(value: &str) -> Result<TrainerType, ImportError> {
+    Ok(match value {
+        "Item" => TrainerType::Item,
+        "Supporter" => TrainerType::Supporter,
+        "Stadium" => TrainerType::Stadium,
+        "Tool" => TrainerType::Tool,
+        other => return Err(ImportError::Parse(format!("Unknown trainer type: {}", other))),
+    })
+}
+
+#[cfg(feature = "csv_import")]
+fn parse_rarity(value: &str) -> Result<CardRarity, ImportError> {
+    Ok(match value {
+        "Common" => CardRarity::Common,
+        "Uncommon" => CardRarity::Uncommon,
+        "Rare" => CardRarity::Rare,
+        "RareHolo" => CardRarity::RareHolo,
+        "UltraRare" => CardRarity::UltraRare,
+        "SecretRare" => CardRarity::SecretRare,
+        "Promo" => CardRarity::Promo,
+        other => return Err(ImportError::Parse(format!("Unknown rarity: {}", other))),
+    })
+}
+
+#[cfg(all(test, feature = "csv_import"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_import_round_trip() {
+        let mut pikachu = Card::new(
+            "Pikachu".to_string(),
+            CardType::Pokemon {
+                species: "Pikachu".to_string(),
+                hp: 60,
+                pokemon_type: Some(EnergyType::Lightning),
+                retreat_cost: 1,
+                weakness: Some(EnergyType::Fighting),
+                resistance: None,
+                stage: EvolutionStage::Basic,
+                evolves_from: None,
+            },
+            "Base Set".to_string(),
+            "58".to_string(),
+            CardRarity::Common,
+        );
+        pikachu.set_localized_name("zh", "皮卡丘");
+        pikachu.add_attack(Attack::coin_flip_damage(
+            "Thunder Jolt, Again".to_string(),
+            vec![EnergyType::Lightning, EnergyType::Colorless],
+            10,
+            20,
+            2,
+        ));
+        // No printed type: the export must not fill one in from the attack cost
+        let mut ditto = Card::new(
+            "Ditto".to_string(),
+            CardType::Pokemon {
+                species: "Ditto".to_string(),
+                hp: 50,
+                pokemon_type: None,
+                retreat_cost: 1,
+                weakness: None,
+                resistance: None,
+                stage: EvolutionStage::Basic,
+                evolves_from: None,
+            },
+            "Fossil".to_string(),
+            "3".to_string(),
+            CardRarity::RareHolo,
+        );
+        ditto.add_attack(Attack::simple("Ember".to_string(), vec![EnergyType::Fire], 30));
+        let energy = Card::new(
+            "Lightning Energy".to_string(),
+            CardType::Energy {
+                energy_type: EnergyType::Lightning,
+                is_basic: true,
+            },
+            "Base Set".to_string(),
+            "100".to_string(),
+            CardRarity::Common,
+        );
+        let trainer = Card::new(
+            "Professor Oak".to_string(),
+            CardType::Trainer {
+                trainer_type: TrainerType::Supporter,
+            },
+            "Base Set".to_string(),
+            "88".to_string(),
+            CardRarity::Uncommon,
+        );
+
+        let cards: HashMap<CardId, Card> = [pikachu, ditto, energy, trainer]
+            .into_iter()
+            .map(|card| (card.id, card))
+            .collect();
+        let csv = CsvExporter::export_cards(&cards).unwrap();
+        let imported = CsvImporter::import_from_str(&csv).unwrap();
+
+        assert_eq!(imported.len(), 4);
+        for card in &imported {
+            let original = cards
+                .values()
+                .find(|original| original.set_number == card.set_number)
+                .unwrap();
+            assert_eq!(card.name, original.name);
+            assert_eq!(card.card_type, original.card_type);
+            assert_eq!(card.set_name, original.set_name);
+            assert_eq!(card.rarity, original.rarity);
+            assert_eq!(card.attacks, original.attacks);
+            assert_eq!(card.names, original.names);
+        }
+        let pikachu = imported.iter().find(|card| card.name == "Pikachu").unwrap();
+        assert_eq!(pikachu.pokemon_type(), Some(EnergyType::Lightning));
+        let ditto = imported.iter().find(|card| card.name == "Ditto").unwrap();
+        assert_eq!(ditto.pokemon_type(), None);
+    }
+}
@@ -1,11 +1,28 @@
 //! Database import functionality
+//!
+//! Cards are stored in SQLite across two tables: `cards` (one row per card)
+//! and `attacks` (one row per attack, ordered by `position`). Structured
+//! fields without a natural column (card type details, abilities, metadata,
+//! attack damage modes) are stored as JSON text.
 
 #[cfg(feature = "database")]
 use crate::data::{DataImporter, ImportError, SourceInfo};
 
+#[cfg(feature = "database")]
+use crate::core::card::{CardDatabase, CardId, CardType};
+
 #[cfg(feature = "database")]
 use crate::core::Card;
 
+#[cfg(feature = "database")]
+use rusqlite::{params, Connection};
+
+#[cfg(feature = "database")]
+use std::cell::RefCell;
+
+#[cfg(feature = "database")]
+use std::collections::HashMap;
+
 #[cfg(feature = "database")]
 use std::path::Path;
 
@@ -13,38 +30,352 @@ use std::path::Path;
 #[cfg(feature = "database")]
 pub struct DatabaseImporter {
     file_path: String,
+    connection: Connection,
+    /// Cards loaded by `load_all`, indexed by id, name and printing
+    cache: RefCell<Option<CardDatabase>>,
 }
 
+#[cfg(feature = "database")]
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS cards (
+        id TEXT PRIMARY KEY,
+        name TEXT NOT NULL,
+        supertype TEXT NOT NULL,
+        hp INTEGER,
+        card_type TEXT NOT NULL,
+        set_name TEXT NOT NULL,
+        set_number TEXT NOT NULL,
+        rarity TEXT NOT NULL,
+        abilities TEXT NOT NULL,
+        rules TEXT NOT NULL,
+        metadata TEXT NOT NULL
+    );
+    CREATE INDEX IF NOT EXISTS cards_printing ON cards (set_name, set_number);
+    CREATE TABLE IF NOT EXISTS attacks (
+        card_id TEXT NOT NULL REFERENCES cards (id) ON DELETE CASCADE,
+        position INTEGER NOT NULL,
+        name TEXT NOT NULL,
+        cost TEXT NOT NULL,
+        damage INTEGER NOT NULL,
+        effect TEXT,
+        details TEXT NOT NULL,
+        PRIMARY KEY (card_id, position)
+    );
+";
+
 #[cfg(feature = "database")]
 impl DatabaseImporter {
-    pub fn new<P: AsRef<Path>>(file_path: P) -> Self {
-        Self {
+    /// Open (or create) a SQLite database file
+    pub fn new<P: AsRef<Path>>(file_path: P) -> Result<Self, ImportError> {
+        let connection = Connection::open(file_path.as_ref())?;
+        Ok(Self {
             file_path: file_path.as_ref().to_string_lossy().to_string(),
+            connection,
+            cache: RefCell::new(None),
+        })
+    }
+
+    /// Open a private in-memory database
+    pub fn in_memory() -> Result<Self, ImportError> {
+        Ok(Self {
+            file_path: ":memory:".to_string(),
+            connection: Connection::open_in_memory()?,
+            cache: RefCell::new(None),
+        })
+    }
+
+    /// Create the `cards` and `attacks` tables if they do not exist
+    pub fn create_schema(&self) -> Result<(), ImportError> {
+        self.connection.execute_batch(SCHEMA)?;
+        Ok(())
+    }
+
+    /// Insert or replace a single card together with its attacks
+    pub fn insert_card(&self, card: &Card) -> Result<(), ImportError> {
+        self.insert_cards(std::slice::from_ref(card))
+    }
+
+    /// Insert or replace many cards in a single transaction
+    ///
+    /// Invalidates the card cache.
+    pub fn insert_cards(&self, cards: &[Card]) -> Result<(), ImportError> {
+        self.cache.replace(None);
+        let transaction = self.connection.unchecked_transaction()?;
+        {
+            let mut insert_card = transaction.prepare_cached(
+                "INSERT OR REPLACE INTO cards
+                    (id, name, supertype, hp, card_type, set_name, set_number, rarity, abilities, rules, metadata)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+            )?;
+            let mut delete_attacks = transaction.prepare_cached("DELETE FROM attacks WHERE card_id = ?1")?;
+            let mut insert_attack = transaction.prepare_cached(
+                "INSERT INTO attacks (card_id, position, name, cost, damage, effect, details)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            )?;
+
+            for card in cards {
+                let id = card.id.to_string();
+                let supertype = match card.card_type {
+                    CardType::Pokemon { .. } => "Pokemon",
+                    CardType::Energy { .. } => "Energy",
+                    CardType::Trainer { .. } => "Trainer",
+                };
+                insert_card.execute(params![
+                    id,
+                    card.name,
+                    supertype,
+                    card.get_hp(),
+                    serde_json::to_string(&card.card_type)?,
+                    card.set_name,
+                    card.set_number,
+                    serde_json::to_string(&card.rarity)?,
+                    serde_json::to_string(&card.abilities)?,
+                    serde_json::to_string(&card.rules)?,
+                    serde_json::to_string(&card.metadata)?,
+                ])?;
+
+                delete_attacks.execute(params![id])?;
+                for (position, attack) in card.attacks.iter().enumerate() {
+                    let cost: String = attack.cost.iter().map(|energy| energy.symbol()).collect();
+                    insert_attack.execute(params![
+                        id,
+                        position as i64,
+                        attack.name,
+                        cost,
+                        attack.damage,
+                        attack.effect,
+                        serde_json::to_string(attack)?,
+                    ])?;
+                }
+            }
+        }
+        transaction.commit()?;
+        Ok(())
+    }
+
+    /// Read every card (with its attacks) from the database
+    pub fn import_all(&self) -> Result<Vec<Card>, ImportError> {
+        self.load_cards(None)
+    }
+
+    /// Load every card into the in-memory cache and return them by id
+    ///
+    /// Later calls to `load_all`, `find_by_name` and `find_by_printing` are
+    /// answered from the cache until cards are inserted again.
+    pub fn load_all(&self) -> Result<HashMap<CardId, Card>, ImportError> {
+        Ok(self.cached()?.cards().clone())
+    }
+
+    /// Find all printings of a card by name, using the cache
+    pub fn find_by_name(&self, name: &str) -> Result<Vec<Card>, ImportError> {
+        Ok(self
+            .cached()?
+            .find_by_name(name)
+            .into_iter()
+            .cloned()
+            .collect())
+    }
+
+    /// Find a card by set name and number, using the cache
+    pub fn find_by_printing(&self, set: &str, number: &str) -> Result<Option<Card>, ImportError> {
+        Ok(self.cached()?.get_by_printing(set, number).cloned())
+    }
+
+    /// Borrow the card cache, filling it from the database on first use
+    fn cached(&self) -> Result<std::cell::Ref<'_, CardDatabase>, ImportError> {
+        if self.cache.borrow().is_none() {
+            let database = self.load_cards(None)?.into_iter().collect();
+            self.cache.replace(Some(database));
         }
+        Ok(std::cell::Ref::map(self.cache.borrow(), |cache| {
+            cache.as_ref().expect("cache was just filled")
+        }))
+    }
+
+    /// Load cards, optionally only those whose id or name matches `identifier`
+    ///
+    /// Cards and their attacks are read with a single joined query inside one
+    /// read transaction.
+    fn load_cards(&self, identifier: Option<&str>) -> Result<Vec<Card>, ImportError> {
+        let transaction = self.connection.unchecked_transaction()?;
+        let mut cards: Vec<Card> = Vec::new();
+        {
+            let mut statement = transaction.prepare_cached(
+                "SELECT c.id, c.name, c.card_type, c.set_name, c.set_number, c.rarity,
+                        c.abilities, c.rules, c.metadata, a.details
+                 FROM cards c LEFT JOIN attacks a ON a.card_id = c.id
+                 WHERE ?1 IS NULL OR c.id = ?1 OR c.name = ?1
+                 ORDER BY c.set_name, c.set_number, c.name, c.id, a.position",
+            )?;
+            let mut rows = statement.query(params![identifier])?;
+
+            while let Some(row) = rows.next()? {
+                let id: CardId = row
+                    .get::<_, String>(0)?
+                    .parse()
+                    .map_err(|e: uuid::Error| ImportError::Parse(e.to_string()))?;
+
+                // Rows of the same card are adjacent; start a new card on a new id
+                if cards.last().is_none_or(|card| card.id != id) {
+                    let mut card = Card::with_id(
+                        id,
+                        row.get(1)?,
+                        serde_json::from_str(&row.get::<_, String>(2)?)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                        serde_json::from_str(&row.get::<_, String>(5)?)?,
+                    );
+                    card.abilities = serde_json::from_str(&row.get::<_, String>(6)?)?;
+                    card.rules = serde_json::from_str(&row.get::<_, String>(7)?)?;
+                    card.metadata = serde_json::from_str(&row.get::<_, String>(8)?)?;
+                    cards.push(card);
+                }
+
+                if let Some(details) = row.get::<_, Option<String>>(9)?
+                    && let Some(card) = cards.last_mut()
+                {
+                    card.attacks.push(serde_json::from_str(&details)?);
+                }
+            }
+        }
+        transaction.commit()?;
+
+        Ok(cards)
     }
 }
 
 #[cfg(feature = "database")]
 impl DataImporter for DatabaseImporter {
     fn import_cards(&self) -> Result<Vec<Card>, ImportError> {
-        // Placeholder implementation
-        // In a real implementation, this would:
-        // 1. Open the database file
-        // 2. Query card data
-        // 3. Convert to Card objects
-        Ok(Vec::new())
+        self.import_all()
     }
 
-    fn import_card(&self, _identifier: &str) -> Result<Option<Card>, ImportError> {
-        Ok(None)
+    fn import_card(&self, identifier: &str) -> Result<Option<Card>, ImportError> {
+        Ok(self.load_cards(Some(identifier))?.into_iter().next())
     }
 
     fn source_info(&self) -> SourceInfo {
+        let card_count = self
+            .connection
+            .query_row("SELECT COUNT(*) FROM cards", [], |row| row.get::<_, i64>(0))
+            .ok()
+            .map(|count| count as usize);
+
         SourceInfo {
             name: self.file_path.clone(),
             format: "Database".to_string(),
             version: "1.0".to_string(),
-            card_count: None,
+            card_count,
         }
     }
 }
+
+#[cfg(all(test, feature = "database"))]
+mod tests {
+    use super::*;
+    use crate::core::card::{Attack, CardRarity, EnergyType, EvolutionStage, TrainerType};
+
+    #[test]
+    fn test_insert_and_read_back() {
+        let importer = DatabaseImporter::in_memory().unwrap();
+        importer.create_schema().unwrap();
+
+        let mut charmander = Card::new(
+            "Charmander".to_string(),
+            CardType::Pokemon {
+                species: "Charmander".to_string(),
+                hp: 50,
+                pokemon_type: None,
+                retreat_cost: 1,
+                weakness: Some(EnergyType::Water),
+                resistance: None,
+                stage: EvolutionStage::Basic,
+                evolves_from: None,
+            },
+            "Base Set".to_string(),
+            "46".to_string(),
+            CardRarity::Common,
+        );
+        charmander.add_attack(Attack::simple(
+            "Scratch".to_string(),
+            vec![EnergyType::Colorless],
+            10,
+        ));
+        charmander.add_attack(Attack::coin_flip_damage(
+            "Ember".to_string(),
+            vec![EnergyType::Fire, EnergyType::Colorless],
+            30,
+            10,
+            1,
+        ));
+        let bill = Card::new(
+            "Bill".to_string(),
+            CardType::Trainer {
+                trainer_type: TrainerType::Supporter,
+            },
+            "Base Set".to_string(),
+            "91".to_string(),
+            CardRarity::Common,
+        );
+
+        importer.insert_card(&charmander).unwrap();
+        importer.insert_card(&bill).unwrap();
+
+        let cards = importer.import_all().unwrap();
+        assert_eq!(cards, vec![charmander.clone(), bill]);
+        assert_eq!(cards[0].attacks, charmander.attacks);
+        assert_eq!(importer.source_info().card_count, Some(2));
+        assert_eq!(
+            importer.import_card("Charmander").unwrap().map(|card| card.id),
+            Some(charmander.id)
+        );
+    }
+
+    #[test]
+    fn test_cached_lookups() {
+        let importer = DatabaseImporter::in_memory().unwrap();
+        importer.create_schema().unwrap();
+
+        let energy = |set: &str, number: &str| {
+            Card::with_id(
+                Card::printing_id(set, number),
+                "Grass Energy".to_string(),
+                CardType::Energy {
+                    energy_type: EnergyType::Grass,
+                    is_basic: true,
+                },
+                set.to_string(),
+                number.to_string(),
+                CardRarity::Common,
+            )
+        };
+        let cards = vec![
+            energy("Base Set", "99"),
+            energy("Jungle", "99"),
+            Card::new(
+                "Potion".to_string(),
+                CardType::Trainer {
+                    trainer_type: TrainerType::Item,
+                },
+                "Base Set".to_string(),
+                "94".to_string(),
+                CardRarity::Common,
+            ),
+        ];
+        importer.insert_cards(&cards).unwrap();
+
+        let all = importer.load_all().unwrap();
+        assert_eq!(all.len(), 3);
+        assert!(cards.iter().all(|card| all.get(&card.id) == Some(card)));
+
+        let mut grass = importer.find_by_name("Grass Energy").unwrap();
+        grass.sort_by(|a, b| a.set_name.cmp(&b.set_name));
+        assert_eq!(grass, cards[..2].to_vec());
+        assert_eq!(importer.find_by_printing("Base Set", "94").unwrap(), Some(cards[2].clone()));
+        assert_eq!(importer.find_by_printing("Fossil", "1").unwrap(), None);
+
+        // Inserting invalidates the cache
+        importer.insert_card(&energy("Fossil", "1")).unwrap();
+        assert!(importer.find_by_printing("Fossil", "1").unwrap().is_some());
+    }
+}
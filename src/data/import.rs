@@ -42,7 +42,7 @@ pub enum ImportError {
     #[error("Source not found: {0}")]
     SourceNotFound(String),
 
-    #[cfg(feature = "json")]
+    #[cfg(feature = "serde_json")]
     #[error("JSON error: {0}")]
     Json(#[from] serde_json::Error),
 
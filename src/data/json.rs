@@ -7,7 +7,13 @@ use serde_json;
 use crate::data::{DataExporter, DataImporter, ExportError, ImportError, SourceInfo};
 
 #[cfg(feature = "json")]
-use crate::core::Card;
+use crate::core::{
+    Ability, Attack, Card, CardDatabase, CardRarity, CardType, DamageMode, Deck, EnergyType,
+    EvolutionStage, TrainerType,
+};
+
+#[cfg(feature = "json")]
+use serde::Deserialize;
 
 #[cfg(feature = "json")]
 use std::path::Path;
@@ -27,11 +33,475 @@ impl JsonImporter {
     }
 }
 
+/// A card entry that was skipped while importing a JSON file
+#[cfg(feature = "json")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportWarning {
+    /// Position of the entry in the file's card array
+    pub index: usize,
+    /// Card name, if the entry had one
+    pub name: Option<String>,
+    /// Why the entry was rejected
+    pub message: String,
+}
+
+#[cfg(feature = "json")]
+impl JsonImporter {
+    /// Import a file of cards in the engine's own JSON format, skipping bad entries
+    ///
+    /// Each entry of the top-level array is validated on its own, so valid
+    /// cards still load when others are malformed. Rejected entries are
+    /// reported as warnings. Fails only if the file cannot be read or is not
+    /// a JSON array.
+    pub fn import_with_report<P: AsRef<Path>>(
+        path: P,
+    ) -> Result<(Vec<Card>, Vec<ImportWarning>), crate::Error> {
+        let content = std::fs::read_to_string(path)?;
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&content)
+            .map_err(|e| crate::Error::Data(format!("expected an array of cards: {}", e)))?;
+
+        let mut cards = Vec::new();
+        let mut warnings = Vec::new();
+        for (index, entry) in entries.into_iter().enumerate() {
+            let name = entry
+                .get("name")
+                .and_then(|name| name.as_str())
+                .map(str::to_string);
+            match Self::parse_card(entry) {
+                Ok(card) => cards.push(card),
+                Err(error) => warnings.push(ImportWarning {
+                    index,
+                    name,
+                    message: error.to_string(),
+                }),
+            }
+        }
+
+        Ok((cards, warnings))
+    }
+
+    /// Strictly deserialize and validate a single card in the engine's JSON format
+    ///
+    /// Missing fields (such as a Pokemon's `hp`), unknown enum values (such as
+    /// an unknown `energy_type`) and a Pokemon with 0 HP are reported as
+    /// `Error::Data` naming the card.
+    pub fn parse_card(value: serde_json::Value) -> Result<Card, crate::Error> {
+        let name = value
+            .get("name")
+            .and_then(|name| name.as_str())
+            .unwrap_or("<unnamed>")
+            .to_string();
+        let card = card_from_value(value).map_err(|e| crate::Error::Data(format!("{}: {}", name, e)))?;
+
+        if card.get_hp() == Some(0) {
+            return Err(crate::Error::Data(format!("{}: Pokemon must have HP", name)));
+        }
+
+        Ok(card)
+    }
+}
+
+/// A decklist in the simple `{ name, format, cards: [{ set, number, count }] }` schema
+#[cfg(feature = "json")]
+#[derive(Deserialize)]
+struct JsonDecklist {
+    name: String,
+    format: String,
+    cards: Vec<JsonDecklistEntry>,
+}
+
+#[cfg(feature = "json")]
+#[derive(Deserialize)]
+struct JsonDecklistEntry {
+    set: String,
+    number: String,
+    count: i64,
+}
+
+#[cfg(feature = "json")]
+impl JsonImporter {
+    /// Build a deck from a JSON decklist, resolving cards by set and number
+    ///
+    /// Entries whose printing is not in `db` are left out of the deck and
+    /// returned as `"<set> <number>"` strings. A count below 1 is an
+    /// `Error::Data`.
+    pub fn import_deck(json: &str, db: &CardDatabase) -> Result<(Deck, Vec<String>), crate::Error> {
+        let decklist: JsonDecklist = serde_json::from_str(json)?;
+
+        let mut deck = Deck::new(decklist.name, decklist.format);
+        let mut unresolved = Vec::new();
+        for entry in decklist.cards {
+            let count = u32::try_from(entry.count).ok().filter(|&count| count > 0).ok_or_else(|| {
+                crate::Error::Data(format!(
+                    "{} {}: count must be positive, got {}",
+                    entry.set, entry.number, entry.count
+                ))
+            })?;
+
+            match db.get_by_printing(&entry.set, &entry.number) {
+                Some(card) => deck.add_card(card.id, count),
+                None => unresolved.push(format!("{} {}", entry.set, entry.number)),
+            }
+        }
+
+        Ok((deck, unresolved))
+    }
+}
+
+/// Result of importing cards in the pokemontcg.io API format
+#[cfg(feature = "json")]
+#[derive(Debug, Clone)]
+pub struct PokemonTcgImport {
+    /// Successfully mapped cards
+    pub cards: Vec<Card>,
+    /// Non-fatal problems found while mapping (e.g. unknown energy symbols)
+    pub warnings: Vec<String>,
+}
+
+#[cfg(feature = "json")]
+impl JsonImporter {
+    /// Import cards from the pokemontcg.io card JSON schema
+    ///
+    /// Accepts either an API response (`{ "data": [...] }`), a bare array of
+    /// cards, or a single card object. Unknown energy symbols are mapped to
+    /// `Colorless` and reported in `warnings`.
+    pub fn import_pokemontcg(json: &str) -> Result<PokemonTcgImport, ImportError> {
+        let payload: PtcgPayload = serde_json::from_str(json)?;
+        let entries = match payload {
+            PtcgPayload::Response { data } => data,
+            PtcgPayload::List(cards) => cards,
+            PtcgPayload::Single(card) => vec![*card],
+        };
+
+        let mut warnings = Vec::new();
+        let mut cards = Vec::with_capacity(entries.len());
+        for entry in entries {
+            cards.push(entry.into_card(&mut warnings)?);
+        }
+
+        Ok(PokemonTcgImport { cards, warnings })
+    }
+}
+
+#[cfg(feature = "json")]
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PtcgPayload {
+    Response { data: Vec<PtcgCard> },
+    List(Vec<PtcgCard>),
+    Single(Box<PtcgCard>),
+}
+
+#[cfg(feature = "json")]
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PtcgCard {
+    id: Option<String>,
+    name: String,
+    supertype: String,
+    #[serde(default)]
+    subtypes: Vec<String>,
+    hp: Option<String>,
+    #[serde(default)]
+    types: Vec<String>,
+    evolves_from: Option<String>,
+    #[serde(default)]
+    rules: Vec<String>,
+    #[serde(default)]
+    abilities: Vec<PtcgAbility>,
+    #[serde(default)]
+    attacks: Vec<PtcgAttack>,
+    #[serde(default)]
+    weaknesses: Vec<PtcgTypeValue>,
+    #[serde(default)]
+    resistances: Vec<PtcgTypeValue>,
+    #[serde(default)]
+    retreat_cost: Vec<String>,
+    converted_retreat_cost: Option<u32>,
+    set: Option<PtcgSet>,
+    #[serde(default)]
+    number: String,
+    rarity: Option<String>,
+}
+
+#[cfg(feature = "json")]
+#[derive(Deserialize)]
+struct PtcgAbility {
+    name: String,
+    #[serde(default)]
+    text: String,
+    #[serde(rename = "type", default)]
+    ability_type: String,
+}
+
+#[cfg(feature = "json")]
+#[derive(Deserialize)]
+struct PtcgAttack {
+    name: String,
+    #[serde(default)]
+    cost: Vec<String>,
+    #[serde(default)]
+    damage: String,
+    #[serde(default)]
+    text: String,
+}
+
+#[cfg(feature = "json")]
+#[derive(Deserialize)]
+struct PtcgTypeValue {
+    #[serde(rename = "type")]
+    energy_type: String,
+}
+
+#[cfg(feature = "json")]
+#[derive(Deserialize)]
+struct PtcgSet {
+    name: String,
+}
+
+#[cfg(feature = "json")]
+impl PtcgCard {
+    fn into_card(self, warnings: &mut Vec<String>) -> Result<Card, ImportError> {
+        let untyped = self.types.is_empty();
+        let card_type = match self.supertype.as_str() {
+            "Pokémon" | "Pokemon" => {
+                let hp = self
+                    .hp
+                    .as_deref()
+                    .ok_or_else(|| ImportError::MissingField(format!("{}: hp", self.name)))?
+                    .parse::<u32>()
+                    .map_err(|e| ImportError::Parse(format!("{}: invalid hp ({})", self.name, e)))?;
+
+                CardType::Pokemon {
+                    species: self.name.clone(),
+                    hp,
+                    pokemon_type: self
+                        .types
+                        .first()
+                        .map(|t| map_energy(t, &self.name, warnings)),
+                    retreat_cost: self
+                        .converted_retreat_cost
+                        .unwrap_or(self.retreat_cost.len() as u32),
+                    weakness: self
+                        .weaknesses
+                        .first()
+                        .map(|w| map_energy(&w.energy_type, &self.name, warnings)),
+                    resistance: self
+                        .resistances
+                        .first()
+                        .map(|r| map_energy(&r.energy_type, &self.name, warnings)),
+                    stage: map_stage(&self.subtypes),
+                    evolves_from: self.evolves_from.clone(),
+                }
+            }
+            "Energy" => {
+                let symbol = self
+                    .types
+                    .first()
+                    .cloned()
+                    .unwrap_or_else(|| self.name.trim_end_matches(" Energy").to_string());
+                CardType::Energy {
+                    energy_type: map_energy(&symbol, &self.name, warnings),
+                    is_basic: self.subtypes.iter().any(|s| s == "Basic"),
+                }
+            }
+            "Trainer" => CardType::Trainer {
+                trainer_type: map_trainer_type(&self.subtypes, &self.name, warnings),
+            },
+            other => {
+                return Err(ImportError::InvalidFormat(format!(
+                    "{}: unknown supertype '{}'",
+                    self.name, other
+                )));
+            }
+        };
+
+        let rarity = map_rarity(self.rarity.as_deref(), &self.name, warnings);
+        let set_name = self.set.map(|set| set.name).unwrap_or_default();
+        let id = Card::printing_id(&set_name, &self.number);
+        let mut card = Card::with_id(id, self.name, card_type, set_name, self.number, rarity);
+        // pokemontcg.io 的数据为英文
+        let english_name = card.name.clone();
+        card.set_localized_name("en", &english_name);
+
+        for attack in self.attacks {
+            let cost = attack
+                .cost
+                .iter()
+                .filter(|symbol| symbol.as_str() != "Free")
+                .map(|symbol| map_energy(symbol, &card.name, warnings))
+                .collect();
+            let (damage, damage_mode) = parse_damage(&attack.damage);
+
+            let mut mapped = Attack::simple(attack.name, cost, damage);
+            mapped.damage_mode = damage_mode;
+            if !attack.text.is_empty() {
+                mapped.effect = Some(attack.text);
+            }
+            card.add_attack(mapped);
+        }
+
+        for ability in self.abilities {
+            card.add_ability(Ability {
+                name: ability.name,
+                effect: ability.text,
+                ability_type: ability.ability_type,
+            });
+        }
+
+        for rule in self.rules {
+            card.add_rule(rule);
+        }
+
+        if let Some(id) = self.id {
+            card.add_metadata("pokemontcg_id".to_string(), id);
+        }
+        if untyped {
+            infer_legacy_pokemon_type(&mut card);
+        }
+
+        Ok(card)
+    }
+}
+
+/// Deserialize a card in the engine's JSON format
+///
+/// Files written before Pokemon cards had a `pokemon_type` field get one
+/// inferred by `infer_legacy_pokemon_type`.
+#[cfg(feature = "json")]
+fn card_from_value(value: serde_json::Value) -> Result<Card, serde_json::Error> {
+    let legacy = value
+        .pointer("/card_type/Pokemon")
+        .is_some_and(|pokemon| pokemon.get("pokemon_type").is_none());
+    let mut card: Card = serde_json::from_value(value)?;
+    if legacy {
+        infer_legacy_pokemon_type(&mut card);
+    }
+    Ok(card)
+}
+
+/// Guess the type of a Pokemon whose data does not state one
+///
+/// Uses the `type` metadata entry, then the first non-Colorless energy in
+/// the attack costs. Leaves the type unset if neither gives an answer.
+#[cfg(feature = "json")]
+fn infer_legacy_pokemon_type(card: &mut Card) {
+    let inferred = card
+        .metadata
+        .get("type")
+        .and_then(|energy_type| energy_type.parse().ok())
+        .or_else(|| {
+            card.attacks
+                .iter()
+                .flat_map(|attack| attack.cost.iter())
+                .find(|energy_type| **energy_type != EnergyType::Colorless)
+                .cloned()
+        });
+    if let CardType::Pokemon { pokemon_type, .. } = &mut card.card_type
+        && pokemon_type.is_none()
+    {
+        *pokemon_type = inferred;
+    }
+}
+
+/// Map a pokemontcg.io type name to an energy type, defaulting to Colorless
+#[cfg(feature = "json")]
+fn map_energy(symbol: &str, card_name: &str, warnings: &mut Vec<String>) -> EnergyType {
+    symbol.parse().unwrap_or_else(|_| {
+        warnings.push(format!(
+            "{}: unknown energy symbol '{}', using Colorless",
+            card_name, symbol
+        ));
+        EnergyType::Colorless
+    })
+}
+
+/// Pick the most specific evolution stage from the card's subtypes
+#[cfg(feature = "json")]
+fn map_stage(subtypes: &[String]) -> EvolutionStage {
+    let has = |name: &str| subtypes.iter().any(|s| s == name);
+
+    if has("VMAX") {
+        EvolutionStage::VMax
+    } else if has("V") {
+        EvolutionStage::V
+    } else if has("GX") {
+        EvolutionStage::GX
+    } else if has("EX") {
+        EvolutionStage::EX
+    } else if has("MEGA") {
+        EvolutionStage::Mega
+    } else if has("Stage 2") {
+        EvolutionStage::Stage2
+    } else if has("Stage 1") {
+        EvolutionStage::Stage1
+    } else {
+        EvolutionStage::Basic
+    }
+}
+
+#[cfg(feature = "json")]
+fn map_trainer_type(subtypes: &[String], card_name: &str, warnings: &mut Vec<String>) -> TrainerType {
+    for subtype in subtypes {
+        match subtype.as_str() {
+            "Item" => return TrainerType::Item,
+            "Supporter" => return TrainerType::Supporter,
+            "Stadium" => return TrainerType::Stadium,
+            "Pokémon Tool" | "Pokemon Tool" => return TrainerType::Tool,
+            _ => {}
+        }
+    }
+
+    warnings.push(format!("{}: unknown trainer subtype, using Item", card_name));
+    TrainerType::Item
+}
+
+#[cfg(feature = "json")]
+fn map_rarity(rarity: Option<&str>, card_name: &str, warnings: &mut Vec<String>) -> CardRarity {
+    match rarity {
+        None | Some("Common") => CardRarity::Common,
+        Some("Uncommon") => CardRarity::Uncommon,
+        Some("Rare") => CardRarity::Rare,
+        Some("Rare Holo") => CardRarity::RareHolo,
+        Some("Promo") => CardRarity::Promo,
+        Some(r) if r.contains("Secret") || r.contains("Rainbow") => CardRarity::SecretRare,
+        Some(r) if r.starts_with("Rare") || r.contains("Ultra") => CardRarity::UltraRare,
+        Some(other) => {
+            warnings.push(format!("{}: unknown rarity '{}', using Common", card_name, other));
+            CardRarity::Common
+        }
+    }
+}
+
+/// Parse damage strings like "30", "30+", "20×" or "" into a base value and mode
+#[cfg(feature = "json")]
+fn parse_damage(damage: &str) -> (u32, Option<DamageMode>) {
+    let trimmed = damage.trim();
+    let digits: String = trimmed.chars().take_while(|c| c.is_ascii_digit()).collect();
+    let base = digits.parse().unwrap_or(0);
+    let modifier = trimmed[digits.len()..].trim();
+
+    if modifier.is_empty() {
+        (base, None)
+    } else {
+        (
+            base,
+            Some(DamageMode::EffectText {
+                modifier: modifier.to_string(),
+            }),
+        )
+    }
+}
+
 #[cfg(feature = "json")]
 impl DataImporter for JsonImporter {
     fn import_cards(&self) -> Result<Vec<Card>, ImportError> {
         let content = std::fs::read_to_string(&self.file_path)?;
-        let cards: Vec<Card> = serde_json::from_str(&content)?;
+        let entries: Vec<serde_json::Value> = serde_json::from_str(&content)?;
+        let cards = entries
+            .into_iter()
+            .map(card_from_value)
+            .collect::<Result<Vec<Card>, _>>()?;
         Ok(cards)
     }
 
@@ -76,3 +546,226 @@ impl DataExporter for JsonExporter {
         self.export_cards(std::slice::from_ref(card))
     }
 }
+
+#[cfg(all(test, feature = "json"))]
+mod tests {
+    use super::*;
+
+    const FIXTURE: &str = r#"{
+        "data": [{
+            "id": "base1-58",
+            "name": "Pikachu",
+            "supertype": "Pokémon",
+            "subtypes": ["Basic"],
+            "hp": "60",
+            "types": ["Lightning"],
+            "abilities": [{ "name": "Static", "text": "Paralyzes attackers.", "type": "Pokémon Power" }],
+            "attacks": [{
+                "name": "Thunder Jolt",
+                "cost": ["Lightning", "Sparkle"],
+                "damage": "30+",
+                "text": "Flip a coin."
+            }],
+            "weaknesses": [{ "type": "Fighting", "value": "×2" }],
+            "retreatCost": ["Colorless"],
+            "convertedRetreatCost": 1,
+            "set": { "id": "base1", "name": "Base" },
+            "number": "58",
+            "rarity": "Common"
+        }]
+    }"#;
+
+    #[test]
+    fn test_import_pokemontcg_fixture() {
+        let result = JsonImporter::import_pokemontcg(FIXTURE).unwrap();
+        assert_eq!(result.cards.len(), 1);
+
+        let card = &result.cards[0];
+        assert_eq!(card.name, "Pikachu");
+        assert_eq!(card.set_name, "Base");
+        assert_eq!(card.set_number, "58");
+        assert_eq!(card.rarity, CardRarity::Common);
+        assert_eq!(card.get_hp(), Some(60));
+        assert!(matches!(
+            &card.card_type,
+            CardType::Pokemon {
+                stage: EvolutionStage::Basic,
+                weakness: Some(EnergyType::Fighting),
+                retreat_cost: 1,
+                ..
+            }
+        ));
+
+        let attack = &card.attacks[0];
+        assert_eq!(attack.damage, 30);
+        assert_eq!(
+            attack.damage_mode,
+            Some(DamageMode::EffectText {
+                modifier: "+".to_string()
+            })
+        );
+        assert_eq!(attack.cost, vec![EnergyType::Lightning, EnergyType::Colorless]);
+
+        assert_eq!(card.abilities.len(), 1);
+        assert_eq!(card.abilities[0].name, "Static");
+
+        // The unknown "Sparkle" symbol should produce a single warning
+        assert_eq!(result.warnings.len(), 1);
+        assert!(result.warnings[0].contains("Sparkle"));
+    }
+    #[test]
+    fn test_legacy_cards_get_inferred_pokemon_type() {
+        let mut card = Card::new(
+            "Charmander".to_string(),
+            CardType::Pokemon {
+                species: "Charmander".to_string(),
+                hp: 50,
+                pokemon_type: None,
+                retreat_cost: 1,
+                weakness: None,
+                resistance: None,
+                stage: EvolutionStage::Basic,
+                evolves_from: None,
+            },
+            "Base".to_string(),
+            "46".to_string(),
+            CardRarity::Common,
+        );
+        card.add_attack(Attack::simple(
+            "Ember".to_string(),
+            vec![EnergyType::Colorless, EnergyType::Fire],
+            30,
+        ));
+
+        // A card saved with an explicit empty type stays untyped
+        let current = serde_json::to_value(&card).unwrap();
+        assert_eq!(JsonImporter::parse_card(current.clone()).unwrap().pokemon_type(), None);
+
+        // Data from before the field existed is typed from its attack cost
+        let mut legacy = current;
+        legacy["card_type"]["Pokemon"]
+            .as_object_mut()
+            .unwrap()
+            .remove("pokemon_type");
+        assert_eq!(
+            JsonImporter::parse_card(legacy.clone()).unwrap().pokemon_type(),
+            Some(EnergyType::Fire)
+        );
+        legacy["metadata"]["type"] = serde_json::json!("Water");
+        assert_eq!(
+            JsonImporter::parse_card(legacy).unwrap().pokemon_type(),
+            Some(EnergyType::Water)
+        );
+    }
+
+    #[test]
+    fn test_import_with_report_skips_bad_entries() {
+        let pokemon = |name: &str| {
+            Card::new(
+                name.to_string(),
+                CardType::Pokemon {
+                    species: name.to_string(),
+                    hp: 60,
+                    pokemon_type: None,
+                    retreat_cost: 1,
+                    weakness: None,
+                    resistance: None,
+                    stage: EvolutionStage::Basic,
+                    evolves_from: None,
+                },
+                "Base".to_string(),
+                "1".to_string(),
+                CardRarity::Common,
+            )
+        };
+        let good = pokemon("Pikachu");
+        let mut missing_hp = serde_json::to_value(pokemon("Raichu")).unwrap();
+        missing_hp["card_type"]["Pokemon"]
+            .as_object_mut()
+            .unwrap()
+            .remove("hp");
+        let mut bad_energy = serde_json::to_value(pokemon("Jolteon")).unwrap();
+        bad_energy["card_type"]["Pokemon"]["weakness"] = serde_json::json!("Sparkle");
+
+        let entries = serde_json::json!([good, missing_hp, bad_energy]);
+        let path = std::env::temp_dir().join(format!("ptcg-import-{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&path, entries.to_string()).unwrap();
+        let (cards, warnings) = JsonImporter::import_with_report(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(cards, vec![good]);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!((warnings[0].index, warnings[0].name.as_deref()), (1, Some("Raichu")));
+        assert!(warnings[0].message.contains("hp"));
+        assert_eq!(warnings[1].name.as_deref(), Some("Jolteon"));
+        assert!(warnings[1].message.contains("Sparkle"));
+
+        assert!(matches!(
+            JsonImporter::parse_card(missing_hp),
+            Err(crate::Error::Data(message)) if message.starts_with("Raichu")
+        ));
+    }
+
+    #[test]
+    fn test_same_printing_imports_to_same_id() {
+        let first = JsonImporter::import_pokemontcg(FIXTURE).unwrap();
+        let second = JsonImporter::import_pokemontcg(FIXTURE).unwrap();
+
+        assert_eq!(first.cards[0].id, second.cards[0].id);
+        assert_eq!(first.cards[0].id, Card::printing_id("Base", "58"));
+    }
+
+    #[test]
+    fn test_import_deck_from_json_decklist() {
+        let mut db = CardDatabase::new();
+        let pikachu = db.insert(Card::new(
+            "Pikachu".to_string(),
+            CardType::Pokemon {
+                species: "Pikachu".to_string(),
+                hp: 60,
+                pokemon_type: None,
+                retreat_cost: 1,
+                weakness: None,
+                resistance: None,
+                stage: EvolutionStage::Basic,
+                evolves_from: None,
+            },
+            "SVI".to_string(),
+            "025".to_string(),
+            CardRarity::Common,
+        ));
+        let energy = db.insert(Card::new(
+            "Lightning Energy".to_string(),
+            CardType::Energy {
+                energy_type: EnergyType::Lightning,
+                is_basic: true,
+            },
+            "SVE".to_string(),
+            "004".to_string(),
+            CardRarity::Common,
+        ));
+
+        let json = r#"{
+            "name": "Pikachu Rush",
+            "format": "Standard",
+            "cards": [
+                { "set": "SVI", "number": "025", "count": 4 },
+                { "set": "SVE", "number": "004", "count": 10 },
+                { "set": "SVI", "number": "999", "count": 2 }
+            ]
+        }"#;
+        let (deck, unresolved) = JsonImporter::import_deck(json, &db).unwrap();
+        assert_eq!((deck.name.as_str(), deck.format.as_str()), ("Pikachu Rush", "Standard"));
+        assert_eq!(deck.total_cards(), 14);
+        assert_eq!(deck.get_card_count(pikachu), 4);
+        assert_eq!(deck.get_card_count(energy), 10);
+        assert_eq!(unresolved, vec!["SVI 999".to_string()]);
+
+        let zero = r#"{ "name": "Bad", "format": "Standard",
+            "cards": [{ "set": "SVI", "number": "025", "count": 0 }] }"#;
+        assert!(matches!(
+            JsonImporter::import_deck(zero, &db),
+            Err(crate::Error::Data(message)) if message.contains("count must be positive")
+        ));
+    }
+}
@@ -33,21 +33,29 @@
 pub mod core;
 /// 数据导入和管理模块
 pub mod data;
+/// AI代理模块
+pub mod ai;
+
+/// 测试共用的卡牌工厂
+#[cfg(test)]
+pub(crate) mod test_fixtures;
 
 /// 网络功能模块（需要async特性）
 #[cfg(feature = "async")]
 pub mod network;
 
 // 重新导出常用类型
+pub use ai::{Agent, GreedyAgent};
 pub use core::{
     card::{Ability, Attack, Card, CardRarity, CardType, EnergyType, TrainerType},
-    deck::{Deck, DeckValidationError},
+    deck::{Deck, DeckValidationError, DeckValidationWarning},
     effects::{
         Effect, EffectContext, EffectError, EffectId, EffectOutcome, EffectTarget, EffectTrigger,
-        TargetRequirement, PokemonAbilityEffect, PokemonAttackEffect, TrainerEffect, SpecialEnergyEffect, AbilityType
+        TargetRequirement, PokemonAbilityEffect, PokemonAttackEffect, TrainerEffect, SpecialEnergyEffect, AbilityType,
+        TrainerEffectRegistry, DrawCardsEffect
     },
     events::{EventBus, EventHandler, GameEvent},
-    game::{Game, GamePhase, GameRules, GameState},
+    game::{Game, GameError, GamePhase, GameRules, GameState},
     player::{CardLocation, Player, PlayerId, SpecialCondition, SpecialConditionInstance},
     rules::{Rule, RuleEngine, StandardRules},
 };
@@ -56,7 +64,7 @@ pub use core::{
 pub use data::json::JsonImporter;
 
 #[cfg(feature = "csv_import")]
-pub use data::csv::CsvImporter;
+pub use data::csv::{CsvExporter, CsvImporter};
 
 #[cfg(feature = "database")]
 pub use data::database::DatabaseImporter;
@@ -70,6 +78,9 @@ pub enum Error {
     #[error("游戏错误: {0}")]
     Game(String),
 
+    #[error("游戏错误: {0}")]
+    GameError(#[from] GameError),
+
     #[error("规则违反: {0}")]
     Rule(String),
 
@@ -89,6 +100,10 @@ pub enum Error {
     #[cfg(feature = "database")]
     #[error("数据库错误: {0}")]
     Database(#[from] rusqlite::Error),
+
+    #[cfg(feature = "bincode")]
+    #[error("二进制序列化错误: {0}")]
+    Bincode(#[from] bincode::Error),
 }
 
 /// 库版本信息
@@ -125,5 +140,8 @@ fn get_enabled_features() -> Vec<&'static str> {
     #[cfg(feature = "async")]
     features.push("async");
 
+    #[cfg(feature = "bincode")]
+    features.push("bincode");
+
     features
 }
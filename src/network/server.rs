@@ -1 +1,194 @@
-//! Network server functionality
\ No newline at end of file
+//! Network server functionality
+
+use crate::core::game::{Game, GameState};
+use crate::core::rules::{GameAction, RuleEngine, RuleViolation};
+use std::time::Instant;
+
+/// What the server does for a player who lets the turn timer run out
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeoutPolicy {
+    /// End the player's turn for them
+    #[default]
+    AutoPass,
+    /// Concede the game on the player's behalf
+    AutoConcede,
+}
+
+/// Hosts one game and enforces `GameRules::turn_time_limit` and
+/// `GameRules::time_bank` for online play
+///
+/// The server owns no timer of its own; the caller drives it with `tick`,
+/// so a disconnected player eventually forfeits their turn or the game.
+pub struct GameServer {
+    pub game: Game,
+    pub rule_engine: RuleEngine,
+    pub timeout_policy: TimeoutPolicy,
+    /// Instant the game's seconds clock counts from
+    epoch: Instant,
+    /// Last clock reading, up to which time banks have been charged
+    last_tick: Instant,
+}
+
+impl GameServer {
+    /// Host a game, with `now` as the start of its clock
+    pub fn new(game: Game, rule_engine: RuleEngine, timeout_policy: TimeoutPolicy, now: Instant) -> Self {
+        Self {
+            game,
+            rule_engine,
+            timeout_policy,
+            epoch: now,
+            last_tick: now,
+        }
+    }
+
+    /// Execute a player's action and restart the turn timer
+    ///
+    /// The limit applies to inactivity: each accepted action gives the
+    /// current player the full time limit again.
+    pub fn submit_action(&mut self, action: &GameAction, now: Instant) -> Result<(), Vec<RuleViolation>> {
+        self.charge_time_bank(now);
+        self.game.execute_action(&self.rule_engine, action)?;
+        self.game.start_turn_timer(self.seconds(now));
+        Ok(())
+    }
+
+    /// Advance the server clock to `now`, applying the timeout policy if due
+    ///
+    /// The time since the last reading is charged to the current player's
+    /// time bank. Returns the action taken on behalf of the current player,
+    /// or `None` while the turn is within its time limit and the bank is
+    /// not empty. The policy applies to the per-turn limit; a player whose
+    /// time bank runs out always concedes.
+    pub fn tick(&mut self, now: Instant) -> Result<Option<GameAction>, Vec<RuleViolation>> {
+        self.charge_time_bank(now);
+        let action = match self.game.check_turn_timeout(self.seconds(now)) {
+            Some(GameAction::EndTurn { player_id }) => match self.timeout_policy {
+                TimeoutPolicy::AutoPass => GameAction::EndTurn { player_id },
+                TimeoutPolicy::AutoConcede => GameAction::Concede { player_id },
+            },
+            Some(action) => action,
+            None => return Ok(None),
+        };
+        self.game.execute_action(&self.rule_engine, &action)?;
+        // The next player's time runs from the pass, not from the next tick
+        self.game.start_turn_timer(self.seconds(now));
+        Ok(Some(action))
+    }
+
+    /// Deduct the time since the last clock reading from the current player's bank
+    fn charge_time_bank(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_tick);
+        self.last_tick = self.last_tick.max(now);
+        if self.game.state != GameState::InProgress {
+            return;
+        }
+        if let Ok(player_id) = self.game.get_current_player_id() {
+            self.game.consume_time(player_id, elapsed);
+        }
+    }
+
+    fn seconds(&self, now: Instant) -> u64 {
+        now.saturating_duration_since(self.epoch).as_secs()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::game::GameRules;
+    use crate::core::player::{Player, PlayerId};
+    use crate::core::rules::StandardRules;
+    use std::time::Duration;
+    use uuid::Uuid;
+
+    fn server(rules: GameRules, policy: TimeoutPolicy, now: Instant) -> (GameServer, PlayerId, PlayerId) {
+        let mut game = Game::with_rules(rules);
+        let mut alice = Player::new("Alice".to_string());
+        let mut bob = Player::new("Bob".to_string());
+        let (alice_id, bob_id) = (alice.id, bob.id);
+        for player in [&mut alice, &mut bob] {
+            player.active_pokemon = Some(Uuid::new_v4());
+            player.set_deck((0..5).map(|_| Uuid::new_v4()).collect());
+        }
+        game.turn_order = vec![alice_id, bob_id];
+        game.add_player(alice).unwrap();
+        game.add_player(bob).unwrap();
+        game.start().unwrap();
+
+        let server = GameServer::new(game, StandardRules::create_engine(), policy, now);
+        (server, alice_id, bob_id)
+    }
+
+    #[test]
+    fn test_timeout_auto_passes_turn() {
+        let start = Instant::now();
+        let rules = GameRules::builder().turn_time_limit(Some(30)).build().unwrap();
+        let (mut server, alice_id, bob_id) = server(rules, TimeoutPolicy::AutoPass, start);
+
+        assert_eq!(server.tick(start), Ok(None));
+        assert_eq!(server.tick(start + Duration::from_secs(29)), Ok(None));
+        assert_eq!(
+            server.tick(start + Duration::from_secs(31)),
+            Ok(Some(GameAction::EndTurn { player_id: alice_id }))
+        );
+        assert_eq!(server.game.get_current_player_id(), Ok(bob_id));
+
+        // Bob's timer starts at the pass, so he times out exactly 30s later
+        assert_eq!(server.tick(start + Duration::from_secs(60)), Ok(None));
+        assert_eq!(
+            server.tick(start + Duration::from_secs(61)),
+            Ok(Some(GameAction::EndTurn { player_id: bob_id }))
+        );
+
+        // Acting resets the timer
+        let draw = GameAction::DrawCard { player_id: alice_id };
+        server.submit_action(&draw, start + Duration::from_secs(80)).unwrap();
+        assert_eq!(server.tick(start + Duration::from_secs(109)), Ok(None));
+        assert_eq!(
+            server.tick(start + Duration::from_secs(110)),
+            Ok(Some(GameAction::EndTurn { player_id: alice_id }))
+        );
+        assert_eq!(server.game.get_current_player_id(), Ok(bob_id));
+    }
+
+    #[test]
+    fn test_timeout_auto_concedes() {
+        let start = Instant::now();
+        let rules = GameRules::builder().turn_time_limit(Some(30)).build().unwrap();
+        let (mut server, alice_id, bob_id) = server(rules, TimeoutPolicy::AutoConcede, start);
+
+        assert_eq!(server.tick(start), Ok(None));
+        assert_eq!(
+            server.tick(start + Duration::from_secs(60)),
+            Ok(Some(GameAction::Concede { player_id: alice_id }))
+        );
+        assert_eq!(server.game.state, GameState::Finished { winner: Some(bob_id) });
+        assert_eq!(server.tick(start + Duration::from_secs(120)), Ok(None));
+    }
+
+    #[test]
+    fn test_ticks_drain_time_bank_of_current_player() {
+        let start = Instant::now();
+        let rules = GameRules::builder().time_bank(Some(100)).build().unwrap();
+        let (mut server, alice_id, bob_id) = server(rules, TimeoutPolicy::AutoPass, start);
+
+        assert_eq!(server.tick(start + Duration::from_secs(40)), Ok(None));
+        let end_turn = GameAction::EndTurn { player_id: alice_id };
+        server.submit_action(&end_turn, start + Duration::from_secs(70)).unwrap();
+        assert_eq!(server.game.time_remaining(alice_id), Some(Duration::from_secs(30)));
+
+        assert_eq!(server.tick(start + Duration::from_secs(90)), Ok(None));
+        assert_eq!(server.game.time_remaining(bob_id), Some(Duration::from_secs(80)));
+        assert_eq!(server.game.time_remaining(alice_id), Some(Duration::from_secs(30)));
+
+        let end_turn = GameAction::EndTurn { player_id: bob_id };
+        server.submit_action(&end_turn, start + Duration::from_secs(95)).unwrap();
+        assert_eq!(
+            server.tick(start + Duration::from_secs(130)),
+            Ok(Some(GameAction::Concede { player_id: alice_id }))
+        );
+        assert_eq!(server.game.time_remaining(alice_id), Some(Duration::ZERO));
+        assert_eq!(server.game.state, GameState::Finished { winner: Some(bob_id) });
+        assert_eq!(server.tick(start + Duration::from_secs(200)), Ok(None));
+    }
+}
@@ -0,0 +1,102 @@
+//! 测试共用的卡牌工厂
+//!
+//! 卡包统一为 "Test"，编号为 "1"；需要特殊属性的测试在返回的卡牌上再做修改。
+
+use crate::core::card::{Card, CardRarity, CardType, EnergyType, EvolutionStage, TrainerType};
+
+/// 基础宝可梦：撤退费用为1，没有属性、弱点、抗性和招式
+pub(crate) fn pokemon(name: &str, hp: u32) -> Card {
+    pokemon_at_stage(name, hp, EvolutionStage::Basic)
+}
+
+/// 指定进化阶段的宝可梦，其余同 `pokemon`
+pub(crate) fn pokemon_at_stage(name: &str, hp: u32, stage: EvolutionStage) -> Card {
+    Card::new(
+        name.to_string(),
+        CardType::Pokemon {
+            species: name.to_string(),
+            hp,
+            pokemon_type: None,
+            retreat_cost: 1,
+            weakness: None,
+            resistance: None,
+            stage,
+            evolves_from: None,
+        },
+        "Test".to_string(),
+        "1".to_string(),
+        CardRarity::Common,
+    )
+}
+
+/// 从 `evolves_from` 进化而来的1阶进化宝可梦
+pub(crate) fn stage1(name: &str, hp: u32, evolves_from: &str) -> Card {
+    let mut card = pokemon_at_stage(name, hp, EvolutionStage::Stage1);
+    if let CardType::Pokemon { evolves_from: slot, .. } = &mut card.card_type {
+        *slot = Some(evolves_from.to_string());
+    }
+    card
+}
+
+/// 设置宝可梦的属性
+pub(crate) fn with_pokemon_type(mut card: Card, energy_type: EnergyType) -> Card {
+    if let CardType::Pokemon { pokemon_type, .. } = &mut card.card_type {
+        *pokemon_type = Some(energy_type);
+    }
+    card
+}
+
+/// 设置宝可梦的弱点
+pub(crate) fn with_weakness(mut card: Card, energy_type: EnergyType) -> Card {
+    if let CardType::Pokemon { weakness, .. } = &mut card.card_type {
+        *weakness = Some(energy_type);
+    }
+    card
+}
+
+/// 设置宝可梦的抗性
+pub(crate) fn with_resistance(mut card: Card, energy_type: EnergyType) -> Card {
+    if let CardType::Pokemon { resistance, .. } = &mut card.card_type {
+        *resistance = Some(energy_type);
+    }
+    card
+}
+
+/// 设置宝可梦的撤退费用
+pub(crate) fn with_retreat_cost(mut card: Card, cost: u32) -> Card {
+    if let CardType::Pokemon { retreat_cost, .. } = &mut card.card_type {
+        *retreat_cost = cost;
+    }
+    card
+}
+
+/// 基础能量卡，名称如 "Fire Energy"
+pub(crate) fn basic_energy(energy_type: EnergyType) -> Card {
+    energy(format!("{} Energy", energy_type), energy_type, true)
+}
+
+/// 特殊能量卡
+pub(crate) fn special_energy(name: &str, energy_type: EnergyType) -> Card {
+    energy(name.to_string(), energy_type, false)
+}
+
+fn energy(name: String, energy_type: EnergyType, is_basic: bool) -> Card {
+    Card::new(
+        name,
+        CardType::Energy { energy_type, is_basic },
+        "Test".to_string(),
+        "1".to_string(),
+        CardRarity::Common,
+    )
+}
+
+/// 训练家卡
+pub(crate) fn trainer(name: &str, trainer_type: TrainerType) -> Card {
+    Card::new(
+        name.to_string(),
+        CardType::Trainer { trainer_type },
+        "Test".to_string(),
+        "1".to_string(),
+        CardRarity::Common,
+    )
+}